@@ -0,0 +1,99 @@
+//! Compare decoding a large allocation list as it is streamed against
+//! buffering the whole response body before decoding it.
+//!
+//! Run with `cargo bench --bench list_allocations`.
+
+use nomad_rs_api::allocation::AllocationStub;
+use nomad_rs_api::{Config, Nomad};
+use serde_json::json;
+use std::time::{Duration, Instant};
+use wiremock::matchers::{method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+const ALLOCATIONS: usize = 50_000;
+const ITERATIONS: u32 = 10;
+
+fn allocations() -> serde_json::Value {
+    (0..ALLOCATIONS)
+        .map(|idx| {
+            json!({
+                "ID": format!("a8198d79-cfdb-6593-a999-{:012}", idx),
+                "EvalID": "5456bd7a-9fc0-c0dd-6131-cbee77f57577",
+                "Name": format!("example.cache[{}]", idx),
+                "Namespace": "default",
+                "NodeID": "fb2170a8-257d-3c64-b14d-bc06cc94e34c",
+                "NodeName": "node-1",
+                "JobID": "example",
+                "JobType": "service",
+                "JobVersion": 0,
+                "TaskGroup": "cache",
+                "DesiredStatus": "run",
+                "DesiredDescription": "",
+                "ClientStatus": "running",
+                "ClientDescription": "",
+                "TaskStates": null,
+                "PreemptedByAllocation": "",
+                "CreateIndex": idx,
+                "ModifyIndex": idx,
+                "CreateTime": 1700000000000000000i64,
+                "ModifyTime": 1700000001000000000i64,
+            })
+        })
+        .collect()
+}
+
+async fn time<F, Fut>(name: &str, f: F)
+where
+    F: Fn() -> Fut,
+    Fut: Future<Output = usize>,
+{
+    let mut total = Duration::ZERO;
+    for _ in 0..ITERATIONS {
+        let start = Instant::now();
+        let count = f().await;
+        total += start.elapsed();
+        assert_eq!(count, ALLOCATIONS);
+    }
+    println!(
+        "{:<10} {:>10.2?} per list of {} allocations",
+        name,
+        total / ITERATIONS,
+        ALLOCATIONS
+    );
+}
+
+#[tokio::main]
+async fn main() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/v1/allocations"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(allocations()))
+        .mount(&server)
+        .await;
+
+    let nomad = Nomad::new(Config {
+        address: server.uri(),
+        ..Config::default()
+    });
+    time("streaming", || async {
+        nomad.allocation().list(None).await.unwrap().len()
+    })
+    .await;
+
+    let http_client = reqwest::Client::new();
+    let url = format!("{}/v1/allocations", server.uri());
+    time("buffered", || async {
+        let body = http_client
+            .get(&url)
+            .send()
+            .await
+            .unwrap()
+            .bytes()
+            .await
+            .unwrap();
+        serde_json::from_slice::<Vec<AllocationStub>>(&body)
+            .unwrap()
+            .len()
+    })
+    .await;
+}
@@ -0,0 +1,49 @@
+//! Run a command within a task of a running allocation and print its output.
+//!
+//! Usage: `cargo run --example alloc_exec --features exec -- <alloc-id> <task> <command>...`
+
+use nomad_rs_api::exec::ExecOutput;
+use nomad_rs_api::{Config, Nomad};
+use std::io::Write;
+
+#[tokio::main]
+async fn main() {
+    let mut args = std::env::args().skip(1);
+    let alloc_id = args.next().expect("allocation ID must be set");
+    let task = args.next().expect("task name must be set");
+    let command: Vec<String> = args.collect();
+    let command: Vec<&str> = command.iter().map(String::as_str).collect();
+
+    let client = Nomad::new(Config::from_env());
+    let session = match client
+        .allocation()
+        .exec(&alloc_id, &task, &command, false, None)
+        .await
+    {
+        Ok(session) => session,
+        Err(err) => {
+            eprintln!("failed to start exec session: {}", err);
+            return;
+        }
+    };
+
+    // The command does not read any input, so close its stdin straight away
+    // and only handle the output half of the session.
+    let (mut writer, mut reader) = session.split();
+    if let Err(err) = writer.close_stdin().await {
+        eprintln!("failed to close stdin: {}", err);
+        return;
+    }
+
+    while let Some(output) = reader.next_output().await {
+        match output {
+            Ok(ExecOutput::Stdout(data)) => std::io::stdout().write_all(&data).unwrap(),
+            Ok(ExecOutput::Stderr(data)) => std::io::stderr().write_all(&data).unwrap(),
+            Ok(ExecOutput::Exited(exit_code)) => println!("exit code: {}", exit_code),
+            Err(err) => {
+                eprintln!("exec session failed: {}", err);
+                return;
+            }
+        }
+    }
+}
@@ -0,0 +1,78 @@
+//! Build a two-task service job using the job builders and validate it
+//! against the Nomad cluster.
+//!
+//! Usage: `cargo run --example build_job`
+
+use nomad_rs_api::job::{JobValidateRequest, NetworkResource, Port};
+use nomad_rs_api::job_builder::{JobBuilder, TaskBuilder, TaskGroupBuilder};
+use nomad_rs_api::{ClientError, Config, Nomad};
+use serde_json::json;
+use std::time::Duration;
+
+fn build() -> Result<nomad_rs_api::job::Job, ClientError> {
+    let web = TaskBuilder::new("web", "docker")
+        .config("image", json!("nginx:1.27"))
+        .config("ports", json!(["http"]))
+        .cpu_and_memory(200, 128)
+        .kill_timeout(Duration::from_secs(10))
+        .leader(true)
+        .build()?;
+
+    let log_shipper = TaskBuilder::new("log-shipper", "docker")
+        .config("image", json!("busybox:1.36"))
+        .config("command", json!("tail"))
+        .config("args", json!(["-F", "/alloc/logs/web.stdout.0"]))
+        .env("SHIP_TO", "logs.example.com")
+        .cpu_and_memory(50, 32)
+        .build()?;
+
+    let group = TaskGroupBuilder::new("frontend")
+        .count(2)
+        .network(NetworkResource {
+            mode: Some("bridge".to_string()),
+            device: None,
+            cidr: None,
+            ip: None,
+            mbits: None,
+            dns: None,
+            reserved_ports: None,
+            dynamic_ports: Some(vec![Port {
+                label: "http".to_string(),
+                value: None,
+                to: Some(80),
+                host_network: None,
+            }]),
+        })
+        .add_task(web)
+        .add_task(log_shipper)
+        .build()?;
+
+    JobBuilder::new("frontend")
+        .datacenters(&["dc1"])
+        .priority(60)
+        .meta("owner", "web-team")
+        .add_task_group(group)
+        .build()
+}
+
+#[tokio::main]
+async fn main() {
+    let job = match build() {
+        Ok(job) => job,
+        Err(err) => {
+            eprintln!("invalid job: {}", err);
+            return;
+        }
+    };
+    println!("{}", serde_json::to_string_pretty(&job).unwrap());
+
+    let client = Nomad::new(Config::from_env());
+    match client
+        .job()
+        .validate(&JobValidateRequest::new(&job), None)
+        .await
+    {
+        Ok(resp) => println!("validation: {:?}", resp),
+        Err(err) => eprintln!("validation failed: {}", err),
+    }
+}
@@ -0,0 +1,40 @@
+//! Watch a single job and print each status transition.
+//!
+//! Usage: `cargo run --example watch_job -- <job-id>`
+
+use nomad_rs_api::job::Job;
+use nomad_rs_api::option::QueryOptions;
+use nomad_rs_api::{Config, Nomad};
+use std::time::Duration;
+
+#[tokio::main]
+async fn main() {
+    let job_id = std::env::args().nth(1).unwrap_or("example".to_string());
+
+    // The client timeout must be larger than the blocking query wait time, so
+    // that a query which does not observe a change is not aborted early.
+    let mut config = Config::from_env();
+    config.timeout = Some(Duration::from_secs(90));
+    let client = Nomad::new(config);
+
+    let opts = QueryOptions::new().with_wait_time(60_000_000_000);
+    let mut last_status: Option<String> = None;
+
+    let result = client
+        .watch::<Job>(&format!("/v1/job/{}", job_id), Some(opts), |job| {
+            if job.status != last_status {
+                println!(
+                    "job {} status: {} -> {}",
+                    job_id,
+                    last_status.as_deref().unwrap_or("<none>"),
+                    job.status.as_deref().unwrap_or("<none>"),
+                );
+                last_status = job.status;
+            }
+        })
+        .await;
+
+    if let Err(err) = result {
+        eprintln!("watch stopped: {}", err);
+    }
+}
@@ -0,0 +1,372 @@
+use crate::acl_token::ACLToken;
+use crate::option::{QueryOptions, WriteOptions};
+use crate::{ClientError, Nomad};
+use reqwest::Method;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+pub const ACL_AUTH_METHOD_TYPE_OIDC: &str = "OIDC";
+pub const ACL_AUTH_METHOD_TYPE_JWT: &str = "JWT";
+
+pub const ACL_AUTH_METHOD_TOKEN_LOCALITY_LOCAL: &str = "local";
+pub const ACL_AUTH_METHOD_TOKEN_LOCALITY_GLOBAL: &str = "global";
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct AuthMethod {
+    pub name: String,
+    #[serde(rename = "Type")]
+    pub method_type: String,
+    pub token_locality: String,
+    pub token_name_format: Option<String>,
+    #[serde(rename = "MaxTokenTTL", with = "crate::duration_nanos")]
+    pub max_token_ttl: time::Duration,
+    pub default: bool,
+    pub config: Option<AuthMethodConfig>,
+    pub create_index: Option<u64>,
+    pub modify_index: Option<u64>,
+}
+
+impl AuthMethod {
+    /// Create a new auth method object.
+    ///
+    /// # Arguments
+    /// * `name` - The name of the auth method.
+    /// * `method_type` - The type of the auth method, such as `OIDC` or `JWT`.
+    /// * `token_locality` - Whether tokens created by the auth method are
+    ///   `local` or `global`.
+    /// * `max_token_ttl` - The maximum lifetime of created tokens.
+    ///
+    /// # Returns
+    /// A new `AuthMethod` object.
+    pub fn new(
+        name: String,
+        method_type: String,
+        token_locality: String,
+        max_token_ttl: time::Duration,
+    ) -> Self {
+        Self {
+            name,
+            method_type,
+            token_locality,
+            token_name_format: None,
+            max_token_ttl,
+            default: false,
+            config: None,
+            create_index: None,
+            modify_index: None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct AuthMethodConfig {
+    #[serde(rename = "JWTValidationPubKeys")]
+    pub jwt_validation_pub_keys: Option<Vec<String>>,
+    #[serde(rename = "JWKSURL")]
+    pub jwks_url: Option<String>,
+    #[serde(rename = "JWKSCACert")]
+    pub jwks_ca_cert: Option<String>,
+    #[serde(rename = "OIDCDiscoveryURL")]
+    pub oidc_discovery_url: Option<String>,
+    #[serde(rename = "OIDCClientID")]
+    pub oidc_client_id: Option<String>,
+    #[serde(rename = "OIDCClientSecret")]
+    pub oidc_client_secret: Option<String>,
+    #[serde(rename = "OIDCScopes")]
+    pub oidc_scopes: Option<Vec<String>>,
+    #[serde(rename = "OIDCDisableUserInfo")]
+    pub oidc_disable_user_info: Option<bool>,
+    pub bound_audiences: Option<Vec<String>>,
+    pub bound_issuer: Option<Vec<String>>,
+    #[serde(rename = "AllowedRedirectURIs")]
+    pub allowed_redirect_uris: Option<Vec<String>>,
+    pub discovery_ca_pem: Option<Vec<String>>,
+    pub signing_algs: Option<Vec<String>>,
+    pub expiration_leeway: Option<i64>,
+    pub not_before_leeway: Option<i64>,
+    pub clock_skew_leeway: Option<i64>,
+    pub claim_mappings: Option<HashMap<String, String>>,
+    pub list_claim_mappings: Option<HashMap<String, String>>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct AuthMethodStub {
+    pub name: String,
+    #[serde(rename = "Type")]
+    pub method_type: String,
+    pub default: bool,
+    pub hash: Option<String>,
+    pub create_index: Option<u64>,
+    pub modify_index: Option<u64>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct OIDCAuthURLRequest {
+    pub auth_method_name: String,
+    #[serde(rename = "RedirectURI")]
+    pub redirect_uri: String,
+    pub client_nonce: String,
+}
+
+impl OIDCAuthURLRequest {
+    pub fn new(auth_method_name: String, redirect_uri: String, client_nonce: String) -> Self {
+        OIDCAuthURLRequest {
+            auth_method_name,
+            redirect_uri,
+            client_nonce,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct OIDCAuthURLResponse {
+    #[serde(rename = "AuthURL")]
+    pub auth_url: String,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct OIDCCompleteAuthRequest {
+    pub auth_method_name: String,
+    pub client_nonce: String,
+    pub state: String,
+    pub code: String,
+    #[serde(rename = "RedirectURI")]
+    pub redirect_uri: String,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "PascalCase")]
+struct ACLLoginRequest<'a> {
+    auth_method_name: &'a str,
+    login_token: &'a str,
+}
+
+pub struct Endpoint<'a> {
+    client: &'a Nomad,
+}
+
+impl<'a> Endpoint<'a> {
+    /// Create a new `Endpoint` with the given `Nomad` client to interact with
+    /// the ACL auth method endpoints.
+    pub fn new(client: &'a Nomad) -> Self {
+        Self { client }
+    }
+
+    /// Exchange a JWT for a Nomad ACL token using the named JWT auth method.
+    /// This endpoint does not require an ACL token.
+    ///
+    /// # Arguments
+    /// * `auth_method_name` - The name of the auth method to log in with.
+    /// * `login_token` - The JWT issued by the identity provider.
+    /// * `opts` - Optional write options for the request.
+    ///
+    /// # Returns
+    /// A `Result` containing the created `ACLToken` or an error if the request
+    /// fails.
+    pub async fn acl_login(
+        &self,
+        auth_method_name: &str,
+        login_token: &str,
+        opts: Option<WriteOptions>,
+    ) -> Result<ACLToken, ClientError> {
+        let req = self
+            .client
+            .set_request_write_options(
+                self.client.build_request(Method::POST, "/v1/acl/login"),
+                &opts.unwrap_or_default(),
+            )
+            .json(&ACLLoginRequest {
+                auth_method_name,
+                login_token,
+            });
+        self.client.send_with_response::<ACLToken>(req).await
+    }
+
+    /// Get the URL of the OIDC provider which the user must visit to start the
+    /// OIDC login flow. This endpoint does not require an ACL token.
+    ///
+    /// # Arguments
+    /// * `oidc_auth_url_request` - The request identifying the auth method,
+    ///   redirect URI, and client nonce.
+    /// * `opts` - Optional write options for the request.
+    ///
+    /// # Returns
+    /// A `Result` containing the `OIDCAuthURLResponse` or an error if the
+    /// request fails.
+    pub async fn acl_oidc_auth_url(
+        &self,
+        oidc_auth_url_request: &OIDCAuthURLRequest,
+        opts: Option<WriteOptions>,
+    ) -> Result<OIDCAuthURLResponse, ClientError> {
+        let req = self
+            .client
+            .set_request_write_options(
+                self.client
+                    .build_request(Method::POST, "/v1/acl/oidc/auth-url"),
+                &opts.unwrap_or_default(),
+            )
+            .json(oidc_auth_url_request);
+        self.client
+            .send_with_response::<OIDCAuthURLResponse>(req)
+            .await
+    }
+
+    /// Complete the OIDC login flow by exchanging the code and state returned
+    /// by the OIDC provider for a Nomad ACL token. This endpoint does not
+    /// require an ACL token.
+    ///
+    /// # Arguments
+    /// * `oidc_complete_auth_request` - The request containing the client
+    ///   nonce used to start the flow and the provider's code and state.
+    /// * `opts` - Optional write options for the request.
+    ///
+    /// # Returns
+    /// A `Result` containing the created `ACLToken` or an error if the request
+    /// fails.
+    pub async fn acl_oidc_complete_auth(
+        &self,
+        oidc_complete_auth_request: &OIDCCompleteAuthRequest,
+        opts: Option<WriteOptions>,
+    ) -> Result<ACLToken, ClientError> {
+        let req = self
+            .client
+            .set_request_write_options(
+                self.client
+                    .build_request(Method::POST, "/v1/acl/oidc/complete-auth"),
+                &opts.unwrap_or_default(),
+            )
+            .json(oidc_complete_auth_request);
+        self.client.send_with_response::<ACLToken>(req).await
+    }
+
+    /// Create a new ACL auth method in the Nomad cluster.
+    ///
+    /// # Arguments
+    /// * `auth_method` - The auth method to create.
+    /// * `opts` - Optional write options for the request.
+    ///
+    /// # Returns
+    /// A `Result` containing the created `AuthMethod` object or an error if
+    /// the request fails.
+    pub async fn create(
+        &self,
+        auth_method: &AuthMethod,
+        opts: Option<WriteOptions>,
+    ) -> Result<AuthMethod, ClientError> {
+        if auth_method.name.is_empty() {
+            return Err(ClientError::InvalidInputError(
+                "Auth method name must be set".to_string(),
+            ));
+        }
+
+        let req = self
+            .client
+            .set_request_write_options(
+                self.client
+                    .build_request(Method::POST, "/v1/acl/auth-method"),
+                &opts.unwrap_or_default(),
+            )
+            .json(auth_method);
+        self.client.send_with_response::<AuthMethod>(req).await
+    }
+
+    /// Delete an ACL auth method by its name.
+    ///
+    /// # Arguments
+    /// * `name` - The name of the auth method to delete.
+    /// * `opts` - Optional write options for the request.
+    ///
+    /// # Returns
+    /// A `Result` indicating success or failure of the operation.
+    pub async fn delete(&self, name: &str, opts: Option<WriteOptions>) -> Result<(), ClientError> {
+        let req = self.client.set_request_write_options(
+            self.client
+                .build_request(Method::DELETE, &format!("/v1/acl/auth-method/{}", name)),
+            &opts.unwrap_or_default(),
+        );
+        self.client.send_without_response(req).await
+    }
+
+    /// Get an ACL auth method by its name.
+    ///
+    /// # Arguments
+    /// * `name` - The name of the auth method to retrieve.
+    /// * `opts` - Optional query options for the request.
+    ///
+    /// # Returns
+    /// A `Result` containing the `AuthMethod` object or an error if the
+    /// request fails.
+    pub async fn get(
+        &self,
+        name: &str,
+        opts: Option<QueryOptions>,
+    ) -> Result<AuthMethod, ClientError> {
+        let req = self.client.set_request_query_options(
+            self.client
+                .build_request(Method::GET, &format!("/v1/acl/auth-method/{}", name)),
+            &opts.unwrap_or_default(),
+        );
+        self.client.send_with_response::<AuthMethod>(req).await
+    }
+
+    /// Get the list of ACL auth methods in the Nomad cluster.
+    ///
+    /// # Arguments
+    /// * `opts` - Optional query options to filter the results.
+    ///
+    /// # Returns
+    /// A `Result` containing a vector of `AuthMethodStub` objects or an error
+    /// if the request fails.
+    pub async fn list(
+        &self,
+        opts: Option<QueryOptions>,
+    ) -> Result<Vec<AuthMethodStub>, ClientError> {
+        let req = self.client.set_request_query_options(
+            self.client
+                .build_request(Method::GET, "/v1/acl/auth-methods"),
+            &opts.unwrap_or_default(),
+        );
+        self.client
+            .send_with_response::<Vec<AuthMethodStub>>(req)
+            .await
+    }
+
+    /// Update an existing ACL auth method in the Nomad cluster.
+    ///
+    /// # Arguments
+    /// * `auth_method` - The auth method to update, identified by its name.
+    /// * `opts` - Optional write options for the request.
+    ///
+    /// # Returns
+    /// A `Result` containing the updated `AuthMethod` object or an error if
+    /// the request fails.
+    pub async fn update(
+        &self,
+        auth_method: &AuthMethod,
+        opts: Option<WriteOptions>,
+    ) -> Result<AuthMethod, ClientError> {
+        if auth_method.name.is_empty() {
+            return Err(ClientError::InvalidInputError(
+                "Auth method name must be set".to_string(),
+            ));
+        }
+
+        let req = self
+            .client
+            .set_request_write_options(
+                self.client.build_request(
+                    Method::POST,
+                    &format!("/v1/acl/auth-method/{}", auth_method.name),
+                ),
+                &opts.unwrap_or_default(),
+            )
+            .json(auth_method);
+        self.client.send_with_response::<AuthMethod>(req).await
+    }
+}
@@ -0,0 +1,200 @@
+use crate::option::{QueryOptions, WriteOptions};
+use crate::{ClientError, Nomad};
+use reqwest::Method;
+use serde::{Deserialize, Serialize};
+
+pub const ACL_BINDING_RULE_BIND_TYPE_ROLE: &str = "role";
+pub const ACL_BINDING_RULE_BIND_TYPE_POLICY: &str = "policy";
+pub const ACL_BINDING_RULE_BIND_TYPE_MANAGEMENT: &str = "management";
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct ACLBindingRule {
+    #[serde(rename = "ID")]
+    pub id: Option<String>,
+    pub description: Option<String>,
+    pub auth_method: String,
+    pub selector: Option<String>,
+    pub bind_type: String,
+    pub bind_name: Option<String>,
+    pub create_index: Option<u64>,
+    pub modify_index: Option<u64>,
+}
+
+impl ACLBindingRule {
+    /// Create a new ACL binding rule object.
+    ///
+    /// # Arguments
+    /// * `auth_method` - The name of the auth method the rule applies to.
+    /// * `bind_type` - The type of the binding, such as `role`, `policy`, or
+    ///   `management`.
+    /// * `bind_name` - The name of the role or policy to bind to, which is not
+    ///   required for `management` bindings.
+    ///
+    /// # Returns
+    /// A new `ACLBindingRule` object.
+    pub fn new(auth_method: String, bind_type: String, bind_name: Option<String>) -> Self {
+        Self {
+            id: None,
+            description: None,
+            auth_method,
+            selector: None,
+            bind_type,
+            bind_name,
+            create_index: None,
+            modify_index: None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct ACLBindingRuleListStub {
+    #[serde(rename = "ID")]
+    pub id: String,
+    pub description: Option<String>,
+    pub auth_method: String,
+    pub hash: Option<String>,
+    pub create_index: Option<u64>,
+    pub modify_index: Option<u64>,
+}
+
+pub struct Endpoint<'a> {
+    client: &'a Nomad,
+}
+
+impl<'a> Endpoint<'a> {
+    /// Create a new `Endpoint` with the given `Nomad` client to interact with
+    /// the ACL binding rule endpoints.
+    pub fn new(client: &'a Nomad) -> Self {
+        Self { client }
+    }
+
+    /// Create a new ACL binding rule in the Nomad cluster. The ID of the rule
+    /// is generated by Nomad and must not be set.
+    ///
+    /// # Arguments
+    /// * `binding_rule` - The binding rule to create.
+    /// * `opts` - Optional write options for the request.
+    ///
+    /// # Returns
+    /// A `Result` containing the created `ACLBindingRule` object or an error
+    /// if the request fails.
+    pub async fn create(
+        &self,
+        binding_rule: &ACLBindingRule,
+        opts: Option<WriteOptions>,
+    ) -> Result<ACLBindingRule, ClientError> {
+        if binding_rule.id.as_deref().is_some_and(|id| !id.is_empty()) {
+            return Err(ClientError::InvalidInputError(
+                "ACL binding rule ID must not be set".to_string(),
+            ));
+        }
+
+        let req = self
+            .client
+            .set_request_write_options(
+                self.client
+                    .build_request(Method::POST, "/v1/acl/binding-rule"),
+                &opts.unwrap_or_default(),
+            )
+            .json(binding_rule);
+        self.client.send_with_response::<ACLBindingRule>(req).await
+    }
+
+    /// Delete an ACL binding rule by its ID.
+    ///
+    /// # Arguments
+    /// * `id` - The ID of the binding rule to delete.
+    /// * `opts` - Optional write options for the request.
+    ///
+    /// # Returns
+    /// A `Result` indicating success or failure of the operation.
+    pub async fn delete(&self, id: &str, opts: Option<WriteOptions>) -> Result<(), ClientError> {
+        let req = self.client.set_request_write_options(
+            self.client
+                .build_request(Method::DELETE, &format!("/v1/acl/binding-rule/{}", id)),
+            &opts.unwrap_or_default(),
+        );
+        self.client.send_without_response(req).await
+    }
+
+    /// Get an ACL binding rule by its ID.
+    ///
+    /// # Arguments
+    /// * `id` - The ID of the binding rule to retrieve.
+    /// * `opts` - Optional query options for the request.
+    ///
+    /// # Returns
+    /// A `Result` containing the `ACLBindingRule` object or an error if the
+    /// request fails.
+    pub async fn get(
+        &self,
+        id: &str,
+        opts: Option<QueryOptions>,
+    ) -> Result<ACLBindingRule, ClientError> {
+        let req = self.client.set_request_query_options(
+            self.client
+                .build_request(Method::GET, &format!("/v1/acl/binding-rule/{}", id)),
+            &opts.unwrap_or_default(),
+        );
+        self.client.send_with_response::<ACLBindingRule>(req).await
+    }
+
+    /// Get the list of ACL binding rules in the Nomad cluster.
+    ///
+    /// # Arguments
+    /// * `opts` - Optional query options to filter the results.
+    ///
+    /// # Returns
+    /// A `Result` containing a vector of `ACLBindingRuleListStub` objects or
+    /// an error if the request fails.
+    pub async fn list(
+        &self,
+        opts: Option<QueryOptions>,
+    ) -> Result<Vec<ACLBindingRuleListStub>, ClientError> {
+        let req = self.client.set_request_query_options(
+            self.client
+                .build_request(Method::GET, "/v1/acl/binding-rules"),
+            &opts.unwrap_or_default(),
+        );
+        self.client
+            .send_with_response::<Vec<ACLBindingRuleListStub>>(req)
+            .await
+    }
+
+    /// Update an existing ACL binding rule in the Nomad cluster.
+    ///
+    /// # Arguments
+    /// * `binding_rule` - The binding rule to update, which must have its ID
+    ///   set.
+    /// * `opts` - Optional write options for the request.
+    ///
+    /// # Returns
+    /// A `Result` containing the updated `ACLBindingRule` object or an error
+    /// if the request fails.
+    pub async fn update(
+        &self,
+        binding_rule: &ACLBindingRule,
+        opts: Option<WriteOptions>,
+    ) -> Result<ACLBindingRule, ClientError> {
+        let id = match binding_rule.id.as_deref() {
+            Some(id) if !id.is_empty() => id,
+            _ => {
+                return Err(ClientError::InvalidInputError(
+                    "ACL binding rule ID must be set".to_string(),
+                ));
+            }
+        };
+
+        let req = self
+            .client
+            .set_request_write_options(
+                self.client
+                    .build_request(Method::POST, &format!("/v1/acl/binding-rule/{}", id)),
+                &opts.unwrap_or_default(),
+            )
+            .json(binding_rule);
+        self.client.send_with_response::<ACLBindingRule>(req).await
+    }
+}
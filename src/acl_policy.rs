@@ -68,9 +68,13 @@ impl<'a> Endpoint<'a> {
     /// Create a new ACL policy in the Nomad cluster. This can also be used to
     /// update an existing ACL policy by providing the same name.
     ///
+    /// Nomad does not support check-and-set for ACL policy writes, so an update
+    /// replaces the policy regardless of its current modify index.
+    ///
     /// # Arguments
     /// * `policy` - The ACL policy to create.
-    /// * `opts` - Optional write options for the request.
+    /// * `opts` - Optional write options for the request, including any
+    ///   idempotency token.
     ///
     /// # Returns
     /// A `Result` indicating success or failure of the operation.
@@ -129,6 +133,24 @@ impl<'a> Endpoint<'a> {
         self.client.send_with_response::<ACLPolicy>(req).await
     }
 
+    /// Get the ACL policy with the specified name, returning `None` rather than
+    /// an error when it does not exist.
+    ///
+    /// # Arguments
+    /// * `name` - The name of the ACL policy to retrieve.
+    /// * `opts` - Optional query options for the request.
+    ///
+    /// # Returns
+    /// A `Result` containing the ACL policy, `None` if it does not exist, or an
+    /// error if the request fails.
+    pub async fn get_optional(
+        &self,
+        name: &str,
+        opts: Option<QueryOptions>,
+    ) -> Result<Option<ACLPolicy>, ClientError> {
+        crate::not_found_to_none(self.get(name, opts).await)
+    }
+
     /// Get the list of ACL policies in the Nomad cluster.
     ///
     /// # Arguments
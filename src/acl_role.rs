@@ -0,0 +1,229 @@
+use crate::option::{QueryOptions, WriteOptions};
+use crate::{ClientError, Nomad};
+use reqwest::Method;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct ACLRole {
+    #[serde(rename = "ID")]
+    pub id: Option<String>,
+    pub name: String,
+    pub description: Option<String>,
+    pub policies: Vec<ACLRolePolicyLink>,
+    pub create_index: Option<u64>,
+    pub modify_index: Option<u64>,
+}
+
+impl ACLRole {
+    /// Create a new ACL role object with the specified name and policies.
+    ///
+    /// # Arguments
+    /// * `name` - The name of the ACL role.
+    /// * `policies` - The ACL policies bundled by the role.
+    ///
+    /// # Returns
+    /// A new `ACLRole` object.
+    pub fn new(name: String, policies: Vec<ACLRolePolicyLink>) -> Self {
+        Self {
+            id: None,
+            name,
+            description: None,
+            policies,
+            create_index: None,
+            modify_index: None,
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct ACLRoleListStub {
+    #[serde(rename = "ID")]
+    pub id: String,
+    pub name: String,
+    pub description: Option<String>,
+    pub policies: Vec<ACLRolePolicyLink>,
+    pub hash: Option<String>,
+    pub create_index: Option<u64>,
+    pub modify_index: Option<u64>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct ACLRolePolicyLink {
+    pub name: String,
+}
+
+impl ACLRolePolicyLink {
+    pub fn new(name: String) -> Self {
+        ACLRolePolicyLink { name }
+    }
+}
+
+pub struct Endpoint<'a> {
+    client: &'a Nomad,
+}
+
+impl<'a> Endpoint<'a> {
+    /// Create a new `Endpoint` with the given `Nomad` client to interact with
+    /// the ACL role endpoints.
+    pub fn new(client: &'a Nomad) -> Self {
+        Self { client }
+    }
+
+    /// Create a new ACL role in the Nomad cluster. The ID of the role is
+    /// generated by Nomad and must not be set.
+    ///
+    /// # Arguments
+    /// * `role` - The ACL role to create.
+    /// * `opts` - Optional write options for the request.
+    ///
+    /// # Returns
+    /// A `Result` containing the created ACL role object or an error if the
+    /// request fails.
+    pub async fn create(
+        &self,
+        role: &ACLRole,
+        opts: Option<WriteOptions>,
+    ) -> Result<ACLRole, ClientError> {
+        if role.id.as_deref().is_some_and(|id| !id.is_empty()) {
+            return Err(ClientError::InvalidInputError(
+                "ACL role ID must not be set".to_string(),
+            ));
+        }
+
+        let req = self
+            .client
+            .set_request_write_options(
+                self.client.build_request(Method::POST, "/v1/acl/role"),
+                &opts.unwrap_or_default(),
+            )
+            .json(role);
+        self.client.send_with_response::<ACLRole>(req).await
+    }
+
+    /// Delete an ACL role by its ID.
+    ///
+    /// # Arguments
+    /// * `id` - The ID of the ACL role to delete.
+    /// * `opts` - Optional write options for the request.
+    ///
+    /// # Returns
+    /// A `Result` indicating success or failure of the operation.
+    pub async fn delete(&self, id: &str, opts: Option<WriteOptions>) -> Result<(), ClientError> {
+        if id.is_empty() {
+            return Err(ClientError::InvalidInputError(
+                "ACL role ID must be set".to_string(),
+            ));
+        }
+
+        let req = self.client.set_request_write_options(
+            self.client
+                .build_request(Method::DELETE, &format!("/v1/acl/role/{}", id)),
+            &opts.unwrap_or_default(),
+        );
+        self.client.send_without_response(req).await
+    }
+
+    /// Get an ACL role by its ID.
+    ///
+    /// # Arguments
+    /// * `id` - The ID of the ACL role to retrieve.
+    /// * `opts` - Optional query options for the request.
+    ///
+    /// # Returns
+    /// A `Result` containing the ACL role object or an error if the request
+    /// fails.
+    pub async fn get(&self, id: &str, opts: Option<QueryOptions>) -> Result<ACLRole, ClientError> {
+        if id.is_empty() {
+            return Err(ClientError::InvalidInputError(
+                "ACL role ID must be set".to_string(),
+            ));
+        }
+
+        let req = self.client.set_request_query_options(
+            self.client
+                .build_request(Method::GET, &format!("/v1/acl/role/{}", id)),
+            &opts.unwrap_or_default(),
+        );
+        self.client.send_with_response::<ACLRole>(req).await
+    }
+
+    /// Get an ACL role by its name.
+    ///
+    /// # Arguments
+    /// * `name` - The name of the ACL role to retrieve.
+    /// * `opts` - Optional query options for the request.
+    ///
+    /// # Returns
+    /// A `Result` containing the ACL role object or an error if the request
+    /// fails.
+    pub async fn get_by_name(
+        &self,
+        name: &str,
+        opts: Option<QueryOptions>,
+    ) -> Result<ACLRole, ClientError> {
+        let req = self.client.set_request_query_options(
+            self.client
+                .build_request(Method::GET, &format!("/v1/acl/role/name/{}", name)),
+            &opts.unwrap_or_default(),
+        );
+        self.client.send_with_response::<ACLRole>(req).await
+    }
+
+    /// Get the list of ACL roles in the Nomad cluster.
+    ///
+    /// # Arguments
+    /// * `opts` - Optional query options to filter the results.
+    ///
+    /// # Returns
+    /// A `Result` containing a vector of `ACLRoleListStub` objects or an error
+    /// if the request fails.
+    pub async fn list(
+        &self,
+        opts: Option<QueryOptions>,
+    ) -> Result<Vec<ACLRoleListStub>, ClientError> {
+        let req = self.client.set_request_query_options(
+            self.client.build_request(Method::GET, "/v1/acl/roles"),
+            &opts.unwrap_or_default(),
+        );
+        self.client
+            .send_with_response::<Vec<ACLRoleListStub>>(req)
+            .await
+    }
+
+    /// Update an existing ACL role in the Nomad cluster.
+    ///
+    /// # Arguments
+    /// * `role` - The ACL role to update, which must have its ID set.
+    /// * `opts` - Optional write options for the request.
+    ///
+    /// # Returns
+    /// A `Result` containing the updated ACL role object or an error if the
+    /// request fails.
+    pub async fn update(
+        &self,
+        role: &ACLRole,
+        opts: Option<WriteOptions>,
+    ) -> Result<ACLRole, ClientError> {
+        let id = match role.id.as_deref() {
+            Some(id) if !id.is_empty() => id,
+            _ => {
+                return Err(ClientError::InvalidInputError(
+                    "ACL role ID must be set".to_string(),
+                ));
+            }
+        };
+
+        let req = self
+            .client
+            .set_request_write_options(
+                self.client
+                    .build_request(Method::POST, &format!("/v1/acl/role/{}", id)),
+                &opts.unwrap_or_default(),
+            )
+            .json(role);
+        self.client.send_with_response::<ACLRole>(req).await
+    }
+}
@@ -1,10 +1,12 @@
+use crate::filter::Filter;
 use crate::option::{QueryOptions, WriteOptions};
-use crate::{ClientError, Nomad};
+use crate::{ClientError, Nomad, REDACTED};
 use reqwest::Method;
 use serde::{Deserialize, Serialize};
+use std::fmt;
 use time;
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Serialize, Deserialize)]
 #[serde(rename_all = "PascalCase")]
 pub struct ACLTokenBootstrapRequest {
     pub bootstrap_secret: String,
@@ -16,6 +18,14 @@ impl ACLTokenBootstrapRequest {
     }
 }
 
+impl fmt::Debug for ACLTokenBootstrapRequest {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ACLTokenBootstrapRequest")
+            .field("bootstrap_secret", &REDACTED)
+            .finish()
+    }
+}
+
 #[derive(Debug, Serialize)]
 #[serde(rename_all = "PascalCase")]
 pub struct ACLTokenCreateRequest {
@@ -43,7 +53,7 @@ impl ACLTokenCreateRequest {
     }
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Serialize, Deserialize)]
 #[serde(rename_all = "PascalCase")]
 pub struct ACLToken {
     #[serde(rename = "AccessorID")]
@@ -60,11 +70,76 @@ pub struct ACLToken {
     pub create_time: time::OffsetDateTime,
     #[serde(with = "time::serde::rfc3339::option")]
     pub expiration_time: Option<time::OffsetDateTime>,
+    #[serde(
+        rename = "ExpirationTTL",
+        default,
+        with = "crate::duration_nanos::option"
+    )]
     pub expiration_ttl: Option<time::Duration>,
     pub create_index: Option<u64>,
     pub modify_index: Option<u64>,
 }
 
+impl fmt::Debug for ACLToken {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ACLToken")
+            .field("accessor_id", &self.accessor_id)
+            .field("secret_id", &REDACTED)
+            .field("name", &self.name)
+            .field("token_type", &self.token_type)
+            .field("policies", &self.policies)
+            .field("roles", &self.roles)
+            .field("global", &self.global)
+            .field("create_time", &self.create_time)
+            .field("expiration_time", &self.expiration_time)
+            .field("expiration_ttl", &self.expiration_ttl)
+            .field("create_index", &self.create_index)
+            .field("modify_index", &self.modify_index)
+            .finish()
+    }
+}
+
+impl ACLToken {
+    /// Check whether the given secret matches the secret ID of the token. The
+    /// comparison takes the same time regardless of where the secrets differ,
+    /// so it does not leak the secret through timing.
+    ///
+    /// # Arguments
+    /// * `secret` - The secret to compare against the token's secret ID.
+    ///
+    /// # Returns
+    /// `true` if the secret matches the token's secret ID.
+    pub fn secret_matches(&self, secret: &str) -> bool {
+        constant_time_eq(self.secret_id.as_bytes(), secret.as_bytes())
+    }
+
+    /// Check whether the token has expired at the given point in time. Tokens
+    /// without an expiration time never expire.
+    ///
+    /// # Arguments
+    /// * `now` - The point in time to check the expiration against.
+    ///
+    /// # Returns
+    /// `true` if the token has an expiration time which is not after `now`.
+    pub fn is_expired(&self, now: time::OffsetDateTime) -> bool {
+        self.expiration_time
+            .is_some_and(|expiration_time| expiration_time <= now)
+    }
+
+    /// Get the remaining lifetime of the token at the given point in time.
+    ///
+    /// # Arguments
+    /// * `now` - The point in time to calculate the remaining lifetime from.
+    ///
+    /// # Returns
+    /// The remaining lifetime, which is zero if the token has already expired,
+    /// or `None` if the token does not expire.
+    pub fn time_until_expiry(&self, now: time::OffsetDateTime) -> Option<time::Duration> {
+        self.expiration_time
+            .map(|expiration_time| (expiration_time - now).max(time::Duration::ZERO))
+    }
+}
+
 #[derive(Debug, Serialize, Deserialize)]
 #[serde(rename_all = "PascalCase")]
 pub struct ACLTokenStub {
@@ -197,6 +272,24 @@ impl<'a> Endpoint<'a> {
         self.client.send_with_response::<ACLToken>(req).await
     }
 
+    /// Get an ACL token by its accessor ID, returning `None` rather than an
+    /// error when it does not exist.
+    ///
+    /// # Arguments
+    /// * `accessor_id` - The accessor ID of the ACL token to retrieve.
+    /// * `opts` - Optional query options for the request.
+    ///
+    /// # Returns
+    /// A `Result` containing the ACL token, `None` if it does not exist, or an
+    /// error if the request fails.
+    pub async fn get_optional(
+        &self,
+        accessor_id: &str,
+        opts: Option<QueryOptions>,
+    ) -> Result<Option<ACLToken>, ClientError> {
+        crate::not_found_to_none(self.get(accessor_id, opts).await)
+    }
+
     /// Get an ACL token for the token used to authenticate the request.
     ///
     /// # Arguments
@@ -230,4 +323,116 @@ impl<'a> Endpoint<'a> {
             .send_with_response::<Vec<ACLTokenStub>>(req)
             .await
     }
+
+    /// Get the list of ACL tokens which are, or are not, global and which are
+    /// linked to a policy.
+    ///
+    /// The tokens are selected server-side using a filter expression on
+    /// `Global` and `Policies`. Any filter set within `opts` is combined with
+    /// it using `and`.
+    ///
+    /// # Arguments
+    /// * `global` - Optional requirement for the tokens to be global, or to be
+    ///   local to the region.
+    /// * `policy` - Optional name of a policy the tokens must be linked to.
+    /// * `opts` - Optional query options for the request.
+    ///
+    /// # Returns
+    /// A `Result` containing a vector of `ACLTokenStub` objects or an error if
+    /// the request fails.
+    pub async fn list_filtered(
+        &self,
+        global: Option<bool>,
+        policy: Option<&str>,
+        opts: Option<QueryOptions>,
+    ) -> Result<Vec<ACLTokenStub>, ClientError> {
+        let filter = [
+            global.map(|global| Filter::eq("Global", global)),
+            policy.map(|policy| Filter::contains("Policies", policy)),
+        ]
+        .into_iter()
+        .flatten()
+        .reduce(Filter::and);
+
+        let mut opts = opts.unwrap_or_default();
+        if let Some(filter) = filter {
+            opts = opts.and_filter_expr(filter);
+        }
+        self.list(Some(opts)).await
+    }
+
+    /// Get the list of global ACL tokens, which are replicated to every
+    /// region.
+    ///
+    /// # Arguments
+    /// * `opts` - Optional query options for the request.
+    ///
+    /// # Returns
+    /// A `Result` containing a vector of `ACLTokenStub` objects or an error if
+    /// the request fails.
+    pub async fn list_global(
+        &self,
+        opts: Option<QueryOptions>,
+    ) -> Result<Vec<ACLTokenStub>, ClientError> {
+        self.list_filtered(Some(true), None, opts).await
+    }
+
+    /// Renew an ACL token by its accessor ID, extending its expiration time by
+    /// its expiration TTL.
+    ///
+    /// # Arguments
+    /// * `accessor_id` - The accessor ID of the ACL token to renew.
+    /// * `opts` - Optional write options for the request.
+    ///
+    /// # Returns
+    /// A `Result` containing the renewed ACL token object or an error if the
+    /// request fails.
+    pub async fn renew(
+        &self,
+        accessor_id: &str,
+        opts: Option<WriteOptions>,
+    ) -> Result<ACLToken, ClientError> {
+        if accessor_id.is_empty() {
+            return Err(ClientError::InvalidInputError(
+                "ACL token accessor ID must be set".to_string(),
+            ));
+        }
+
+        let req = self.client.set_request_write_options(
+            self.client.build_request(
+                Method::POST,
+                &format!("/v1/acl/token/{}/renew", accessor_id),
+            ),
+            &opts.unwrap_or_default(),
+        );
+        self.client.send_with_response::<ACLToken>(req).await
+    }
+
+    /// Renew the ACL token used to authenticate the request, extending its
+    /// expiration time by its expiration TTL.
+    ///
+    /// # Arguments
+    /// * `opts` - Optional write options for the request.
+    ///
+    /// # Returns
+    /// A `Result` containing the renewed ACL token object or an error if the
+    /// request fails.
+    pub async fn renew_self(&self, opts: Option<WriteOptions>) -> Result<ACLToken, ClientError> {
+        let req = self.client.set_request_write_options(
+            self.client
+                .build_request(Method::POST, "/v1/acl/token/self/renew"),
+            &opts.unwrap_or_default(),
+        );
+        self.client.send_with_response::<ACLToken>(req).await
+    }
+}
+
+/// Compare two byte slices in constant time with respect to their contents.
+/// Only the lengths, which are not secret for Nomad's fixed length UUID
+/// secrets, can influence the time taken.
+fn constant_time_eq(a: &[u8], b: &[u8]) -> bool {
+    if a.len() != b.len() {
+        return false;
+    }
+    a.iter().zip(b).fold(0u8, |diff, (x, y)| diff | (x ^ y)) == 0
 }
@@ -0,0 +1,512 @@
+use crate::option::QueryOptions;
+use crate::{ClientError, Nomad};
+use reqwest::Method;
+use serde::Deserialize;
+use std::collections::HashMap;
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub struct AgentSelf {
+    pub config: Option<HashMap<String, serde_json::Value>>,
+    pub member: AgentMember,
+    pub stats: Option<HashMap<String, HashMap<String, String>>>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct AgentMember {
+    pub name: String,
+    pub addr: String,
+    pub port: u16,
+    pub tags: Option<HashMap<String, String>>,
+    pub status: String,
+    pub protocol_min: u8,
+    pub protocol_max: u8,
+    pub protocol_cur: u8,
+    pub delegate_min: u8,
+    pub delegate_max: u8,
+    pub delegate_cur: u8,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct ServerMembers {
+    pub server_name: String,
+    pub server_region: String,
+    #[serde(rename = "ServerDC")]
+    pub server_dc: String,
+    pub members: Vec<AgentMember>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub struct AgentHealth {
+    pub client: Option<AgentHealthStatus>,
+    pub server: Option<AgentHealthStatus>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub struct AgentHealthStatus {
+    pub ok: bool,
+    pub message: String,
+}
+
+/// The host information of an agent, as returned by the agent host endpoint.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct AgentHostData {
+    #[serde(rename = "AgentID")]
+    pub agent_id: String,
+    pub host_data: Option<HostData>,
+}
+
+/// Operating system level information about the host an agent runs on.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct HostData {
+    /// The operating system and kernel details, as reported by `uname`.
+    #[serde(rename = "OS")]
+    pub os: String,
+    /// The network interfaces of the host, each as a map of its attributes.
+    pub network: Option<Vec<HashMap<String, String>>>,
+    /// The contents of the host's `/etc/resolv.conf`.
+    pub resolv_conf: String,
+    /// The contents of the host's `/etc/hosts`.
+    pub hosts: String,
+    /// The environment variables of the agent process.
+    pub environment: Option<HashMap<String, String>>,
+    /// The disk usage of the agent's data directories, keyed by path.
+    pub disk: Option<HashMap<String, HostDiskUsage>>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct HostDiskUsage {
+    #[serde(rename = "DiskMB")]
+    pub disk_mb: i64,
+    #[serde(rename = "UsedMB")]
+    pub used_mb: i64,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub struct AgentJoinResponse {
+    pub num_joined: u32,
+    pub error: String,
+}
+
+impl AgentSelf {
+    /// The region of the agent, read from its configuration and falling back
+    /// to the `region` member tag.
+    pub fn region(&self) -> Option<&str> {
+        self.config
+            .as_ref()
+            .and_then(|config| config.get("Region"))
+            .and_then(|region| region.as_str())
+            .or_else(|| {
+                self.member
+                    .tags
+                    .as_ref()
+                    .and_then(|tags| tags.get("region"))
+                    .map(String::as_str)
+            })
+            .filter(|region| !region.is_empty())
+    }
+}
+
+/// The version and build information of a Nomad agent.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AgentVersion {
+    /// The version, including any prerelease suffix, such as `1.8.0` or
+    /// `1.9.0-beta.1`.
+    pub version: String,
+    pub revision: Option<String>,
+    pub build_date: Option<String>,
+}
+
+/// Nomad features which are only available from a specific version.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum VersionFeature {
+    /// Nomad Variables, available from 1.4.0.
+    Variables,
+    /// Node pools, available from 1.6.0.
+    NodePools,
+}
+
+impl VersionFeature {
+    /// The minimum version which supports the feature, as
+    /// `(major, minor, patch)`.
+    pub fn min_version(&self) -> (u64, u64, u64) {
+        match self {
+            VersionFeature::Variables => (1, 4, 0),
+            VersionFeature::NodePools => (1, 6, 0),
+        }
+    }
+}
+
+impl AgentVersion {
+    /// Extract the version from the configuration of an agent, falling back
+    /// to the `build` member tag when the configuration does not include it.
+    pub fn from_agent_self(agent: &AgentSelf) -> Option<Self> {
+        let info = agent
+            .config
+            .as_ref()
+            .and_then(|config| config.get("Version"))
+            .and_then(|version| version.as_object());
+        let field = |name: &str| {
+            info.and_then(|info| info.get(name))
+                .and_then(|value| value.as_str())
+                .filter(|value| !value.is_empty())
+                .map(str::to_string)
+        };
+
+        match field("Version") {
+            Some(version) => Some(Self {
+                version: match field("VersionPrerelease") {
+                    Some(prerelease) => format!("{}-{}", version, prerelease),
+                    None => version,
+                },
+                revision: field("Revision"),
+                build_date: field("BuildDate"),
+            }),
+            None => agent
+                .member
+                .tags
+                .as_ref()
+                .and_then(|tags| tags.get("build"))
+                .map(|build| Self {
+                    version: build.clone(),
+                    revision: None,
+                    build_date: None,
+                }),
+        }
+    }
+
+    /// Parse the numeric part of the version as `(major, minor, patch)`,
+    /// ignoring any prerelease or metadata suffix.
+    pub fn numeric(&self) -> Option<(u64, u64, u64)> {
+        let core = self
+            .version
+            .trim_start_matches('v')
+            .split(['-', '+'])
+            .next()?;
+        let mut parts = core.split('.').map(|part| part.parse::<u64>().ok());
+        let major = parts.next()??;
+        let minor = parts.next().unwrap_or(Some(0))?;
+        let patch = parts.next().unwrap_or(Some(0))?;
+        Some((major, minor, patch))
+    }
+
+    /// Whether the agent version supports the given feature. A version which
+    /// cannot be parsed is treated as not supporting any feature.
+    pub fn supports(&self, feature: VersionFeature) -> bool {
+        self.numeric()
+            .is_some_and(|version| version >= feature.min_version())
+    }
+}
+
+/// A single frame of the agent monitor stream. Nomad periodically sends
+/// heartbeat frames which carry no data.
+#[cfg(feature = "stream")]
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct MonitorFrame {
+    /// The base64 encoded log output carried by the frame.
+    #[serde(default)]
+    pub data: Option<String>,
+    #[serde(default)]
+    pub offset: i64,
+    #[serde(default)]
+    pub file_event: Option<String>,
+}
+
+/// Decodes the raw chunks returned by `Endpoint::monitor` into plain log
+/// lines. Frames and lines may be split across chunks, so any incomplete
+/// remainder is buffered until the following chunk arrives.
+#[cfg(feature = "stream")]
+#[derive(Debug, Default)]
+pub struct MonitorLogDecoder {
+    frames: Vec<u8>,
+    line: Vec<u8>,
+}
+
+#[cfg(feature = "stream")]
+impl MonitorLogDecoder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Decode a chunk of the monitor stream.
+    ///
+    /// # Arguments
+    /// * `chunk` - The next chunk of bytes read from the stream.
+    ///
+    /// # Returns
+    /// A `Result` containing the log lines completed by the chunk, without
+    /// their trailing newline, or an error if a frame could not be decoded.
+    pub fn decode(&mut self, chunk: &[u8]) -> Result<Vec<String>, ClientError> {
+        use base64::Engine;
+
+        self.frames.extend_from_slice(chunk);
+
+        let mut lines = Vec::new();
+        let mut frames =
+            serde_json::Deserializer::from_slice(&self.frames).into_iter::<MonitorFrame>();
+        loop {
+            match frames.next() {
+                Some(Ok(frame)) => {
+                    let Some(data) = frame.data else { continue };
+                    let decoded = base64::engine::general_purpose::STANDARD
+                        .decode(data)
+                        .map_err(|err| monitor_decode_error(err.to_string(), &self.frames))?;
+                    self.line.extend_from_slice(&decoded);
+                }
+                Some(Err(err)) if err.is_eof() => break,
+                Some(Err(err)) => return Err(monitor_decode_error(err.to_string(), &self.frames)),
+                None => break,
+            }
+        }
+        let consumed = frames.byte_offset();
+        self.frames.drain(..consumed);
+
+        while let Some(pos) = self.line.iter().position(|b| *b == b'\n') {
+            let line: Vec<u8> = self.line.drain(..=pos).collect();
+            lines.push(
+                String::from_utf8_lossy(&line[..pos])
+                    .trim_end_matches('\r')
+                    .to_string(),
+            );
+        }
+        Ok(lines)
+    }
+
+    /// Consume the decoder once the stream has ended.
+    ///
+    /// # Returns
+    /// The final log line if it was not terminated by a newline.
+    pub fn finish(self) -> Option<String> {
+        match self.line.is_empty() {
+            true => None,
+            false => Some(String::from_utf8_lossy(&self.line).into_owned()),
+        }
+    }
+}
+
+#[cfg(feature = "stream")]
+fn monitor_decode_error(message: String, frames: &[u8]) -> ClientError {
+    let snippet = &frames[..frames.len().min(crate::DESERIALIZATION_ERROR_SNIPPET_LEN)];
+    ClientError::DeserializationError {
+        status: reqwest::StatusCode::OK.as_u16(),
+        message,
+        body_snippet: String::from_utf8_lossy(snippet).into_owned(),
+    }
+}
+
+pub struct Endpoint<'a> {
+    client: &'a Nomad,
+}
+
+impl<'a> Endpoint<'a> {
+    /// Create a new `Endpoint` with the given `Nomad` client to interact with
+    /// the agent endpoints.
+    pub fn new(client: &'a Nomad) -> Self {
+        Self { client }
+    }
+
+    /// Force a failed gossip member into the left state, removing it from the
+    /// member list of the agent.
+    ///
+    /// # Arguments
+    /// * `node` - The name of the member to force leave.
+    ///
+    /// # Returns
+    /// A `Result` indicating success or failure of the operation.
+    pub async fn force_leave(&self, node: &str) -> Result<(), ClientError> {
+        if node.is_empty() {
+            return Err(ClientError::InvalidInputError(
+                "Node must be set".to_string(),
+            ));
+        }
+
+        let req = self
+            .client
+            .build_request(Method::POST, "/v1/agent/force-leave")
+            .query(&[("node", node)]);
+        self.client.send_without_response(req).await
+    }
+
+    /// Get the health of the agent the client is connected to. Nomad responds
+    /// with a non-success status code if the agent is unhealthy.
+    ///
+    /// # Returns
+    /// A `Result` containing the `AgentHealth` object or an error if the
+    /// request fails.
+    pub async fn health(&self) -> Result<AgentHealth, ClientError> {
+        let req = self.client.build_request(Method::GET, "/v1/agent/health");
+        self.client.send_with_response::<AgentHealth>(req).await
+    }
+
+    /// Get operating system level information about the host an agent runs
+    /// on. Without a node or server ID, the agent the client is connected to
+    /// is targeted.
+    ///
+    /// # Arguments
+    /// * `node_id` - The optional ID of the client agent to read the host
+    ///   information of.
+    /// * `server_id` - The optional name of the server agent to read the host
+    ///   information of, or `leader` to target the current leader.
+    /// * `opts` - Optional query options for the request.
+    ///
+    /// # Returns
+    /// A `Result` containing the `AgentHostData` object or an error if the
+    /// request fails.
+    pub async fn host(
+        &self,
+        node_id: Option<&str>,
+        server_id: Option<&str>,
+        opts: Option<QueryOptions>,
+    ) -> Result<AgentHostData, ClientError> {
+        let mut query = Vec::new();
+        if let Some(node_id) = node_id {
+            query.push(("node_id", node_id));
+        }
+        if let Some(server_id) = server_id {
+            query.push(("server_id", server_id));
+        }
+
+        let req = self
+            .client
+            .set_request_query_options(
+                self.client.build_request(Method::GET, "/v1/agent/host"),
+                &opts.unwrap_or_default(),
+            )
+            .query(&query);
+        self.client.send_with_response::<AgentHostData>(req).await
+    }
+
+    /// Instruct the agent to join the gossip pool of one or more existing
+    /// server agents.
+    ///
+    /// # Arguments
+    /// * `addresses` - The addresses of the agents to join. Each address is
+    ///   sent as a separate `address` query parameter.
+    ///
+    /// # Returns
+    /// A `Result` containing the `AgentJoinResponse` object or an error if the
+    /// request fails.
+    pub async fn join(&self, addresses: &[&str]) -> Result<AgentJoinResponse, ClientError> {
+        if addresses.is_empty() {
+            return Err(ClientError::InvalidInputError(
+                "At least one address must be set".to_string(),
+            ));
+        }
+
+        let query: Vec<(&str, &str)> = addresses.iter().map(|addr| ("address", *addr)).collect();
+        let req = self
+            .client
+            .build_request(Method::POST, "/v1/agent/join")
+            .query(&query);
+        self.client
+            .send_with_response::<AgentJoinResponse>(req)
+            .await
+    }
+
+    /// Get the list of server members known to the agent's gossip pool.
+    ///
+    /// # Returns
+    /// A `Result` containing the `ServerMembers` object or an error if the
+    /// request fails.
+    pub async fn members(&self) -> Result<ServerMembers, ClientError> {
+        let req = self.client.build_request(Method::GET, "/v1/agent/members");
+        self.client.send_with_response::<ServerMembers>(req).await
+    }
+
+    /// Stream the logs of an agent. By default the logs of the agent the client
+    /// is connected to are streamed; `node_id` or `server_id` can be used to
+    /// target another client or server agent.
+    ///
+    /// The stream yields the raw chunks of the response, which contain JSON
+    /// encoded frames with base64 encoded log output. Use
+    /// `MonitorLogDecoder` to turn the chunks into plain log lines. The stream
+    /// stays open until it is dropped.
+    ///
+    /// # Arguments
+    /// * `log_level` - The minimum level of the logs to stream. One of
+    ///   `trace`, `debug`, `info`, `warn`, or `error`.
+    /// * `node_id` - The optional ID of the client agent to stream logs from.
+    /// * `server_id` - The optional name of the server agent to stream logs
+    ///   from, or `leader` to target the current leader.
+    /// * `opts` - Optional query options for the request.
+    ///
+    /// # Returns
+    /// A `Result` containing a stream of frame chunks or an error if the
+    /// request fails.
+    #[cfg(feature = "stream")]
+    pub async fn monitor(
+        &self,
+        log_level: &str,
+        node_id: Option<&str>,
+        server_id: Option<&str>,
+        opts: Option<QueryOptions>,
+    ) -> Result<
+        impl futures_util::Stream<Item = Result<bytes::Bytes, ClientError>> + use<>,
+        ClientError,
+    > {
+        if log_level.is_empty() {
+            return Err(ClientError::InvalidInputError(
+                "Log level must be set".to_string(),
+            ));
+        }
+
+        let mut query = vec![("log_level", log_level)];
+        if let Some(node_id) = node_id {
+            query.push(("node_id", node_id));
+        }
+        if let Some(server_id) = server_id {
+            query.push(("server_id", server_id));
+        }
+
+        let req = self
+            .client
+            .set_request_query_options(
+                self.client.build_request(Method::GET, "/v1/agent/monitor"),
+                &opts.unwrap_or_default(),
+            )
+            .query(&query);
+        self.client.send_stream(req).await
+    }
+
+    /// Get the configuration, member information, and stats of the agent the
+    /// client is connected to.
+    ///
+    /// # Arguments
+    /// * `opts` - Optional query options for the request.
+    ///
+    /// # Returns
+    /// A `Result` containing the `AgentSelf` object or an error if the request
+    /// fails.
+    pub async fn self_(&self, opts: Option<QueryOptions>) -> Result<AgentSelf, ClientError> {
+        let req = self.client.set_request_query_options(
+            self.client.build_request(Method::GET, "/v1/agent/self"),
+            &opts.unwrap_or_default(),
+        );
+        self.client.send_with_response::<AgentSelf>(req).await
+    }
+
+    /// Get the version and build information of the agent the client is
+    /// connected to.
+    ///
+    /// # Arguments
+    /// * `opts` - Optional query options for the request.
+    ///
+    /// # Returns
+    /// A `Result` containing the `AgentVersion` or an error if the request
+    /// fails or the agent does not report its version.
+    pub async fn version(&self, opts: Option<QueryOptions>) -> Result<AgentVersion, ClientError> {
+        let agent = self.self_(opts).await?;
+        AgentVersion::from_agent_self(&agent).ok_or_else(|| {
+            ClientError::IncompleteResponse("Agent did not report its version".to_string())
+        })
+    }
+}
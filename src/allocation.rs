@@ -1,6 +1,69 @@
-use serde::Deserialize;
+use crate::job::{Job, NetworkResource, TaskResources};
+use crate::option::{QueryOptions, SortOrder, WriteOptions};
+use crate::service::ServiceRegistration;
+use crate::{ClientError, Nomad};
+use bytes::Bytes;
+use reqwest::Method;
+use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct Allocation {
+    #[serde(rename = "ID")]
+    pub id: String,
+    pub namespace: String,
+    #[serde(rename = "EvalID")]
+    pub eval_id: String,
+    pub name: String,
+    #[serde(rename = "NodeID")]
+    pub node_id: String,
+    pub node_name: String,
+    #[serde(rename = "JobID")]
+    pub job_id: String,
+    pub job: Option<Job>,
+    pub task_group: String,
+    pub resources: Option<TaskResources>,
+    pub task_resources: Option<HashMap<String, TaskResources>>,
+    /// The resources assigned to the allocation by the scheduler, as opposed
+    /// to the resources requested by the job.
+    pub allocated_resources: Option<AllocatedResources>,
+    pub services: Option<HashMap<String, String>>,
+    pub metrics: Option<AllocationMetric>,
+    pub desired_status: String,
+    pub desired_description: String,
+    pub desired_transition: Option<AllocationDesiredTransition>,
+    pub client_status: String,
+    pub client_description: String,
+    pub task_states: Option<HashMap<String, AllocationTaskState>>,
+    #[serde(rename = "DeploymentID")]
+    pub deployment_id: Option<String>,
+    pub deployment_status: Option<AllocationDeploymentStatus>,
+    #[serde(rename = "FollowupEvalID")]
+    pub followup_eval_id: Option<String>,
+    pub previous_allocation: Option<String>,
+    pub next_allocation: Option<String>,
+    pub preempted_allocations: Option<Vec<String>>,
+    pub preempted_by_allocation: Option<String>,
+    pub create_index: u64,
+    pub modify_index: u64,
+    pub alloc_modify_index: u64,
+    pub create_time: i64,
+    pub modify_time: i64,
+}
+
+impl Allocation {
+    /// The client status of the allocation as a typed `ClientStatus`.
+    pub fn client_status_enum(&self) -> ClientStatus {
+        ClientStatus::from(self.client_status.as_str())
+    }
+
+    /// The desired status of the allocation as a typed `DesiredStatus`.
+    pub fn desired_status_enum(&self) -> DesiredStatus {
+        DesiredStatus::from(self.desired_status.as_str())
+    }
+}
+
 #[derive(Debug, Clone, Deserialize)]
 #[serde(rename_all = "PascalCase")]
 pub struct AllocationStub {
@@ -26,6 +89,7 @@ pub struct AllocationStub {
     #[serde(rename = "DeploymentID")]
     pub deployment_id: Option<String>,
     pub deployment_status: Option<AllocationDeploymentStatus>,
+    #[serde(rename = "FollowupEvalID")]
     pub followup_eval_id: Option<String>,
     pub preempted_allocations: Option<Vec<String>>,
     pub preempted_by_allocation: String,
@@ -35,6 +99,63 @@ pub struct AllocationStub {
     pub modify_time: i64,
 }
 
+impl AllocationStub {
+    /// The client status of the allocation as a typed `ClientStatus`.
+    pub fn client_status_enum(&self) -> ClientStatus {
+        ClientStatus::from(self.client_status.as_str())
+    }
+
+    /// The desired status of the allocation as a typed `DesiredStatus`.
+    pub fn desired_status_enum(&self) -> DesiredStatus {
+        DesiredStatus::from(self.desired_status.as_str())
+    }
+
+    /// Whether the allocation has stopped, or has been told to stop, and will
+    /// not run again. Mirrors Nomad's terminal status check, which considers
+    /// both the desired and the client status.
+    pub fn is_terminal(&self) -> bool {
+        matches!(
+            self.desired_status_enum(),
+            DesiredStatus::Stop | DesiredStatus::Evict
+        ) || self.client_status_enum().is_terminal()
+    }
+
+    /// Whether the allocation was lost, typically because the node running it
+    /// failed or stopped heartbeating.
+    pub fn is_lost(&self) -> bool {
+        self.client_status_enum() == ClientStatus::Lost
+    }
+
+    /// Whether the allocation failed or was lost while Nomad still wants it
+    /// running, and no follow-up evaluation has been created to replace it
+    /// yet.
+    pub fn needs_reschedule(&self) -> bool {
+        self.desired_status_enum() == DesiredStatus::Run
+            && matches!(
+                self.client_status_enum(),
+                ClientStatus::Failed | ClientStatus::Lost
+            )
+            && self
+                .followup_eval_id
+                .as_deref()
+                .is_none_or(|id| id.is_empty())
+    }
+
+    /// The most recent task event across all of the allocation's tasks,
+    /// ordered by event time.
+    ///
+    /// # Returns
+    /// The latest `AllocationTaskEvent`, or `None` if no task has recorded any
+    /// events.
+    pub fn last_task_event(&self) -> Option<&AllocationTaskEvent> {
+        self.task_states
+            .iter()
+            .flat_map(|states| states.values())
+            .flat_map(|state| state.events.iter().flatten())
+            .max_by_key(|event| event.time)
+    }
+}
+
 #[derive(Debug, Clone, Deserialize)]
 #[serde(rename_all = "PascalCase")]
 pub struct AllocationTaskState {
@@ -85,6 +206,15 @@ pub struct AllocationDeploymentStatus {
     pub modify_index: u64,
 }
 
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct AllocationDesiredTransition {
+    pub migrate: Option<bool>,
+    pub reschedule: Option<bool>,
+    pub force_reschedule: Option<bool>,
+    pub no_shutdown_delay: Option<bool>,
+}
+
 #[derive(Debug, Clone, Deserialize)]
 #[serde(rename_all = "PascalCase")]
 pub struct AllocationMetric {
@@ -112,3 +242,871 @@ pub struct AllocationResourceExhausted {
     pub memory_mb: i32,
     pub disk_mb: i32,
 }
+
+/// The resources the scheduler assigned to an allocation.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct AllocatedResources {
+    pub tasks: Option<HashMap<String, AllocatedTaskResources>>,
+    pub shared: AllocatedSharedResources,
+}
+
+/// The resources assigned to a single task of an allocation.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct AllocatedTaskResources {
+    pub cpu: AllocatedCpuResources,
+    pub memory: AllocatedMemoryResources,
+    pub networks: Option<Vec<NetworkResource>>,
+    pub devices: Option<Vec<AllocatedDeviceResource>>,
+}
+
+/// The resources shared by every task of an allocation.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct AllocatedSharedResources {
+    #[serde(rename = "DiskMB")]
+    pub disk_mb: i64,
+    pub networks: Option<Vec<NetworkResource>>,
+    pub ports: Option<Vec<AllocatedPortMapping>>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct AllocatedCpuResources {
+    pub cpu_shares: i64,
+    /// The cores reserved for the task, when it requested whole cores rather
+    /// than CPU shares.
+    pub reserved_cores: Option<Vec<u16>>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct AllocatedMemoryResources {
+    #[serde(rename = "MemoryMB")]
+    pub memory_mb: i64,
+    /// The memory limit the task may burst to, which is zero when memory
+    /// oversubscription is not used.
+    #[serde(rename = "MemoryMaxMB")]
+    pub memory_max_mb: i64,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct AllocatedDeviceResource {
+    pub vendor: String,
+    #[serde(rename = "Type")]
+    pub device_type: String,
+    pub name: String,
+    #[serde(rename = "DeviceIDs")]
+    pub device_ids: Vec<String>,
+}
+
+/// A port assigned to an allocation, mapping the host port to the port within
+/// the allocation's network namespace.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct AllocatedPortMapping {
+    pub label: String,
+    pub value: i32,
+    pub to: i32,
+    #[serde(rename = "HostIP")]
+    pub host_ip: String,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct AllocStopResponse {
+    #[serde(rename = "EvalID")]
+    pub eval_id: String,
+    #[serde(rename = "Index")]
+    pub eval_create_index: u64,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "PascalCase")]
+struct AllocRestartRequest<'a> {
+    task_name: Option<&'a str>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "PascalCase")]
+struct AllocSignalRequest<'a> {
+    task: Option<&'a str>,
+    signal: &'a str,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct AllocFileInfo {
+    pub name: String,
+    pub is_dir: bool,
+    pub size: i64,
+    pub file_mode: String,
+    #[serde(rename = "ModTime", with = "time::serde::rfc3339")]
+    pub modified_time: time::OffsetDateTime,
+    pub content_type: Option<String>,
+}
+
+/// The latest result of a Nomad service check, as stored by the client running
+/// the allocation.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct AllocCheckStatus {
+    #[serde(rename = "ID")]
+    pub id: String,
+    pub check: String,
+    pub group: String,
+    pub task: Option<String>,
+    pub service: String,
+    /// The result of the check, such as `success`, `failure`, or `pending`.
+    pub status: String,
+    /// The HTTP status code of the check response, which is zero for checks
+    /// which are not HTTP checks.
+    pub status_code: Option<i32>,
+    pub output: Option<String>,
+    /// The time the check was run, in seconds since the Unix epoch.
+    pub timestamp: i64,
+    /// Whether the check determines the health or the readiness of the
+    /// service.
+    pub mode: String,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct AllocResourceUsage {
+    pub resource_usage: ResourceUsage,
+    pub tasks: HashMap<String, TaskResourceUsage>,
+    /// The time the stats were collected, in nanoseconds since the Unix epoch.
+    pub timestamp: i64,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct TaskResourceUsage {
+    pub resource_usage: ResourceUsage,
+    /// The time the stats were collected, in nanoseconds since the Unix epoch.
+    pub timestamp: i64,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct ResourceUsage {
+    pub memory_stats: MemoryStats,
+    pub cpu_stats: CpuStats,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct MemoryStats {
+    #[serde(rename = "RSS")]
+    pub rss: u64,
+    pub cache: u64,
+    pub swap: u64,
+    pub mapped_file: Option<u64>,
+    pub usage: Option<u64>,
+    pub max_usage: Option<u64>,
+    pub kernel_usage: Option<u64>,
+    pub kernel_max_usage: Option<u64>,
+    pub measured: Option<Vec<String>>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct CpuStats {
+    pub system_mode: f64,
+    pub user_mode: f64,
+    pub total_ticks: f64,
+    pub throttled_periods: u64,
+    pub throttled_time: u64,
+    pub percent: f64,
+    pub measured: Option<Vec<String>>,
+}
+
+/// The output stream of a task to read logs from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LogType {
+    Stdout,
+    Stderr,
+}
+
+impl LogType {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            LogType::Stdout => "stdout",
+            LogType::Stderr => "stderr",
+        }
+    }
+}
+
+/// The status of an allocation as reported by the client running it.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(from = "String", into = "String")]
+pub enum ClientStatus {
+    Pending,
+    Running,
+    Complete,
+    Failed,
+    Lost,
+    /// The allocation is on a client which has disconnected from the servers
+    /// and its current state is not known.
+    Unknown,
+    /// Any other status, holding the raw value.
+    Other(String),
+}
+
+impl ClientStatus {
+    pub fn as_str(&self) -> &str {
+        match self {
+            ClientStatus::Pending => "pending",
+            ClientStatus::Running => "running",
+            ClientStatus::Complete => "complete",
+            ClientStatus::Failed => "failed",
+            ClientStatus::Lost => "lost",
+            ClientStatus::Unknown => "unknown",
+            ClientStatus::Other(status) => status,
+        }
+    }
+
+    /// Whether the allocation has stopped running and will not be restarted
+    /// by the client.
+    pub fn is_terminal(&self) -> bool {
+        matches!(
+            self,
+            ClientStatus::Complete | ClientStatus::Failed | ClientStatus::Lost
+        )
+    }
+}
+
+impl From<&str> for ClientStatus {
+    fn from(status: &str) -> Self {
+        match status {
+            "pending" => ClientStatus::Pending,
+            "running" => ClientStatus::Running,
+            "complete" => ClientStatus::Complete,
+            "failed" => ClientStatus::Failed,
+            "lost" => ClientStatus::Lost,
+            "unknown" => ClientStatus::Unknown,
+            other => ClientStatus::Other(other.to_string()),
+        }
+    }
+}
+
+impl From<String> for ClientStatus {
+    fn from(status: String) -> Self {
+        ClientStatus::from(status.as_str())
+    }
+}
+
+impl From<ClientStatus> for String {
+    fn from(status: ClientStatus) -> Self {
+        status.as_str().to_string()
+    }
+}
+
+/// The status the scheduler wants an allocation to be in.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(from = "String", into = "String")]
+pub enum DesiredStatus {
+    Run,
+    Stop,
+    Evict,
+    /// Any other status, holding the raw value.
+    Other(String),
+}
+
+impl DesiredStatus {
+    pub fn as_str(&self) -> &str {
+        match self {
+            DesiredStatus::Run => "run",
+            DesiredStatus::Stop => "stop",
+            DesiredStatus::Evict => "evict",
+            DesiredStatus::Other(status) => status,
+        }
+    }
+}
+
+impl From<&str> for DesiredStatus {
+    fn from(status: &str) -> Self {
+        match status {
+            "run" => DesiredStatus::Run,
+            "stop" => DesiredStatus::Stop,
+            "evict" => DesiredStatus::Evict,
+            other => DesiredStatus::Other(other.to_string()),
+        }
+    }
+}
+
+impl From<String> for DesiredStatus {
+    fn from(status: String) -> Self {
+        DesiredStatus::from(status.as_str())
+    }
+}
+
+impl From<DesiredStatus> for String {
+    fn from(status: DesiredStatus) -> Self {
+        status.as_str().to_string()
+    }
+}
+
+pub struct Endpoint<'a> {
+    client: &'a Nomad,
+}
+
+impl<'a> Endpoint<'a> {
+    /// Create a new `Endpoint` with the given `Nomad` client to interact with
+    /// the allocation endpoints.
+    pub fn new(client: &'a Nomad) -> Self {
+        Self { client }
+    }
+
+    /// Get the latest results of the Nomad service checks of an allocation,
+    /// keyed by check ID. Checks of services registered with Consul are not
+    /// included. This talks to the client agent running the allocation, which
+    /// Nomad will forward to when required.
+    ///
+    /// # Arguments
+    /// * `alloc_id` - The ID of the allocation.
+    /// * `opts` - Optional query options for the request.
+    ///
+    /// # Returns
+    /// A `Result` containing a map of check ID to `AllocCheckStatus` or an
+    /// error if the request fails.
+    pub async fn checks(
+        &self,
+        alloc_id: &str,
+        opts: Option<QueryOptions>,
+    ) -> Result<HashMap<String, AllocCheckStatus>, ClientError> {
+        if alloc_id.is_empty() {
+            return Err(ClientError::InvalidInputError(
+                "Allocation ID must be set".to_string(),
+            ));
+        }
+
+        let req = self.client.set_request_query_options(
+            self.client.build_request(
+                Method::GET,
+                &format!("/v1/client/allocation/{}/checks", alloc_id),
+            ),
+            &opts.unwrap_or_default(),
+        );
+        self.client
+            .send_with_response::<HashMap<String, AllocCheckStatus>>(req)
+            .await
+    }
+
+    /// Execute a command within a running task of an allocation, returning an
+    /// interactive session connected to the command over a WebSocket. This
+    /// talks to the client agent running the allocation, which Nomad will
+    /// forward to when required.
+    ///
+    /// # Arguments
+    /// * `alloc_id` - The ID of the allocation.
+    /// * `task` - The name of the task to execute the command within.
+    /// * `command` - The command and its arguments.
+    /// * `tty` - Whether to allocate a pseudo-terminal for the command.
+    /// * `opts` - Optional query options for the request.
+    ///
+    /// # Returns
+    /// A `Result` containing the `ExecSession` or an error if the session
+    /// could not be opened.
+    #[cfg(feature = "exec")]
+    pub async fn exec(
+        &self,
+        alloc_id: &str,
+        task: &str,
+        command: &[&str],
+        tty: bool,
+        opts: Option<QueryOptions>,
+    ) -> Result<crate::exec::ExecSession, ClientError> {
+        if alloc_id.is_empty() {
+            return Err(ClientError::InvalidInputError(
+                "Allocation ID must be set".to_string(),
+            ));
+        }
+        if task.is_empty() {
+            return Err(ClientError::InvalidInputError(
+                "Task name must be set".to_string(),
+            ));
+        }
+        if command.is_empty() {
+            return Err(ClientError::InvalidInputError(
+                "Command must be set".to_string(),
+            ));
+        }
+
+        let command = serde_json::to_string(command)
+            .map_err(|err| ClientError::RequestCreationError(err.to_string()))?;
+        let req = self
+            .client
+            .set_request_query_options(
+                self.client.build_request(
+                    Method::GET,
+                    &format!("/v1/client/allocation/{}/exec", alloc_id),
+                ),
+                &opts.unwrap_or_default(),
+            )
+            .query(&[
+                ("task", task),
+                ("tty", if tty { "true" } else { "false" }),
+                ("command", command.as_str()),
+            ]);
+
+        let req = self.client.finalize_request(req)?;
+        crate::exec::connect(&self.client.config, req).await
+    }
+
+    /// Read the contents of a file within an allocation's directory. This talks
+    /// to the client agent running the allocation, which Nomad will forward to
+    /// when required. The entire file is buffered in memory.
+    ///
+    /// # Arguments
+    /// * `alloc_id` - The ID of the allocation.
+    /// * `path` - The path of the file, relative to the allocation directory.
+    /// * `opts` - Optional query options for the request.
+    ///
+    /// # Returns
+    /// A `Result` containing the file contents or an error if the request
+    /// fails.
+    pub async fn fs_cat(
+        &self,
+        alloc_id: &str,
+        path: &str,
+        opts: Option<QueryOptions>,
+    ) -> Result<Bytes, ClientError> {
+        if alloc_id.is_empty() {
+            return Err(ClientError::InvalidInputError(
+                "Allocation ID must be set".to_string(),
+            ));
+        }
+
+        let req = self
+            .client
+            .set_request_query_options(
+                self.client
+                    .build_request(Method::GET, &format!("/v1/client/fs/cat/{}", alloc_id)),
+                &opts.unwrap_or_default(),
+            )
+            .query(&[("path", path)]);
+
+        self.client
+            .send_raw(req)
+            .await?
+            .bytes()
+            .await
+            .map_err(crate::network_error)
+    }
+
+    /// List the files within a directory of an allocation. This talks to the
+    /// client agent running the allocation, which Nomad will forward to when
+    /// required.
+    ///
+    /// # Arguments
+    /// * `alloc_id` - The ID of the allocation.
+    /// * `path` - The path of the directory, relative to the allocation
+    ///   directory.
+    /// * `opts` - Optional query options for the request.
+    ///
+    /// # Returns
+    /// A `Result` containing a vector of `AllocFileInfo` objects or an error if
+    /// the request fails.
+    pub async fn fs_ls(
+        &self,
+        alloc_id: &str,
+        path: &str,
+        opts: Option<QueryOptions>,
+    ) -> Result<Vec<AllocFileInfo>, ClientError> {
+        if alloc_id.is_empty() {
+            return Err(ClientError::InvalidInputError(
+                "Allocation ID must be set".to_string(),
+            ));
+        }
+
+        let req = self
+            .client
+            .set_request_query_options(
+                self.client
+                    .build_request(Method::GET, &format!("/v1/client/fs/ls/{}", alloc_id)),
+                &opts.unwrap_or_default(),
+            )
+            .query(&[("path", path)]);
+        self.client
+            .send_with_response::<Vec<AllocFileInfo>>(req)
+            .await
+    }
+
+    /// Get the details of a single file within an allocation. This talks to
+    /// the client agent running the allocation, which Nomad will forward to
+    /// when required.
+    ///
+    /// # Arguments
+    /// * `alloc_id` - The ID of the allocation.
+    /// * `path` - The path of the file, relative to the allocation directory.
+    /// * `opts` - Optional query options for the request.
+    ///
+    /// # Returns
+    /// A `Result` containing the `AllocFileInfo` object or an error if the
+    /// request fails.
+    pub async fn fs_stat(
+        &self,
+        alloc_id: &str,
+        path: &str,
+        opts: Option<QueryOptions>,
+    ) -> Result<AllocFileInfo, ClientError> {
+        if alloc_id.is_empty() {
+            return Err(ClientError::InvalidInputError(
+                "Allocation ID must be set".to_string(),
+            ));
+        }
+
+        let req = self
+            .client
+            .set_request_query_options(
+                self.client
+                    .build_request(Method::GET, &format!("/v1/client/fs/stat/{}", alloc_id)),
+                &opts.unwrap_or_default(),
+            )
+            .query(&[("path", path)]);
+        self.client.send_with_response::<AllocFileInfo>(req).await
+    }
+
+    /// Garbage collect an allocation, removing its local data from the node
+    /// running it to reclaim disk space. The allocation must be terminal. This
+    /// is a client API, answered by the agent of the node running the
+    /// allocation. Requests sent to a server are forwarded to that node, so
+    /// the node must be reachable from the servers.
+    ///
+    /// # Arguments
+    /// * `alloc_id` - The ID of the allocation to garbage collect.
+    /// * `opts` - Optional query options for the request.
+    ///
+    /// # Returns
+    /// A `Result` indicating success or failure of the operation.
+    pub async fn gc(&self, alloc_id: &str, opts: Option<QueryOptions>) -> Result<(), ClientError> {
+        if alloc_id.is_empty() {
+            return Err(ClientError::InvalidInputError(
+                "Allocation ID must be set".to_string(),
+            ));
+        }
+
+        let req = self.client.set_request_query_options(
+            self.client.build_request(
+                Method::GET,
+                &format!("/v1/client/allocation/{}/gc", alloc_id),
+            ),
+            &opts.unwrap_or_default(),
+        );
+        self.client.send_without_response(req).await
+    }
+
+    /// Get a specific allocation by its ID.
+    ///
+    /// # Arguments
+    /// * `alloc_id` - The ID of the allocation to retrieve.
+    /// * `opts` - Optional query options for the request.
+    ///
+    /// # Returns
+    /// A `Result` containing the `Allocation` object or an error if the
+    /// request fails.
+    pub async fn get(
+        &self,
+        alloc_id: &str,
+        opts: Option<QueryOptions>,
+    ) -> Result<Allocation, ClientError> {
+        let req = self.client.set_request_query_options(
+            self.client
+                .build_request(Method::GET, &format!("/v1/allocation/{}", alloc_id)),
+            &opts.unwrap_or_default(),
+        );
+        self.client.send_with_response::<Allocation>(req).await
+    }
+
+    /// Get a specific allocation by its ID, returning `None` rather than an
+    /// error when it does not exist.
+    ///
+    /// # Arguments
+    /// * `alloc_id` - The ID of the allocation to retrieve.
+    /// * `opts` - Optional query options for the request.
+    ///
+    /// # Returns
+    /// A `Result` containing the `Allocation`, `None` if it does not exist, or
+    /// an error if the request fails.
+    pub async fn get_optional(
+        &self,
+        alloc_id: &str,
+        opts: Option<QueryOptions>,
+    ) -> Result<Option<Allocation>, ClientError> {
+        crate::not_found_to_none(self.get(alloc_id, opts).await)
+    }
+
+    /// List all allocations in the Nomad cluster.
+    ///
+    /// # Arguments
+    /// * `opts` - Optional query options for the request.
+    ///
+    /// # Returns
+    /// A `Result` containing a vector of `AllocationStub` objects or an error
+    /// if the request fails.
+    pub async fn list(
+        &self,
+        opts: Option<QueryOptions>,
+    ) -> Result<Vec<AllocationStub>, ClientError> {
+        let opts = opts.unwrap_or_default();
+        let sort_order = opts.resolve_sort_order(SortOrder::Descending);
+        let req = self.client.set_request_query_options(
+            self.client.build_request(Method::GET, "/v1/allocations"),
+            &opts,
+        );
+        let mut allocations = self
+            .client
+            .send_with_response_streaming::<AllocationStub>(req)
+            .await?;
+
+        // Sort by CreateIndex, highest first unless another order is requested
+        sort_order.sort_by(&mut allocations, |a, b| a.create_index.cmp(&b.create_index));
+
+        Ok(allocations)
+    }
+
+    /// Read the logs of a task within an allocation. This talks to the client
+    /// agent running the allocation, which Nomad will forward to when
+    /// required.
+    ///
+    /// The logs are requested in plain format, so the returned chunks contain
+    /// the raw log bytes. When `follow` is `false`, the stream ends once the
+    /// existing log content has been read. When `follow` is `true`, the stream
+    /// stays open and yields new log output as it is written, until the task
+    /// stops or the stream is dropped.
+    ///
+    /// # Arguments
+    /// * `alloc_id` - The ID of the allocation.
+    /// * `task` - The name of the task to read the logs of.
+    /// * `log_type` - Whether to read the stdout or stderr logs.
+    /// * `follow` - Whether to keep streaming new log output.
+    /// * `opts` - Optional query options for the request.
+    ///
+    /// # Returns
+    /// A `Result` containing a stream of log chunks or an error if the request
+    /// fails.
+    #[cfg(feature = "stream")]
+    pub async fn logs(
+        &self,
+        alloc_id: &str,
+        task: &str,
+        log_type: LogType,
+        follow: bool,
+        opts: Option<QueryOptions>,
+    ) -> Result<impl futures_util::Stream<Item = Result<Bytes, ClientError>> + use<>, ClientError>
+    {
+        if alloc_id.is_empty() {
+            return Err(ClientError::InvalidInputError(
+                "Allocation ID must be set".to_string(),
+            ));
+        }
+        if task.is_empty() {
+            return Err(ClientError::InvalidInputError(
+                "Task name must be set".to_string(),
+            ));
+        }
+
+        let req = self
+            .client
+            .set_request_query_options(
+                self.client
+                    .build_request(Method::GET, &format!("/v1/client/fs/logs/{}", alloc_id)),
+                &opts.unwrap_or_default(),
+            )
+            .query(&[
+                ("task", task),
+                ("type", log_type.as_str()),
+                ("follow", if follow { "true" } else { "false" }),
+                ("origin", "start"),
+                ("plain", "true"),
+            ]);
+        self.client.send_stream(req).await
+    }
+
+    /// Restart tasks within an allocation. This talks to the client agent
+    /// running the allocation, which Nomad will forward to when required.
+    ///
+    /// # Arguments
+    /// * `alloc_id` - The ID of the allocation to restart.
+    /// * `task_name` - Optional name of a single task to restart. When `None`,
+    ///   all running tasks are restarted.
+    /// * `opts` - Optional write options for the request.
+    ///
+    /// # Returns
+    /// A `Result` indicating success or failure of the operation.
+    pub async fn restart(
+        &self,
+        alloc_id: &str,
+        task_name: Option<&str>,
+        opts: Option<WriteOptions>,
+    ) -> Result<(), ClientError> {
+        if alloc_id.is_empty() {
+            return Err(ClientError::InvalidInputError(
+                "Allocation ID must be set".to_string(),
+            ));
+        }
+
+        let req = self
+            .client
+            .set_request_write_options(
+                self.client.build_request(
+                    Method::POST,
+                    &format!("/v1/client/allocation/{}/restart", alloc_id),
+                ),
+                &opts.unwrap_or_default(),
+            )
+            .json(&AllocRestartRequest { task_name });
+        self.client.send_without_response(req).await
+    }
+
+    /// Get the Nomad service registrations of an allocation. Services
+    /// registered with Consul are not included. This requires Nomad 1.3.0 or
+    /// later, which introduced Nomad service discovery.
+    ///
+    /// # Arguments
+    /// * `alloc_id` - The ID of the allocation.
+    /// * `opts` - Optional query options for the request.
+    ///
+    /// # Returns
+    /// A `Result` containing a vector of `ServiceRegistration` objects or an
+    /// error if the request fails.
+    pub async fn services(
+        &self,
+        alloc_id: &str,
+        opts: Option<QueryOptions>,
+    ) -> Result<Vec<ServiceRegistration>, ClientError> {
+        if alloc_id.is_empty() {
+            return Err(ClientError::InvalidInputError(
+                "Allocation ID must be set".to_string(),
+            ));
+        }
+
+        let req = self.client.set_request_query_options(
+            self.client.build_request(
+                Method::GET,
+                &format!("/v1/allocation/{}/services", alloc_id),
+            ),
+            &opts.unwrap_or_default(),
+        );
+        self.client
+            .send_with_response::<Vec<ServiceRegistration>>(req)
+            .await
+    }
+
+    /// Send a signal to tasks within an allocation.
+    ///
+    /// # Arguments
+    /// * `alloc_id` - The ID of the allocation to signal.
+    /// * `task_name` - Optional name of a single task to signal. When `None`,
+    ///   all tasks in the allocation are signalled.
+    /// * `signal` - The signal to send, such as `SIGHUP`.
+    /// * `opts` - Optional write options for the request.
+    ///
+    /// # Returns
+    /// A `Result` indicating success or failure of the operation.
+    pub async fn signal(
+        &self,
+        alloc_id: &str,
+        task_name: Option<&str>,
+        signal: &str,
+        opts: Option<WriteOptions>,
+    ) -> Result<(), ClientError> {
+        if alloc_id.is_empty() {
+            return Err(ClientError::InvalidInputError(
+                "Allocation ID must be set".to_string(),
+            ));
+        }
+
+        if signal.is_empty() {
+            return Err(ClientError::InvalidInputError(
+                "Signal must be set".to_string(),
+            ));
+        }
+
+        let req = self
+            .client
+            .set_request_write_options(
+                self.client.build_request(
+                    Method::POST,
+                    &format!("/v1/client/allocation/{}/signal", alloc_id),
+                ),
+                &opts.unwrap_or_default(),
+            )
+            .json(&AllocSignalRequest {
+                task: task_name,
+                signal,
+            });
+        self.client.send_without_response(req).await
+    }
+
+    /// Get the current resource usage of an allocation and its tasks. This is
+    /// a client API, answered by the agent of the node running the allocation.
+    /// Requests sent to a server are forwarded to that node, so the node must
+    /// be reachable from the servers.
+    ///
+    /// # Arguments
+    /// * `alloc_id` - The ID of the allocation.
+    /// * `opts` - Optional query options for the request.
+    ///
+    /// # Returns
+    /// A `Result` containing the `AllocResourceUsage` or an error if the
+    /// request fails.
+    pub async fn stats(
+        &self,
+        alloc_id: &str,
+        opts: Option<QueryOptions>,
+    ) -> Result<AllocResourceUsage, ClientError> {
+        let req = self.client.set_request_query_options(
+            self.client.build_request(
+                Method::GET,
+                &format!("/v1/client/allocation/{}/stats", alloc_id),
+            ),
+            &opts.unwrap_or_default(),
+        );
+        self.client
+            .send_with_response::<AllocResourceUsage>(req)
+            .await
+    }
+
+    /// Stop an allocation, causing it to be rescheduled if the job
+    /// configuration allows.
+    ///
+    /// # Arguments
+    /// * `alloc_id` - The ID of the allocation to stop.
+    /// * `no_shutdown_delay` - Whether to ignore the shutdown delay of the
+    ///   allocation's tasks and services.
+    /// * `opts` - Optional write options for the request.
+    ///
+    /// # Returns
+    /// A `Result` containing the `AllocStopResponse` or an error if the request
+    /// fails.
+    pub async fn stop(
+        &self,
+        alloc_id: &str,
+        no_shutdown_delay: bool,
+        opts: Option<WriteOptions>,
+    ) -> Result<AllocStopResponse, ClientError> {
+        if alloc_id.is_empty() {
+            return Err(ClientError::InvalidInputError(
+                "Allocation ID must be set".to_string(),
+            ));
+        }
+
+        let req = self.client.set_request_write_options(
+            self.client.build_request(
+                Method::POST,
+                &format!(
+                    "/v1/allocation/{}/stop?no_shutdown_delay={}",
+                    alloc_id, no_shutdown_delay
+                ),
+            ),
+            &opts.unwrap_or_default(),
+        );
+        self.client
+            .send_with_response::<AllocStopResponse>(req)
+            .await
+    }
+}
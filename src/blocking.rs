@@ -0,0 +1,236 @@
+//! A synchronous client for applications which do not run an async runtime,
+//! such as simple command line tools.
+//!
+//! `BlockingNomad` owns a single threaded Tokio runtime and blocks on the
+//! async endpoint methods of the wrapped `Nomad` client. Only the most
+//! commonly used endpoint methods are exposed; the async client remains
+//! available via `BlockingNomad::client` for use with a runtime of the
+//! caller's choosing.
+//!
+//! The blocking methods must not be called from within an async context, such
+//! as a task running on a Tokio runtime, as blocking on the runtime from there
+//! will panic.
+//!
+//! ```no_run
+//! use nomad_rs_api::blocking::BlockingNomad;
+//! use nomad_rs_api::Config;
+//!
+//! let client = BlockingNomad::new(Config::from_env())?;
+//! for region in client.region().list()? {
+//!     println!("{}", region);
+//! }
+//! # Ok::<(), nomad_rs_api::ClientError>(())
+//! ```
+
+use crate::agent::{AgentHealth, AgentSelf};
+use crate::allocation::{AllocStopResponse, Allocation, AllocationStub};
+use crate::deployment::Deployment;
+use crate::evaluation::Evaluation;
+use crate::job::{
+    Job, JobDeregisterRequest, JobDeregisterResponse, JobDispatchRequest, JobDispatchResponse,
+    JobPlanRequest, JobPlanResponse, JobRegisterRequest, JobRegisterResponse, JobStub,
+    JobsListRequest,
+};
+use crate::namespace::Namespace;
+use crate::node::{Node, NodeStub};
+use crate::option::{QueryOptions, WriteOptions};
+use crate::variable::{Variable, VariableMetadata};
+use crate::{ClientError, Config, Nomad};
+use tokio::runtime::Runtime;
+
+/// A synchronous wrapper around the `Nomad` client.
+pub struct BlockingNomad {
+    client: Nomad,
+    runtime: Runtime,
+}
+
+impl BlockingNomad {
+    /// Create a new blocking client, along with the runtime used to drive its
+    /// requests.
+    ///
+    /// # Arguments
+    /// * `config` - The configuration of the client.
+    ///
+    /// # Returns
+    /// A `Result` containing the `BlockingNomad` client, or an error if the
+    /// runtime or the underlying client could not be created.
+    pub fn new(config: Config) -> Result<Self, ClientError> {
+        let runtime = tokio::runtime::Builder::new_current_thread()
+            .enable_all()
+            .build()
+            .map_err(|err| ClientError::ConfigurationError(err.to_string()))?;
+
+        Ok(Self {
+            client: Nomad::try_new(config)?,
+            runtime,
+        })
+    }
+
+    /// Get access to the wrapped async client.
+    pub fn client(&self) -> &Nomad {
+        &self.client
+    }
+}
+
+/// Generate a blocking endpoint, along with its accessor on `BlockingNomad`,
+/// where each method blocks on the async method of the same name. A method
+/// may be given a different name to the async method using `name = target`.
+macro_rules! blocking_endpoint {
+    (@name $method:ident) => {
+        stringify!($method)
+    };
+    (@name $method:ident $target:ident) => {
+        stringify!($target)
+    };
+    (@call $endpoint:expr, $method:ident, ($($arg:ident),*)) => {
+        $endpoint.$method($($arg),*)
+    };
+    (@call $endpoint:expr, $method:ident $target:ident, ($($arg:ident),*)) => {
+        $endpoint.$target($($arg),*)
+    };
+    (
+        $(#[$endpoint_doc:meta])*
+        $endpoint:ident => $accessor:ident {
+            $(
+                $(#[$method_doc:meta])*
+                fn $method:ident $(= $target:ident)? ($($arg:ident: $arg_ty:ty),*) -> $ret:ty;
+            )*
+        }
+    ) => {
+        $(#[$endpoint_doc])*
+        pub struct $endpoint<'a> {
+            nomad: &'a BlockingNomad,
+        }
+
+        impl<'a> $endpoint<'a> {
+            $(
+                $(#[$method_doc])*
+                #[doc = concat!(
+                    "Blocking version of `",
+                    stringify!($accessor),
+                    "::Endpoint::",
+                    blocking_endpoint!(@name $method $($target)?),
+                    "`."
+                )]
+                pub fn $method(&self, $($arg: $arg_ty),*) -> Result<$ret, ClientError> {
+                    self.nomad.runtime.block_on(blocking_endpoint!(
+                        @call self.nomad.client.$accessor(),
+                        $method $($target)?,
+                        ($($arg),*)
+                    ))
+                }
+            )*
+        }
+
+        impl BlockingNomad {
+            $(#[$endpoint_doc])*
+            pub fn $accessor(&self) -> $endpoint<'_> {
+                $endpoint { nomad: self }
+            }
+        }
+    };
+}
+
+blocking_endpoint! {
+    /// Get access to the blocking Agent endpoint methods.
+    BlockingAgent => agent {
+        fn health() -> AgentHealth;
+        fn self_(opts: Option<QueryOptions>) -> AgentSelf;
+    }
+}
+
+blocking_endpoint! {
+    /// Get access to the blocking Allocation endpoint methods.
+    BlockingAllocation => allocation {
+        fn get(alloc_id: &str, opts: Option<QueryOptions>) -> Allocation;
+        fn list(opts: Option<QueryOptions>) -> Vec<AllocationStub>;
+        fn stop(
+            alloc_id: &str,
+            no_shutdown_delay: bool,
+            opts: Option<WriteOptions>
+        ) -> AllocStopResponse;
+    }
+}
+
+blocking_endpoint! {
+    /// Get access to the blocking Deployment endpoint methods.
+    BlockingDeployment => deployment {
+        fn get(id: &str, opts: Option<QueryOptions>) -> Deployment;
+        fn list(opts: Option<QueryOptions>) -> Vec<Deployment>;
+    }
+}
+
+blocking_endpoint! {
+    /// Get access to the blocking Evaluation endpoint methods.
+    BlockingEvaluation => evaluation {
+        fn get(evaluation_id: &str, opts: Option<QueryOptions>) -> Evaluation;
+        fn list(opts: Option<QueryOptions>) -> Vec<Evaluation>;
+    }
+}
+
+blocking_endpoint! {
+    /// Get access to the blocking Job endpoint methods.
+    BlockingJob => job {
+        fn deregister(
+            job_deregister_request: &JobDeregisterRequest,
+            opts: Option<WriteOptions>
+        ) -> JobDeregisterResponse;
+        fn dispatch(
+            job_dispatch_request: &JobDispatchRequest,
+            opts: Option<WriteOptions>
+        ) -> JobDispatchResponse;
+        fn get(job_id: &str, opts: Option<QueryOptions>) -> Job;
+        fn list(
+            jobs_list_request: Option<&JobsListRequest>,
+            opts: Option<QueryOptions>
+        ) -> Vec<JobStub>;
+        fn plan(
+            job_plan_request: &JobPlanRequest<'_>,
+            opts: Option<WriteOptions>
+        ) -> JobPlanResponse;
+        fn register = regsiter(
+            job_register_request: &JobRegisterRequest<'_>,
+            opts: Option<WriteOptions>
+        ) -> JobRegisterResponse;
+        fn stop(job_id: &str, opts: Option<WriteOptions>) -> JobDeregisterResponse;
+    }
+}
+
+blocking_endpoint! {
+    /// Get access to the blocking Namespace endpoint methods.
+    BlockingNamespace => namespace {
+        fn get(name: &str, opts: Option<QueryOptions>) -> Namespace;
+        fn list(opts: Option<QueryOptions>) -> Vec<Namespace>;
+    }
+}
+
+blocking_endpoint! {
+    /// Get access to the blocking Node endpoint methods.
+    BlockingNode => node {
+        fn get(node_id: &str, opts: Option<QueryOptions>) -> Node;
+        fn list(opts: Option<QueryOptions>) -> Vec<NodeStub>;
+    }
+}
+
+blocking_endpoint! {
+    /// Get access to the blocking Region endpoint methods.
+    BlockingRegion => region {
+        fn list() -> Vec<String>;
+    }
+}
+
+blocking_endpoint! {
+    /// Get access to the blocking Status endpoint methods.
+    BlockingStatus => status {
+        fn get_leader(opts: Option<QueryOptions>) -> String;
+        fn list_peers(opts: Option<QueryOptions>) -> Vec<String>;
+    }
+}
+
+blocking_endpoint! {
+    /// Get access to the blocking Variable endpoint methods.
+    BlockingVariable => variable {
+        fn get(path: &str, opts: Option<QueryOptions>) -> Variable;
+        fn list(prefix: Option<&str>, opts: Option<QueryOptions>) -> Vec<VariableMetadata>;
+    }
+}
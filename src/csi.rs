@@ -0,0 +1,389 @@
+use crate::allocation::AllocationStub;
+use crate::option::{QueryOptions, WriteOptions};
+use crate::{ClientError, Nomad};
+use reqwest::Method;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct CSIVolume {
+    #[serde(rename = "ID")]
+    pub id: String,
+    pub name: String,
+    #[serde(rename = "ExternalID")]
+    pub external_id: Option<String>,
+    pub namespace: Option<String>,
+    pub topologies: Option<Vec<CSITopology>>,
+    pub access_mode: Option<String>,
+    pub attachment_mode: Option<String>,
+    pub schedulable: Option<bool>,
+    #[serde(rename = "PluginID")]
+    pub plugin_id: String,
+    pub provider: Option<String>,
+    pub capacity: Option<i64>,
+    pub requested_capacity_min: Option<i64>,
+    pub requested_capacity_max: Option<i64>,
+    pub requested_capabilities: Option<Vec<CSIVolumeCapability>>,
+    pub requested_topologies: Option<CSITopologyRequest>,
+    pub mount_options: Option<CSIMountOptions>,
+    #[serde(rename = "SnapshotID")]
+    pub snapshot_id: Option<String>,
+    #[serde(rename = "CloneID")]
+    pub clone_id: Option<String>,
+    pub secrets: Option<HashMap<String, String>>,
+    pub parameters: Option<HashMap<String, String>>,
+    pub context: Option<HashMap<String, String>>,
+    #[serde(skip_serializing)]
+    pub allocations: Option<Vec<AllocationStub>>,
+    pub create_index: Option<u64>,
+    pub modify_index: Option<u64>,
+}
+
+impl CSIVolume {
+    /// Create a new CSI volume object suitable for registration or creation.
+    ///
+    /// # Arguments
+    /// * `id` - The ID of the volume within Nomad.
+    /// * `name` - The display name of the volume.
+    /// * `plugin_id` - The ID of the CSI plugin which manages the volume.
+    ///
+    /// # Returns
+    /// A new `CSIVolume` object.
+    pub fn new(id: String, name: String, plugin_id: String) -> Self {
+        Self {
+            id,
+            name,
+            external_id: None,
+            namespace: None,
+            topologies: None,
+            access_mode: None,
+            attachment_mode: None,
+            schedulable: None,
+            plugin_id,
+            provider: None,
+            capacity: None,
+            requested_capacity_min: None,
+            requested_capacity_max: None,
+            requested_capabilities: None,
+            requested_topologies: None,
+            mount_options: None,
+            snapshot_id: None,
+            clone_id: None,
+            secrets: None,
+            parameters: None,
+            context: None,
+            allocations: None,
+            create_index: None,
+            modify_index: None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct CSIVolumeCapability {
+    pub access_mode: String,
+    pub attachment_mode: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct CSITopologyRequest {
+    pub required: Option<Vec<CSITopology>>,
+    pub preferred: Option<Vec<CSITopology>>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct CSIMountOptions {
+    pub fs_type: Option<String>,
+    pub mount_flags: Option<Vec<String>>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct CSITopology {
+    pub segments: HashMap<String, String>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct CSIVolumeListStub {
+    #[serde(rename = "ID")]
+    pub id: String,
+    pub namespace: String,
+    pub name: String,
+    #[serde(rename = "ExternalID")]
+    pub external_id: String,
+    pub topologies: Option<Vec<CSITopology>>,
+    pub access_mode: String,
+    pub attachment_mode: String,
+    pub current_readers: i32,
+    pub current_writers: i32,
+    pub schedulable: bool,
+    #[serde(rename = "PluginID")]
+    pub plugin_id: String,
+    pub provider: String,
+    pub controller_required: bool,
+    pub controllers_healthy: i32,
+    pub controllers_expected: i32,
+    pub nodes_healthy: i32,
+    pub nodes_expected: i32,
+    pub create_index: u64,
+    pub modify_index: u64,
+}
+
+#[derive(Debug, Clone)]
+pub struct CSIVolumeCreateRequest {
+    /// The volumes to create. All volumes are created via the path of the
+    /// first volume's ID.
+    pub volumes: Vec<CSIVolume>,
+    /// Secrets passed to the CSI plugin for every volume which does not define
+    /// its own.
+    pub secrets: Option<HashMap<String, String>>,
+}
+
+impl CSIVolumeCreateRequest {
+    pub fn new(volumes: Vec<CSIVolume>) -> Self {
+        Self {
+            volumes,
+            secrets: None,
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "PascalCase")]
+struct CSIVolumeRegisterRequest<'a> {
+    volumes: Vec<&'a CSIVolume>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "PascalCase")]
+struct CSIVolumeCreateWireRequest {
+    volumes: Vec<CSIVolume>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct CSIVolumeCreateResponse {
+    volumes: Vec<CSIVolume>,
+}
+
+pub struct Endpoint<'a> {
+    client: &'a Nomad,
+}
+
+impl<'a> Endpoint<'a> {
+    /// Create a new `Endpoint` with the given `Nomad` client to interact with
+    /// the CSI volume endpoints.
+    pub fn new(client: &'a Nomad) -> Self {
+        Self { client }
+    }
+
+    /// Create one or more CSI volumes using the storage provider of the CSI
+    /// plugin and register them with Nomad.
+    ///
+    /// # Arguments
+    /// * `create_request` - The request detailing the volumes to create.
+    /// * `opts` - Optional write options for the request.
+    ///
+    /// # Returns
+    /// A `Result` containing the created `CSIVolume` objects or an error if
+    /// the request fails.
+    pub async fn create(
+        &self,
+        create_request: &CSIVolumeCreateRequest,
+        opts: Option<WriteOptions>,
+    ) -> Result<Vec<CSIVolume>, ClientError> {
+        let id = match create_request.volumes.first() {
+            Some(volume) => volume.id.clone(),
+            None => {
+                return Err(ClientError::InvalidInputError(
+                    "At least one volume must be set".to_string(),
+                ));
+            }
+        };
+        if create_request
+            .volumes
+            .iter()
+            .any(|volume| volume.id.is_empty())
+        {
+            return Err(ClientError::InvalidInputError(
+                "Volume ID must be set".to_string(),
+            ));
+        }
+
+        let volumes = create_request
+            .volumes
+            .iter()
+            .cloned()
+            .map(|mut volume| {
+                if volume.secrets.is_none() {
+                    volume.secrets = create_request.secrets.clone();
+                }
+                volume
+            })
+            .collect();
+
+        let req = self
+            .client
+            .set_request_write_options(
+                self.client
+                    .build_request(Method::PUT, &format!("/v1/volume/csi/{}/create", id)),
+                &opts.unwrap_or_default(),
+            )
+            .json(&CSIVolumeCreateWireRequest { volumes });
+        self.client
+            .send_with_response::<CSIVolumeCreateResponse>(req)
+            .await
+            .map(|resp| resp.volumes)
+    }
+
+    /// Delete a CSI volume from the storage provider and deregister it from
+    /// Nomad.
+    ///
+    /// # Arguments
+    /// * `id` - The ID of the volume to delete.
+    /// * `opts` - Optional write options for the request.
+    ///
+    /// # Returns
+    /// A `Result` indicating success or failure of the operation.
+    pub async fn delete(&self, id: &str, opts: Option<WriteOptions>) -> Result<(), ClientError> {
+        if id.is_empty() {
+            return Err(ClientError::InvalidInputError(
+                "Volume ID must be set".to_string(),
+            ));
+        }
+
+        let req = self.client.set_request_write_options(
+            self.client
+                .build_request(Method::DELETE, &format!("/v1/volume/csi/{}/delete", id)),
+            &opts.unwrap_or_default(),
+        );
+        self.client.send_without_response(req).await
+    }
+
+    /// Deregister a CSI volume from Nomad. The volume is not removed from the
+    /// storage provider.
+    ///
+    /// # Arguments
+    /// * `id` - The ID of the volume to deregister.
+    /// * `force` - Whether to deregister the volume even if it is still in use.
+    /// * `opts` - Optional write options for the request.
+    ///
+    /// # Returns
+    /// A `Result` indicating success or failure of the operation.
+    pub async fn deregister(
+        &self,
+        id: &str,
+        force: bool,
+        opts: Option<WriteOptions>,
+    ) -> Result<(), ClientError> {
+        if id.is_empty() {
+            return Err(ClientError::InvalidInputError(
+                "Volume ID must be set".to_string(),
+            ));
+        }
+
+        let req = self
+            .client
+            .set_request_write_options(
+                self.client
+                    .build_request(Method::DELETE, &format!("/v1/volume/csi/{}", id)),
+                &opts.unwrap_or_default(),
+            )
+            .query(&[("force", force)]);
+        self.client.send_without_response(req).await
+    }
+
+    /// Get a specific CSI volume by its ID.
+    ///
+    /// # Arguments
+    /// * `id` - The ID of the volume to retrieve.
+    /// * `opts` - Optional query options for the request.
+    ///
+    /// # Returns
+    /// A `Result` containing the `CSIVolume` object or an error if the request
+    /// fails.
+    pub async fn get(
+        &self,
+        id: &str,
+        opts: Option<QueryOptions>,
+    ) -> Result<CSIVolume, ClientError> {
+        if id.is_empty() {
+            return Err(ClientError::InvalidInputError(
+                "Volume ID must be set".to_string(),
+            ));
+        }
+
+        let req = self.client.set_request_query_options(
+            self.client
+                .build_request(Method::GET, &format!("/v1/volume/csi/{}", id)),
+            &opts.unwrap_or_default(),
+        );
+        self.client.send_with_response::<CSIVolume>(req).await
+    }
+
+    /// Get the list of CSI volumes registered in the Nomad cluster.
+    ///
+    /// # Arguments
+    /// * `plugin_id` - Optional plugin ID used to only list volumes managed by
+    ///   that plugin.
+    /// * `opts` - Optional query options for the request.
+    ///
+    /// # Returns
+    /// A `Result` containing a vector of `CSIVolumeListStub` objects or an
+    /// error if the request fails.
+    pub async fn list(
+        &self,
+        plugin_id: Option<&str>,
+        opts: Option<QueryOptions>,
+    ) -> Result<Vec<CSIVolumeListStub>, ClientError> {
+        let mut req = self.client.set_request_query_options(
+            self.client
+                .build_request(Method::GET, "/v1/volumes?type=csi"),
+            &opts.unwrap_or_default(),
+        );
+
+        if let Some(plugin_id) = plugin_id {
+            req = req.query(&[("plugin_id", plugin_id)]);
+        }
+        self.client
+            .send_with_response::<Vec<CSIVolumeListStub>>(req)
+            .await
+    }
+
+    /// Register an existing CSI volume with Nomad.
+    ///
+    /// # Arguments
+    /// * `volume` - The volume to register.
+    /// * `opts` - Optional write options for the request.
+    ///
+    /// # Returns
+    /// A `Result` indicating success or failure of the operation.
+    pub async fn register(
+        &self,
+        volume: &CSIVolume,
+        opts: Option<WriteOptions>,
+    ) -> Result<(), ClientError> {
+        if volume.id.is_empty() {
+            return Err(ClientError::InvalidInputError(
+                "Volume ID must be set".to_string(),
+            ));
+        }
+
+        let req = self
+            .client
+            .set_request_write_options(
+                self.client
+                    .build_request(Method::PUT, &format!("/v1/volume/csi/{}", volume.id)),
+                &opts.unwrap_or_default(),
+            )
+            .json(&CSIVolumeRegisterRequest {
+                volumes: vec![volume],
+            });
+        self.client.send_without_response(req).await
+    }
+}
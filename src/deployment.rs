@@ -1,9 +1,19 @@
-use crate::option::{QueryOptions, WriteOptions};
+use crate::allocation::AllocationStub;
+use crate::option::{QueryMeta, QueryOptions, SortOrder, WriteOptions};
 use crate::{ClientError, Nomad};
 use reqwest::Method;
 use serde::{Deserialize, Serialize};
+use std::time::Duration;
 use time;
 
+pub const DEPLOYMENT_STATUS_RUNNING: &str = "running";
+pub const DEPLOYMENT_STATUS_PAUSED: &str = "paused";
+pub const DEPLOYMENT_STATUS_BLOCKED: &str = "blocked";
+pub const DEPLOYMENT_STATUS_PENDING: &str = "pending";
+pub const DEPLOYMENT_STATUS_SUCCESSFUL: &str = "successful";
+pub const DEPLOYMENT_STATUS_FAILED: &str = "failed";
+pub const DEPLOYMENT_STATUS_CANCELLED: &str = "cancelled";
+
 #[derive(Debug, Serialize, Deserialize)]
 #[serde(rename_all = "PascalCase")]
 pub struct Deployment {
@@ -26,6 +36,18 @@ pub struct Deployment {
     pub modify_time: i64,
 }
 
+impl Deployment {
+    /// Whether the deployment has reached a terminal status, after which it
+    /// will not change. A terminal deployment has either succeeded, failed,
+    /// or been cancelled.
+    pub fn is_terminal(&self) -> bool {
+        matches!(
+            self.status.as_str(),
+            DEPLOYMENT_STATUS_SUCCESSFUL | DEPLOYMENT_STATUS_FAILED | DEPLOYMENT_STATUS_CANCELLED
+        )
+    }
+}
+
 #[derive(Debug, Serialize, Deserialize)]
 #[serde(rename_all = "PascalCase")]
 pub struct DeploymentState {
@@ -69,6 +91,13 @@ pub struct DeploymentPromoteRequest {
     pub groups: Option<Vec<String>>,
 }
 
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "PascalCase")]
+struct DeploymentUnblockRequest<'a> {
+    #[serde(rename = "DeploymentID")]
+    deployment_id: &'a str,
+}
+
 pub struct Endpoint<'a> {
     client: &'a Nomad,
 }
@@ -117,12 +146,51 @@ impl<'a> Endpoint<'a> {
         id: &str,
         opts: Option<QueryOptions>,
     ) -> Result<Deployment, ClientError> {
+        self.get_with_meta(id, opts)
+            .await
+            .map(|(deployment, _)| deployment)
+    }
+
+    /// Get a specific deployment by its ID, returning `None` rather than an
+    /// error when it does not exist.
+    ///
+    /// # Arguments
+    /// * `id` - The ID of the deployment to retrieve.
+    /// * `opts` - Optional query options for the request.
+    ///
+    /// # Returns
+    /// A `Result` containing the deployment, `None` if it does not exist, or an
+    /// error if the request fails.
+    pub async fn get_optional(
+        &self,
+        id: &str,
+        opts: Option<QueryOptions>,
+    ) -> Result<Option<Deployment>, ClientError> {
+        crate::not_found_to_none(self.get(id, opts).await)
+    }
+
+    /// Get a specific deployment by its ID along with the query metadata of
+    /// the response. The `last_index` of the metadata can be used as the
+    /// `wait_index` of a subsequent blocking query.
+    ///
+    /// # Arguments
+    /// * `id` - The ID of the deployment to retrieve.
+    /// * `opts` - Optional query options for the request.
+    ///
+    /// # Returns
+    /// A `Result` containing the deployment and the `QueryMeta` of the
+    /// response, or an error if the request fails.
+    pub async fn get_with_meta(
+        &self,
+        id: &str,
+        opts: Option<QueryOptions>,
+    ) -> Result<(Deployment, QueryMeta), ClientError> {
         let req = self.client.set_request_query_options(
             self.client
                 .build_request(Method::GET, &format!("/v1/deployment/{}", id)),
             &opts.unwrap_or_default(),
         );
-        self.client.send_with_response::<Deployment>(req).await
+        self.client.send_with_response_meta::<Deployment>(req).await
     }
 
     /// Get the list of deployments in the Nomad cluster.
@@ -141,6 +209,62 @@ impl<'a> Endpoint<'a> {
         self.client.send_with_response::<Vec<Deployment>>(req).await
     }
 
+    /// Get the list of all deployments in the Nomad cluster, automatically
+    /// following pagination until every page has been read.
+    ///
+    /// All deployments are buffered in memory before being returned, which
+    /// may be significant on large clusters. Use `list` with
+    /// `QueryOptions::per_page` and `QueryOptions::next_token` to control
+    /// paging manually.
+    ///
+    /// # Arguments
+    /// * `opts` - Optional query options for the request. The page size is
+    ///   capped at `QUERY_MAX_PER_PAGE`.
+    ///
+    /// # Returns
+    /// A `Result` containing a vector of deployments or an error if any of the
+    /// requests fail.
+    pub async fn list_all(
+        &self,
+        opts: Option<QueryOptions>,
+    ) -> Result<Vec<Deployment>, ClientError> {
+        self.client
+            .send_paginated::<Deployment>("/v1/deployments", opts.unwrap_or_default())
+            .await
+    }
+
+    /// Get the list of allocations which belong to a deployment.
+    ///
+    /// # Arguments
+    /// * `id` - The ID of the deployment.
+    /// * `opts` - Optional query options for the request.
+    ///
+    /// # Returns
+    /// A `Result` containing a vector of `AllocationStub` objects or an error
+    /// if the request fails.
+    pub async fn list_allocations(
+        &self,
+        id: &str,
+        opts: Option<QueryOptions>,
+    ) -> Result<Vec<AllocationStub>, ClientError> {
+        let opts = opts.unwrap_or_default();
+        let sort_order = opts.resolve_sort_order(SortOrder::Descending);
+        let req = self.client.set_request_query_options(
+            self.client
+                .build_request(Method::GET, &format!("/v1/deployment/allocations/{}", id)),
+            &opts,
+        );
+        let mut allocs = self
+            .client
+            .send_with_response::<Vec<AllocationStub>>(req)
+            .await?;
+
+        // Sort by CreateIndex, highest first unless another order is requested
+        sort_order.sort_by(&mut allocs, |a, b| a.create_index.cmp(&b.create_index));
+
+        Ok(allocs)
+    }
+
     /// Promote a deployment to the next stage.
     ///
     /// # Arguments
@@ -206,4 +330,82 @@ impl<'a> Endpoint<'a> {
             .send_with_response::<DeploymentUpdateResponse>(req)
             .await
     }
+
+    /// Unblock a multiregion deployment which is waiting on the deployments of
+    /// other regions, marking it as successful.
+    ///
+    /// # Arguments
+    /// * `id` - The ID of the deployment to unblock.
+    /// * `opts` - Optional write options for the request.
+    ///
+    /// # Returns
+    /// A `Result` containing the deployment update response or an error if the
+    /// request fails.
+    pub async fn unblock(
+        &self,
+        id: &str,
+        opts: Option<WriteOptions>,
+    ) -> Result<DeploymentUpdateResponse, ClientError> {
+        if id.is_empty() {
+            return Err(ClientError::InvalidInputError(
+                "Deployment ID must be set".to_string(),
+            ));
+        }
+
+        let req = self
+            .client
+            .set_request_write_options(
+                self.client
+                    .build_request(Method::POST, &format!("/v1/deployment/unblock/{}", id)),
+                &opts.unwrap_or_default(),
+            )
+            .json(&DeploymentUnblockRequest { deployment_id: id });
+        self.client
+            .send_with_response::<DeploymentUpdateResponse>(req)
+            .await
+    }
+
+    /// Wait for a deployment to reach a terminal status, using blocking
+    /// queries to be notified of changes rather than polling. A deployment
+    /// which fails or is cancelled is still returned successfully, so the
+    /// caller can inspect its `status` and `status_description`.
+    ///
+    /// # Arguments
+    /// * `id` - The ID of the deployment to wait for.
+    /// * `timeout` - The maximum total time to wait. Each blocking query is
+    ///   limited to the time remaining, so `Config::timeout` must be larger
+    ///   than the wait time of a single query.
+    /// * `opts` - Optional query options for the requests. The `wait_index` is
+    ///   managed by this method.
+    ///
+    /// # Returns
+    /// A `Result` containing the terminal `Deployment`, or an error if a
+    /// request fails or a `ClientError::Timeout` if the deployment did not
+    /// reach a terminal status in time.
+    pub async fn wait_for_deployment(
+        &self,
+        id: &str,
+        timeout: Duration,
+        opts: Option<QueryOptions>,
+    ) -> Result<Deployment, ClientError> {
+        if id.is_empty() {
+            return Err(ClientError::InvalidInputError(
+                "Deployment ID must be set".to_string(),
+            ));
+        }
+
+        crate::wait_until(
+            timeout,
+            opts,
+            |opts| self.get_with_meta(id, Some(opts)),
+            Deployment::is_terminal,
+            || {
+                ClientError::Timeout(format!(
+                    "deployment {} did not reach a terminal status within {:?}",
+                    id, timeout
+                ))
+            },
+        )
+        .await
+    }
 }
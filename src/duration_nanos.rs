@@ -0,0 +1,149 @@
+//! Serde support for `time::Duration` fields which Nomad encodes either as an
+//! integer number of nanoseconds or as a Go duration string, such as
+//! `"1h0m0s"`. Durations are always serialized as nanoseconds, which Nomad
+//! accepts for every such field.
+//!
+//! Use with `#[serde(with = "crate::duration_nanos")]` for required fields and
+//! `#[serde(default, with = "crate::duration_nanos::option")]` for optional
+//! ones.
+
+use serde::de::Error as _;
+use serde::ser::Error as _;
+use serde::{Deserialize, Deserializer, Serializer};
+use std::num::TryFromIntError;
+
+/// Convert a duration to the integer number of nanoseconds Nomad expects,
+/// failing if the duration does not fit within an `i64`.
+pub(crate) fn to_nanos(duration: &time::Duration) -> Result<i64, TryFromIntError> {
+    i64::try_from(duration.whole_nanoseconds())
+}
+
+/// A duration as sent by Nomad, before it has been converted.
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum RawDuration {
+    Nanos(i64),
+    Text(String),
+}
+
+impl RawDuration {
+    /// Convert the raw duration, returning `None` for the empty string Nomad
+    /// sends when the duration is unset.
+    fn into_duration(self) -> Result<Option<time::Duration>, String> {
+        match self {
+            RawDuration::Nanos(nanos) => Ok(Some(time::Duration::nanoseconds(nanos))),
+            RawDuration::Text(text) if text.is_empty() => Ok(None),
+            RawDuration::Text(text) => parse_go_duration(&text).map(Some),
+        }
+    }
+}
+
+/// Parse a duration formatted by Go's `time.Duration.String`, or accepted by
+/// `time.ParseDuration`, such as `"1h0m0s"`, `"90s"`, or `"1.5ms"`.
+fn parse_go_duration(text: &str) -> Result<time::Duration, String> {
+    let invalid = || format!("invalid duration '{}'", text);
+
+    let (negative, mut rest) = match text.strip_prefix('-') {
+        Some(rest) => (true, rest),
+        None => (false, text.strip_prefix('+').unwrap_or(text)),
+    };
+    if rest == "0" {
+        return Ok(time::Duration::ZERO);
+    }
+    if rest.is_empty() {
+        return Err(invalid());
+    }
+
+    let mut total: i128 = 0;
+    while !rest.is_empty() {
+        let number_len = rest
+            .find(|c: char| !c.is_ascii_digit() && c != '.')
+            .unwrap_or(rest.len());
+        let (number, tail) = rest.split_at(number_len);
+        let unit_len = tail
+            .find(|c: char| c.is_ascii_digit() || c == '.')
+            .unwrap_or(tail.len());
+        let (unit, tail) = tail.split_at(unit_len);
+        rest = tail;
+
+        let unit_nanos: i128 = match unit {
+            "ns" => 1,
+            "us" | "µs" | "μs" => 1_000,
+            "ms" => 1_000_000,
+            "s" => 1_000_000_000,
+            "m" => 60_000_000_000,
+            "h" => 3_600_000_000_000,
+            _ => return Err(invalid()),
+        };
+
+        let (whole, fraction) = number.split_once('.').unwrap_or((number, ""));
+        if whole.is_empty() && fraction.is_empty() {
+            return Err(invalid());
+        }
+        let whole: i128 = match whole {
+            "" => 0,
+            whole => whole.parse().map_err(|_| invalid())?,
+        };
+        let mut nanos = whole.checked_mul(unit_nanos).ok_or_else(invalid)?;
+        let mut scale = unit_nanos;
+        for digit in fraction.chars() {
+            scale /= 10;
+            nanos += i128::from(digit.to_digit(10).ok_or_else(invalid)?) * scale;
+        }
+        total = total.checked_add(nanos).ok_or_else(invalid)?;
+    }
+
+    let total = if negative { -total } else { total };
+    i64::try_from(total)
+        .map(time::Duration::nanoseconds)
+        .map_err(|_| invalid())
+}
+
+pub fn serialize<S>(duration: &time::Duration, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    let nanos = to_nanos(duration).map_err(|_| {
+        S::Error::custom(format!(
+            "duration {} is too large to encode as nanoseconds",
+            duration
+        ))
+    })?;
+    serializer.serialize_i64(nanos)
+}
+
+pub fn deserialize<'de, D>(deserializer: D) -> Result<time::Duration, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let duration = RawDuration::deserialize(deserializer)?
+        .into_duration()
+        .map_err(D::Error::custom)?;
+    Ok(duration.unwrap_or(time::Duration::ZERO))
+}
+
+pub mod option {
+    use super::RawDuration;
+    use serde::de::Error as _;
+    use serde::{Deserialize, Deserializer, Serializer};
+
+    pub fn serialize<S>(duration: &Option<time::Duration>, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match duration {
+            Some(duration) => super::serialize(duration, serializer),
+            None => serializer.serialize_none(),
+        }
+    }
+
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<Option<time::Duration>, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        match Option::<RawDuration>::deserialize(deserializer)? {
+            Some(duration) => duration.into_duration().map_err(D::Error::custom),
+            None => Ok(None),
+        }
+    }
+}
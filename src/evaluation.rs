@@ -1,9 +1,11 @@
 use crate::allocation::{AllocationMetric, AllocationStub};
-use crate::option::{QueryOptions, WriteOptions};
+use crate::filter::Filter;
+use crate::option::{QueryMeta, QueryOptions, SortOrder, WriteOptions};
 use crate::{ClientError, Nomad};
 use reqwest::Method;
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
+use std::time::Duration;
 
 pub const EVALUATION_STATUS_BLOCKED: &str = "blocked";
 pub const EVALUATION_STATUS_PENDING: &str = "pending";
@@ -11,6 +13,19 @@ pub const EVALUATION_STATUS_COMPLETE: &str = "complete";
 pub const EVALUATION_STATUS_FAILED: &str = "failed";
 pub const EVALUATION_STATUS_CANCELED: &str = "canceled";
 
+/// Every status an evaluation can have.
+const EVALUATION_STATUSES: [&str; 5] = [
+    EVALUATION_STATUS_BLOCKED,
+    EVALUATION_STATUS_PENDING,
+    EVALUATION_STATUS_COMPLETE,
+    EVALUATION_STATUS_FAILED,
+    EVALUATION_STATUS_CANCELED,
+];
+
+/// The maximum number of `next_eval` links followed by
+/// `Endpoint::follow_eval_chain` before giving up.
+pub const EVALUATION_CHAIN_MAX_HOPS: usize = 64;
+
 #[derive(Debug, Clone, Deserialize)]
 #[serde(rename_all = "PascalCase")]
 pub struct Evaluation {
@@ -51,6 +66,17 @@ pub struct Evaluation {
     pub modify_time: i64,
 }
 
+impl Evaluation {
+    /// Whether the evaluation has reached a terminal status, meaning it is
+    /// complete, failed, or canceled, and will not be processed further.
+    pub fn is_terminal(&self) -> bool {
+        matches!(
+            self.status.as_str(),
+            EVALUATION_STATUS_COMPLETE | EVALUATION_STATUS_FAILED | EVALUATION_STATUS_CANCELED
+        )
+    }
+}
+
 #[derive(Debug, Clone, Deserialize)]
 #[serde(rename_all = "PascalCase")]
 pub struct EvaluationStub {
@@ -199,6 +225,44 @@ impl<'a> Endpoint<'a> {
             .await
     }
 
+    /// Follow the chain of evaluations created from an evaluation, such as
+    /// when a failed placement is retried, returning the latest evaluation of
+    /// the chain.
+    ///
+    /// # Arguments
+    /// * `evaluation_id` - The ID of the evaluation the chain starts from.
+    /// * `opts` - Optional query options for each request.
+    ///
+    /// # Returns
+    /// A `Result` containing the last `Evaluation` of the chain, or an error
+    /// if a request fails or the chain loops or exceeds
+    /// `EVALUATION_CHAIN_MAX_HOPS` links.
+    pub async fn follow_eval_chain(
+        &self,
+        evaluation_id: &str,
+        opts: Option<QueryOptions>,
+    ) -> Result<Evaluation, ClientError> {
+        let mut seen = HashSet::from([evaluation_id.to_string()]);
+        let mut evaluation = self.get(evaluation_id, opts.clone()).await?;
+
+        while let Some(next_eval) = evaluation.next_eval.clone().filter(|id| !id.is_empty()) {
+            if !seen.insert(next_eval.clone()) {
+                return Err(ClientError::InvalidInputError(format!(
+                    "Evaluation chain from {} loops at {}",
+                    evaluation_id, next_eval
+                )));
+            }
+            if seen.len() > EVALUATION_CHAIN_MAX_HOPS {
+                return Err(ClientError::InvalidInputError(format!(
+                    "Evaluation chain from {} exceeds {} links",
+                    evaluation_id, EVALUATION_CHAIN_MAX_HOPS
+                )));
+            }
+            evaluation = self.get(&next_eval, opts.clone()).await?;
+        }
+        Ok(evaluation)
+    }
+
     /// Get information about a specific evaluation.
     ///
     /// # Arguments
@@ -213,6 +277,45 @@ impl<'a> Endpoint<'a> {
         evaluation_id: &str,
         opts: Option<QueryOptions>,
     ) -> Result<Evaluation, ClientError> {
+        self.get_with_meta(evaluation_id, opts)
+            .await
+            .map(|(evaluation, _)| evaluation)
+    }
+
+    /// Get information about a specific evaluation, returning `None` rather
+    /// than an error when it does not exist.
+    ///
+    /// # Arguments
+    /// * `evaluation_id` - The ID of the evaluation to retrieve.
+    /// * `opts` - Optional query options for the request.
+    ///
+    /// # Returns
+    /// A `Result` containing the `Evaluation`, `None` if it does not exist, or
+    /// an error if the request fails.
+    pub async fn get_optional(
+        &self,
+        evaluation_id: &str,
+        opts: Option<QueryOptions>,
+    ) -> Result<Option<Evaluation>, ClientError> {
+        crate::not_found_to_none(self.get(evaluation_id, opts).await)
+    }
+
+    /// Get information about a specific evaluation along with the query
+    /// metadata of the response. The `last_index` of the metadata can be used
+    /// as the `wait_index` of a subsequent blocking query.
+    ///
+    /// # Arguments
+    /// * `evaluation_id` - The ID of the evaluation to retrieve.
+    /// * `opts` - Optional query options for the request.
+    ///
+    /// # Returns
+    /// A `Result` containing the `Evaluation` object and the `QueryMeta` of
+    /// the response, or an error if the request fails.
+    pub async fn get_with_meta(
+        &self,
+        evaluation_id: &str,
+        opts: Option<QueryOptions>,
+    ) -> Result<(Evaluation, QueryMeta), ClientError> {
         let req = self.client.set_request_query_options(
             self.client.build_request(
                 Method::GET,
@@ -220,7 +323,7 @@ impl<'a> Endpoint<'a> {
             ),
             &opts.unwrap_or_default(),
         );
-        self.client.send_with_response::<Evaluation>(req).await
+        self.client.send_with_response_meta::<Evaluation>(req).await
     }
 
     /// List all evaluations.
@@ -232,17 +335,99 @@ impl<'a> Endpoint<'a> {
     /// A `Result` containing a vector of `Evaluation` objects or an error if
     /// the request fails.
     pub async fn list(&self, opts: Option<QueryOptions>) -> Result<Vec<Evaluation>, ClientError> {
+        let opts = opts.unwrap_or_default();
+        let sort_order = opts.resolve_sort_order(SortOrder::Descending);
         let req = self.client.set_request_query_options(
             self.client.build_request(Method::GET, "/v1/evaluations"),
-            &opts.unwrap_or_default(),
+            &opts,
         );
         let mut evaluations = self
             .client
             .send_with_response::<Vec<Evaluation>>(req)
             .await?;
 
-        // Sort by CreateIndex descending (highest first)
-        evaluations.sort_by(|a, b| b.create_index.cmp(&a.create_index));
+        // Sort by CreateIndex, highest first unless another order is requested
+        sort_order.sort_by(&mut evaluations, |a, b| a.create_index.cmp(&b.create_index));
+
+        Ok(evaluations)
+    }
+
+    /// List the evaluations of a job, with a status, or both.
+    ///
+    /// The evaluations are selected server-side using a filter expression on
+    /// `JobID` and `Status`. Any filter set within `opts` is combined with it
+    /// using `and`.
+    ///
+    /// # Arguments
+    /// * `job_id` - Optional ID of the job the evaluations belong to.
+    /// * `status` - Optional status of the evaluations, which must be one of
+    ///   the `EVALUATION_STATUS_*` constants.
+    /// * `opts` - Optional query options for the request.
+    ///
+    /// # Returns
+    /// A `Result` containing a vector of `Evaluation` objects or an error if
+    /// the status is unknown or the request fails.
+    pub async fn list_evaluations_filtered(
+        &self,
+        job_id: Option<&str>,
+        status: Option<&str>,
+        opts: Option<QueryOptions>,
+    ) -> Result<Vec<Evaluation>, ClientError> {
+        let mut filters = Vec::new();
+
+        if let Some(job_id) = job_id {
+            if job_id.is_empty() {
+                return Err(ClientError::InvalidInputError(
+                    "Job ID must not be empty".to_string(),
+                ));
+            }
+            filters.push(Filter::eq("JobID", job_id));
+        }
+        if let Some(status) = status {
+            if !EVALUATION_STATUSES.contains(&status) {
+                return Err(ClientError::InvalidInputError(format!(
+                    "Unknown evaluation status '{}'",
+                    status
+                )));
+            }
+            filters.push(Filter::eq("Status", status));
+        }
+
+        let mut opts = opts.unwrap_or_default();
+        if let Some(filter) = filters.into_iter().reduce(Filter::and) {
+            opts = opts.and_filter_expr(filter);
+        }
+        self.list(Some(opts)).await
+    }
+
+    /// List all evaluations, automatically following pagination until every
+    /// page has been read.
+    ///
+    /// All evaluations are buffered in memory before being returned, which
+    /// may be significant on busy clusters. Use `list` with
+    /// `QueryOptions::per_page` and `QueryOptions::next_token` to control
+    /// paging manually.
+    ///
+    /// # Arguments
+    /// * `opts` - Optional query options for the request. The page size is
+    ///   capped at `QUERY_MAX_PER_PAGE`.
+    ///
+    /// # Returns
+    /// A `Result` containing a vector of `Evaluation` objects or an error if
+    /// any of the requests fail.
+    pub async fn list_all(
+        &self,
+        opts: Option<QueryOptions>,
+    ) -> Result<Vec<Evaluation>, ClientError> {
+        let opts = opts.unwrap_or_default();
+        let sort_order = opts.resolve_sort_order(SortOrder::Descending);
+        let mut evaluations = self
+            .client
+            .send_paginated::<Evaluation>("/v1/evaluations", opts)
+            .await?;
+
+        // Sort by CreateIndex, highest first unless another order is requested
+        sort_order.sort_by(&mut evaluations, |a, b| a.create_index.cmp(&b.create_index));
 
         Ok(evaluations)
     }
@@ -261,21 +446,66 @@ impl<'a> Endpoint<'a> {
         evaluation_id: &str,
         opts: Option<QueryOptions>,
     ) -> Result<Vec<AllocationStub>, ClientError> {
+        let opts = opts.unwrap_or_default();
+        let sort_order = opts.resolve_sort_order(SortOrder::Descending);
         let req = self.client.set_request_query_options(
             self.client.build_request(
                 Method::GET,
                 &format!("/v1/evaluation/{}/allocations", evaluation_id),
             ),
-            &opts.unwrap_or_default(),
+            &opts,
         );
         let mut allocations = self
             .client
             .send_with_response::<Vec<AllocationStub>>(req)
             .await?;
 
-        // Sort by CreateIndex descending (highest first)
-        allocations.sort_by(|a, b| b.create_index.cmp(&a.create_index));
+        // Sort by CreateIndex, highest first unless another order is requested
+        sort_order.sort_by(&mut allocations, |a, b| a.create_index.cmp(&b.create_index));
 
         Ok(allocations)
     }
+
+    /// Wait for an evaluation to reach a terminal status, using blocking
+    /// queries to be notified of changes rather than polling. This is
+    /// typically used with the evaluation ID returned when registering a job.
+    ///
+    /// # Arguments
+    /// * `evaluation_id` - The ID of the evaluation to wait for.
+    /// * `timeout` - The maximum total time to wait. Each blocking query is
+    ///   limited to the time remaining, so `Config::timeout` must be larger
+    ///   than the wait time of a single query.
+    /// * `opts` - Optional query options for the requests. The `wait_index` is
+    ///   managed by this method.
+    ///
+    /// # Returns
+    /// A `Result` containing the terminal `Evaluation`, or an error if a
+    /// request fails or a `ClientError::Timeout` if the evaluation did not
+    /// reach a terminal status in time.
+    pub async fn wait_for_evaluation(
+        &self,
+        evaluation_id: &str,
+        timeout: Duration,
+        opts: Option<QueryOptions>,
+    ) -> Result<Evaluation, ClientError> {
+        if evaluation_id.is_empty() {
+            return Err(ClientError::InvalidInputError(
+                "Evaluation ID must be set".to_string(),
+            ));
+        }
+
+        crate::wait_until(
+            timeout,
+            opts,
+            |opts| self.get_with_meta(evaluation_id, Some(opts)),
+            Evaluation::is_terminal,
+            || {
+                ClientError::Timeout(format!(
+                    "evaluation {} did not reach a terminal status within {:?}",
+                    evaluation_id, timeout
+                ))
+            },
+        )
+        .await
+    }
 }
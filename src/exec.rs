@@ -0,0 +1,481 @@
+//! Interactive command execution within a running allocation, using the
+//! WebSocket protocol of `/v1/client/allocation/{id}/exec`.
+//!
+//! A session is opened using `allocation::Endpoint::exec`. Input is sent as
+//! `ExecInput` frames and the command output is received as `ExecOutput`
+//! frames, with every payload base64 encoded within JSON text messages.
+
+use crate::{ClientError, Config, UNIX_SOCKET_SCHEME};
+use base64::Engine;
+use base64::engine::general_purpose::STANDARD;
+use bytes::Bytes;
+use futures_util::stream::{SplitSink, SplitStream};
+use futures_util::{SinkExt, StreamExt};
+use serde::{Deserialize, Serialize};
+use std::collections::VecDeque;
+use tokio::net::TcpStream;
+use tokio_tungstenite::tungstenite::client::IntoClientRequest;
+use tokio_tungstenite::tungstenite::protocol::CloseFrame;
+use tokio_tungstenite::tungstenite::protocol::frame::coding::CloseCode;
+use tokio_tungstenite::tungstenite::{Error as WsError, Message};
+use tokio_tungstenite::{Connector, MaybeTlsStream, WebSocketStream};
+
+type ExecStream = WebSocketStream<MaybeTlsStream<TcpStream>>;
+
+/// A single frame of input sent to the command.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ExecInput {
+    /// Data written to the standard input of the command.
+    Stdin(Bytes),
+    /// Close the standard input of the command.
+    CloseStdin,
+    /// Resize the terminal of a command started with a TTY.
+    Resize { width: u16, height: u16 },
+    /// An empty frame, which keeps an idle session from timing out.
+    Heartbeat,
+}
+
+impl ExecInput {
+    /// Encode the input as the JSON text frame expected by Nomad.
+    pub fn encode(&self) -> String {
+        let frame = match self {
+            ExecInput::Stdin(data) => ExecInputFrame {
+                stdin: Some(ExecStreamData {
+                    data: Some(STANDARD.encode(data)),
+                    close: None,
+                }),
+                tty_size: None,
+            },
+            ExecInput::CloseStdin => ExecInputFrame {
+                stdin: Some(ExecStreamData {
+                    data: None,
+                    close: Some(true),
+                }),
+                tty_size: None,
+            },
+            ExecInput::Resize { width, height } => ExecInputFrame {
+                stdin: None,
+                tty_size: Some(ExecTerminalSize {
+                    width: *width,
+                    height: *height,
+                }),
+            },
+            ExecInput::Heartbeat => ExecInputFrame {
+                stdin: None,
+                tty_size: None,
+            },
+        };
+
+        // Serializing the frame cannot fail, as it only contains strings,
+        // integers, and booleans.
+        serde_json::to_string(&frame).unwrap_or_default()
+    }
+}
+
+/// A single item of output received from the command.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ExecOutput {
+    /// Data written by the command to its standard output.
+    Stdout(Bytes),
+    /// Data written by the command to its standard error.
+    Stderr(Bytes),
+    /// The command exited with the contained exit code. No further output
+    /// follows.
+    Exited(i32),
+}
+
+impl ExecOutput {
+    /// Decode a JSON text frame received from Nomad. A frame may carry several
+    /// items of output, or none when it only closes an output stream.
+    pub fn decode(frame: &str) -> Result<Vec<ExecOutput>, ClientError> {
+        let decoded: ExecOutputFrame =
+            serde_json::from_str(frame).map_err(|err| exec_decode_error(err.to_string(), frame))?;
+
+        let mut outputs = Vec::new();
+        if let Some(data) = decode_stream_data(decoded.stdout.as_ref(), frame)? {
+            outputs.push(ExecOutput::Stdout(data));
+        }
+        if let Some(data) = decode_stream_data(decoded.stderr.as_ref(), frame)? {
+            outputs.push(ExecOutput::Stderr(data));
+        }
+        if decoded.exited {
+            let exit_code = decoded.result.map_or(0, |result| result.exit_code);
+            outputs.push(ExecOutput::Exited(exit_code));
+        }
+        Ok(outputs)
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct ExecStreamData {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    data: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    close: Option<bool>,
+}
+
+#[derive(Debug, Serialize)]
+struct ExecTerminalSize {
+    height: u16,
+    width: u16,
+}
+
+#[derive(Debug, Serialize)]
+struct ExecInputFrame {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    stdin: Option<ExecStreamData>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    tty_size: Option<ExecTerminalSize>,
+}
+
+#[derive(Debug, Deserialize)]
+struct ExecExitResult {
+    #[serde(default)]
+    exit_code: i32,
+}
+
+#[derive(Debug, Deserialize)]
+struct ExecOutputFrame {
+    stdout: Option<ExecStreamData>,
+    stderr: Option<ExecStreamData>,
+    #[serde(default)]
+    exited: bool,
+    result: Option<ExecExitResult>,
+}
+
+fn decode_stream_data(
+    stream: Option<&ExecStreamData>,
+    frame: &str,
+) -> Result<Option<Bytes>, ClientError> {
+    match stream.and_then(|stream| stream.data.as_deref()) {
+        Some(data) if !data.is_empty() => STANDARD
+            .decode(data)
+            .map(|data| Some(Bytes::from(data)))
+            .map_err(|err| exec_decode_error(err.to_string(), frame)),
+        _ => Ok(None),
+    }
+}
+
+fn exec_decode_error(message: String, frame: &str) -> ClientError {
+    ClientError::DeserializationError {
+        status: reqwest::StatusCode::SWITCHING_PROTOCOLS.as_u16(),
+        message,
+        body_snippet: frame
+            .chars()
+            .take(crate::DESERIALIZATION_ERROR_SNIPPET_LEN)
+            .collect(),
+    }
+}
+
+fn websocket_error(err: WsError) -> ClientError {
+    match err {
+        WsError::Io(err) => ClientError::ConnectionError(err.to_string()),
+        err => ClientError::NetworkError(err.to_string()),
+    }
+}
+
+/// An interactive session with a command running within an allocation.
+///
+/// The session can be used directly, or split into an `ExecWriter` and an
+/// `ExecReader` so that input and output are handled concurrently.
+pub struct ExecSession {
+    writer: ExecWriter,
+    reader: ExecReader,
+}
+
+impl ExecSession {
+    /// Split the session into its input and output halves.
+    pub fn split(self) -> (ExecWriter, ExecReader) {
+        (self.writer, self.reader)
+    }
+
+    /// Write data to the standard input of the command.
+    pub async fn write_stdin(&mut self, data: &[u8]) -> Result<(), ClientError> {
+        self.writer.write_stdin(data).await
+    }
+
+    /// Close the standard input of the command.
+    pub async fn close_stdin(&mut self) -> Result<(), ClientError> {
+        self.writer.close_stdin().await
+    }
+
+    /// Receive the next item of output from the command.
+    pub async fn next_output(&mut self) -> Option<Result<ExecOutput, ClientError>> {
+        self.reader.next_output().await
+    }
+
+    /// Read the remaining output, discarding it, until the command exits.
+    pub async fn wait(self) -> Result<i32, ClientError> {
+        self.reader.wait().await
+    }
+}
+
+/// The input half of an `ExecSession`.
+pub struct ExecWriter {
+    sink: SplitSink<ExecStream, Message>,
+}
+
+impl ExecWriter {
+    /// Send a single input frame to the command.
+    pub async fn send(&mut self, input: ExecInput) -> Result<(), ClientError> {
+        self.sink
+            .send(Message::Text(input.encode()))
+            .await
+            .map_err(websocket_error)
+    }
+
+    /// Write data to the standard input of the command.
+    pub async fn write_stdin(&mut self, data: &[u8]) -> Result<(), ClientError> {
+        self.send(ExecInput::Stdin(Bytes::copy_from_slice(data)))
+            .await
+    }
+
+    /// Close the standard input of the command.
+    pub async fn close_stdin(&mut self) -> Result<(), ClientError> {
+        self.send(ExecInput::CloseStdin).await
+    }
+
+    /// Resize the terminal of a command started with a TTY.
+    pub async fn resize(&mut self, width: u16, height: u16) -> Result<(), ClientError> {
+        self.send(ExecInput::Resize { width, height }).await
+    }
+
+    /// Send a heartbeat. Sessions which may be idle for long periods should
+    /// send one every few seconds, so that proxies do not close the
+    /// connection.
+    pub async fn heartbeat(&mut self) -> Result<(), ClientError> {
+        self.send(ExecInput::Heartbeat).await
+    }
+}
+
+/// The output half of an `ExecSession`.
+pub struct ExecReader {
+    stream: SplitStream<ExecStream>,
+    pending: VecDeque<ExecOutput>,
+    exit_code: Option<i32>,
+}
+
+impl ExecReader {
+    /// The exit code of the command, once `ExecOutput::Exited` has been
+    /// received.
+    pub fn exit_code(&self) -> Option<i32> {
+        self.exit_code
+    }
+
+    /// Receive the next item of output from the command. Returns `None` once
+    /// the session has ended.
+    pub async fn next_output(&mut self) -> Option<Result<ExecOutput, ClientError>> {
+        loop {
+            if let Some(output) = self.pending.pop_front() {
+                if let ExecOutput::Exited(exit_code) = output {
+                    self.exit_code = Some(exit_code);
+                }
+                return Some(Ok(output));
+            }
+            if self.exit_code.is_some() {
+                return None;
+            }
+
+            let frame = match self.stream.next().await? {
+                Ok(Message::Text(text)) => text,
+                Ok(Message::Binary(data)) => String::from_utf8_lossy(&data).into_owned(),
+                Ok(Message::Close(frame)) => return close_error(frame).map(Err),
+                Ok(_) => continue,
+                Err(err) => return Some(Err(websocket_error(err))),
+            };
+            match ExecOutput::decode(&frame) {
+                Ok(outputs) => self.pending.extend(outputs),
+                Err(err) => return Some(Err(err)),
+            }
+        }
+    }
+
+    /// Read the remaining output, discarding it, until the command exits.
+    ///
+    /// # Returns
+    /// A `Result` containing the exit code of the command, or an error if the
+    /// session ended before the command exited.
+    pub async fn wait(mut self) -> Result<i32, ClientError> {
+        while let Some(output) = self.next_output().await {
+            if let ExecOutput::Exited(exit_code) = output? {
+                return Ok(exit_code);
+            }
+        }
+        self.exit_code.ok_or_else(|| {
+            ClientError::NetworkError("Exec session ended before the command exited".to_string())
+        })
+    }
+}
+
+/// Convert an unexpected close of the session into an error. A normal close
+/// simply ends the session.
+fn close_error(frame: Option<CloseFrame<'_>>) -> Option<ClientError> {
+    let frame = frame?;
+    match frame.code {
+        CloseCode::Normal => None,
+        code => Some(ClientError::NetworkError(format!(
+            "Exec session closed by Nomad: [{}] {}",
+            u16::from(code),
+            frame.reason
+        ))),
+    }
+}
+
+/// Open the exec WebSocket for a request built by the allocation endpoint,
+/// carrying over its query parameters and headers.
+pub(crate) async fn connect(
+    config: &Config,
+    req: reqwest::Request,
+) -> Result<ExecSession, ClientError> {
+    if config.address.starts_with(UNIX_SOCKET_SCHEME) {
+        return Err(ClientError::InvalidInputError(
+            "Exec is not supported over a Unix socket".to_string(),
+        ));
+    }
+
+    let mut url = req.url().clone();
+    let scheme = match url.scheme() {
+        "https" => "wss",
+        _ => "ws",
+    };
+    url.set_scheme(scheme).map_err(|_| {
+        ClientError::RequestCreationError(format!("Invalid exec URL '{}'", req.url()))
+    })?;
+
+    let mut request = url
+        .as_str()
+        .into_client_request()
+        .map_err(|err| ClientError::RequestCreationError(err.to_string()))?;
+    for (name, value) in req.headers() {
+        let name =
+            tokio_tungstenite::tungstenite::http::HeaderName::from_bytes(name.as_str().as_bytes())
+                .map_err(|err| ClientError::RequestCreationError(err.to_string()))?;
+        let value = tokio_tungstenite::tungstenite::http::HeaderValue::from_bytes(value.as_bytes())
+            .map_err(|err| ClientError::RequestCreationError(err.to_string()))?;
+        request.headers_mut().insert(name, value);
+    }
+
+    let connector = match scheme {
+        "wss" => Some(Connector::NativeTls(tls_connector(config)?)),
+        _ => None,
+    };
+    let connect = tokio_tungstenite::connect_async_tls_with_config(request, None, false, connector);
+    let result = match config.connect_timeout {
+        Some(timeout) => tokio::time::timeout(timeout, connect)
+            .await
+            .map_err(|err| ClientError::Timeout(err.to_string()))?,
+        None => connect.await,
+    };
+
+    let (stream, _) = result.map_err(|err| match err {
+        WsError::Http(response) => {
+            let status = reqwest::StatusCode::from_u16(response.status().as_u16())
+                .unwrap_or(reqwest::StatusCode::INTERNAL_SERVER_ERROR);
+            let body = response
+                .into_body()
+                .map(|body| String::from_utf8_lossy(&body).into_owned())
+                .unwrap_or_default();
+            crate::server_error(status, body)
+        }
+        err => websocket_error(err),
+    })?;
+
+    let (sink, stream) = stream.split();
+    Ok(ExecSession {
+        writer: ExecWriter { sink },
+        reader: ExecReader {
+            stream,
+            pending: VecDeque::new(),
+            exit_code: None,
+        },
+    })
+}
+
+/// Build the TLS connector for the WebSocket, using the same TLS settings as
+/// the HTTP client.
+fn tls_connector(config: &Config) -> Result<native_tls::TlsConnector, ClientError> {
+    let mut builder = native_tls::TlsConnector::builder();
+
+    if let Some(ref ca_cert_path) = config.ca_cert_path {
+        let pem = crate::read_tls_file(ca_cert_path)?;
+        let cert = native_tls::Certificate::from_pem(&pem)
+            .map_err(|err| ClientError::ConfigurationError(err.to_string()))?;
+        builder.add_root_certificate(cert);
+    }
+    if let (Some(cert_path), Some(key_path)) = (&config.client_cert_path, &config.client_key_path) {
+        let cert = crate::read_tls_file(cert_path)?;
+        let key = crate::read_tls_key(key_path)?;
+        let identity = native_tls::Identity::from_pkcs8(&cert, &key)
+            .map_err(|err| ClientError::ConfigurationError(err.to_string()))?;
+        builder.identity(identity);
+    }
+    if config.tls_skip_verify {
+        builder.danger_accept_invalid_certs(true);
+    }
+
+    builder
+        .build()
+        .map_err(|err| ClientError::ConfigurationError(err.to_string()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn input_frames_match_protocol() {
+        assert_eq!(
+            ExecInput::Stdin(Bytes::from_static(b"ls -la\n")).encode(),
+            r#"{"stdin":{"data":"bHMgLWxhCg=="}}"#
+        );
+        assert_eq!(
+            ExecInput::CloseStdin.encode(),
+            r#"{"stdin":{"close":true}}"#
+        );
+        assert_eq!(
+            ExecInput::Resize {
+                width: 120,
+                height: 40
+            }
+            .encode(),
+            r#"{"tty_size":{"height":40,"width":120}}"#
+        );
+        assert_eq!(ExecInput::Heartbeat.encode(), "{}");
+    }
+
+    #[test]
+    fn output_frames_decode() {
+        assert_eq!(
+            ExecOutput::decode(r#"{"stdout":{"data":"aGVsbG8K"}}"#).unwrap(),
+            vec![ExecOutput::Stdout(Bytes::from_static(b"hello\n"))]
+        );
+        assert_eq!(
+            ExecOutput::decode(r#"{"stderr":{"data":"b29wcwo="}}"#).unwrap(),
+            vec![ExecOutput::Stderr(Bytes::from_static(b"oops\n"))]
+        );
+        assert!(
+            ExecOutput::decode(r#"{"stdout":{"close":true}}"#)
+                .unwrap()
+                .is_empty()
+        );
+        assert_eq!(
+            ExecOutput::decode(r#"{"exited":true,"result":{"exit_code":3}}"#).unwrap(),
+            vec![ExecOutput::Exited(3)]
+        );
+        assert_eq!(
+            ExecOutput::decode(r#"{"exited":true}"#).unwrap(),
+            vec![ExecOutput::Exited(0)]
+        );
+    }
+
+    #[test]
+    fn invalid_output_frames_are_rejected() {
+        for frame in [r#"{"stdout":{"data":"not base64!"}}"#, "not json"] {
+            let err = ExecOutput::decode(frame).unwrap_err();
+            assert!(
+                matches!(err, ClientError::DeserializationError { ref body_snippet, .. } if body_snippet == frame),
+                "unexpected error: {err:?}"
+            );
+        }
+    }
+}
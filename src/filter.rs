@@ -0,0 +1,184 @@
+//! A builder for Nomad filter expressions, which renders the expression with
+//! string values correctly quoted and escaped. The rendered expression is
+//! passed to Nomad using `QueryOptions::with_filter_expr`.
+//!
+//! ```
+//! use nomad_rs_api::filter::Filter;
+//!
+//! let filter = Filter::eq("Status", "running")
+//!     .and(Filter::eq("NodePool", "gpu").or(Filter::contains("Tags", "gpu")));
+//! assert_eq!(
+//!     filter.to_string(),
+//!     r#"Status == "running" and (NodePool == "gpu" or Tags contains "gpu")"#
+//! );
+//! ```
+
+use std::fmt;
+use std::ops::Not;
+
+/// A value compared against a selector within a filter expression.
+#[derive(Debug, Clone, PartialEq)]
+pub enum FilterValue {
+    String(String),
+    Bool(bool),
+    Integer(i64),
+}
+
+impl fmt::Display for FilterValue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            FilterValue::String(value) => write!(f, "{}", quote(value)),
+            FilterValue::Bool(value) => write!(f, "{}", value),
+            FilterValue::Integer(value) => write!(f, "{}", value),
+        }
+    }
+}
+
+impl From<&str> for FilterValue {
+    fn from(value: &str) -> Self {
+        FilterValue::String(value.to_string())
+    }
+}
+
+impl From<String> for FilterValue {
+    fn from(value: String) -> Self {
+        FilterValue::String(value)
+    }
+}
+
+impl From<bool> for FilterValue {
+    fn from(value: bool) -> Self {
+        FilterValue::Bool(value)
+    }
+}
+
+impl From<i64> for FilterValue {
+    fn from(value: i64) -> Self {
+        FilterValue::Integer(value)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum FilterKind {
+    Match,
+    And,
+    Or,
+    Not,
+}
+
+/// A filter expression. Selectors, such as `Status` or `Meta["team"]`, are
+/// rendered as given, while values are always quoted and escaped, so they may
+/// safely contain untrusted input.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Filter {
+    expr: String,
+    kind: FilterKind,
+}
+
+impl Filter {
+    fn matching(selector: &str, operator: &str, value: impl fmt::Display) -> Self {
+        Self {
+            expr: format!("{} {} {}", selector, operator, value),
+            kind: FilterKind::Match,
+        }
+    }
+
+    /// Use an existing filter expression as given. The expression is
+    /// parenthesised when combined with other filters.
+    pub fn raw(expr: &str) -> Self {
+        Self {
+            expr: expr.to_string(),
+            kind: FilterKind::Or,
+        }
+    }
+
+    /// Match when the selector equals the value.
+    pub fn eq(selector: &str, value: impl Into<FilterValue>) -> Self {
+        Self::matching(selector, "==", value.into())
+    }
+
+    /// Match when the selector does not equal the value.
+    pub fn ne(selector: &str, value: impl Into<FilterValue>) -> Self {
+        Self::matching(selector, "!=", value.into())
+    }
+
+    /// Match when the selector, which must be a list, map, or string,
+    /// contains the value.
+    pub fn contains(selector: &str, value: impl Into<FilterValue>) -> Self {
+        Self::matching(selector, "contains", value.into())
+    }
+
+    /// Match when the selector matches the regular expression.
+    pub fn matches(selector: &str, pattern: &str) -> Self {
+        Self::matching(selector, "matches", quote(pattern))
+    }
+
+    /// Match when both this filter and `other` match.
+    pub fn and(self, other: Filter) -> Self {
+        self.combine(other, FilterKind::And, "and")
+    }
+
+    /// Match when either this filter or `other` matches.
+    pub fn or(self, other: Filter) -> Self {
+        self.combine(other, FilterKind::Or, "or")
+    }
+
+    fn combine(self, other: Filter, kind: FilterKind, operator: &str) -> Self {
+        Self {
+            expr: format!(
+                "{} {} {}",
+                self.operand(kind),
+                operator,
+                other.operand(kind)
+            ),
+            kind,
+        }
+    }
+
+    /// Render the filter as the operand of an expression of the given kind,
+    /// parenthesising it unless doing so is unnecessary.
+    fn operand(&self, parent: FilterKind) -> String {
+        match self.kind {
+            FilterKind::Match | FilterKind::Not => self.expr.clone(),
+            kind if kind == parent => self.expr.clone(),
+            _ => format!("({})", self.expr),
+        }
+    }
+}
+
+impl Not for Filter {
+    type Output = Filter;
+
+    /// Match when the filter does not match.
+    fn not(self) -> Self::Output {
+        Self {
+            expr: format!("not {}", self.operand(FilterKind::Not)),
+            kind: FilterKind::Not,
+        }
+    }
+}
+
+impl fmt::Display for Filter {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.expr)
+    }
+}
+
+/// Quote a string literal, escaping the characters which would otherwise end
+/// the literal or be interpreted as an escape sequence.
+fn quote(value: &str) -> String {
+    let mut quoted = String::with_capacity(value.len() + 2);
+    quoted.push('"');
+    for c in value.chars() {
+        match c {
+            '"' => quoted.push_str("\\\""),
+            '\\' => quoted.push_str("\\\\"),
+            '\n' => quoted.push_str("\\n"),
+            '\r' => quoted.push_str("\\r"),
+            '\t' => quoted.push_str("\\t"),
+            c => quoted.push(c),
+        }
+    }
+    quoted.push('"');
+    quoted
+}
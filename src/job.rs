@@ -1,7 +1,7 @@
 use crate::allocation::{AllocationMetric, AllocationStub};
 use crate::deployment::Deployment;
 use crate::evaluation::Evaluation;
-use crate::option::{QueryOptions, WriteOptions};
+use crate::option::{QueryOptions, SortOrder, WriteOptions};
 use crate::{ClientError, Nomad};
 
 use reqwest::Method;
@@ -173,6 +173,36 @@ pub struct JobSummary {
     pub modify_index: u64,
 }
 
+impl JobSummary {
+    /// Sum the allocation counts of every task group within the job.
+    pub fn totals(&self) -> TaskGroupTotals {
+        self.summary
+            .values()
+            .fold(TaskGroupTotals::default(), |mut totals, group| {
+                totals.queued += i64::from(group.queued);
+                totals.complete += i64::from(group.complete);
+                totals.failed += i64::from(group.failed);
+                totals.running += i64::from(group.running);
+                totals.starting += i64::from(group.starting);
+                totals.lost += i64::from(group.lost);
+                totals.unknown += i64::from(group.unknown);
+                totals
+            })
+    }
+}
+
+/// The allocation counts of a job, summed across all of its task groups.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct TaskGroupTotals {
+    pub queued: i64,
+    pub complete: i64,
+    pub failed: i64,
+    pub running: i64,
+    pub starting: i64,
+    pub lost: i64,
+    pub unknown: i64,
+}
+
 #[derive(Debug, Clone, Deserialize)]
 #[serde(rename_all = "PascalCase")]
 pub struct JobSummaryChildren {
@@ -207,6 +237,36 @@ pub struct JobUpdateStrategy {
     pub auto_promote: Option<bool>,
 }
 
+impl JobUpdateStrategy {
+    /// Get the stagger between allocation updates as a typed duration.
+    ///
+    /// # Returns
+    /// The stagger, or `None` if it is not set.
+    pub fn stagger_duration(&self) -> Option<time::Duration> {
+        self.stagger
+            .map(|nanos| time::Duration::nanoseconds(nanos as i64))
+    }
+
+    /// Get the minimum time an allocation must be healthy as a typed duration.
+    ///
+    /// # Returns
+    /// The minimum healthy time, or `None` if it is not set.
+    pub fn min_healthy_time_duration(&self) -> Option<time::Duration> {
+        self.min_healthy_time
+            .map(|nanos| time::Duration::nanoseconds(nanos as i64))
+    }
+
+    /// Get the deadline by which an allocation must become healthy as a typed
+    /// duration.
+    ///
+    /// # Returns
+    /// The healthy deadline, or `None` if it is not set.
+    pub fn healthy_deadline_duration(&self) -> Option<time::Duration> {
+        self.healthy_deadline
+            .map(|nanos| time::Duration::nanoseconds(nanos as i64))
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "PascalCase")]
 pub struct JobMultiregion {
@@ -400,6 +460,24 @@ impl Task {
             dispatch_payload: None,
         }
     }
+
+    /// Get the kill timeout of the task as a typed duration.
+    ///
+    /// # Returns
+    /// The kill timeout, or `None` if it is not set.
+    pub fn kill_timeout_duration(&self) -> Option<time::Duration> {
+        self.kill_timeout
+            .map(|nanos| time::Duration::nanoseconds(nanos as i64))
+    }
+
+    /// Get the shutdown delay of the task as a typed duration.
+    ///
+    /// # Returns
+    /// The shutdown delay, or `None` if it is not set.
+    pub fn shutdown_delay_duration(&self) -> Option<time::Duration> {
+        self.shutdown_delay
+            .map(|nanos| time::Duration::nanoseconds(nanos as i64))
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -761,6 +839,32 @@ pub struct JobSubmission {
     pub variables: Option<String>,
 }
 
+/// The source of a job specification to register using
+/// `Endpoint::register_spec`.
+#[derive(Debug)]
+pub enum JobSpecSource {
+    /// A job which has already been constructed or decoded.
+    Json(Box<Job>),
+    /// A JSON job specification, either the bare job or the job wrapped in a
+    /// `Job` object as produced by `nomad job run -output`.
+    RawJson(String),
+    /// An HCL2 job specification, which is parsed by Nomad before it is
+    /// registered.
+    Hcl(String),
+}
+
+/// A raw JSON job specification, which may or may not be wrapped in a `Job`
+/// object.
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum RawJobSpec {
+    Wrapped {
+        #[serde(rename = "Job")]
+        job: Job,
+    },
+    Bare(Job),
+}
+
 #[derive(Debug, Default)]
 pub struct RegisterOptions {
     pub enforce_index: bool,
@@ -831,6 +935,13 @@ pub struct JobRegisterResponse {
     pub warnings: Option<String>,
 }
 
+impl JobRegisterResponse {
+    /// Split the warnings returned by Nomad into the individual warnings.
+    pub fn warnings_list(&self) -> Vec<String> {
+        split_warnings(self.warnings.as_deref())
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "PascalCase")]
 pub struct JobDeregisterResponse {
@@ -861,6 +972,15 @@ pub struct JobValidateResponse {
     pub warnings: Option<String>,
 }
 
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "PascalCase")]
+struct JobParseRequest<'a> {
+    #[serde(rename = "JobHCL")]
+    job_hcl: &'a str,
+    canonicalize: bool,
+    variables: Option<String>,
+}
+
 #[derive(Debug, Clone, Serialize)]
 #[serde(rename_all = "PascalCase")]
 pub struct JobPlanRequest<'a> {
@@ -876,11 +996,116 @@ pub struct JobPlanResponse {
     pub created_evals: Vec<Evaluation>,
     pub diff: Option<JobDiff>,
     pub annotations: Option<PlanAnnotations>,
+    #[serde(rename = "FailedTGAllocs")]
     pub failed_tg_allocs: Option<HashMap<String, AllocationMetric>>,
     pub next_periodic_launch: Option<String>,
     pub warnings: Option<String>,
 }
 
+impl JobPlanResponse {
+    /// Split the warnings returned by Nomad into the individual warnings.
+    pub fn warnings_list(&self) -> Vec<String> {
+        split_warnings(self.warnings.as_deref())
+    }
+}
+
+/// A summary of a job plan, used to decide whether to go ahead with
+/// registering the job.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct PlanReport {
+    /// Whether the plan created evaluations, such as a blocked evaluation for
+    /// allocations which could not be placed.
+    pub will_create_evals: bool,
+    /// The number of allocations, across every task group, which would be
+    /// replaced by the update.
+    pub destructive_updates: u64,
+    /// The number of allocations, across every task group, which would be
+    /// updated in place.
+    pub in_place_updates: u64,
+    pub warnings: Vec<String>,
+    /// Whether any task group has allocations which could not be placed.
+    pub failed_tg_allocs_present: bool,
+}
+
+impl PlanReport {
+    /// Whether the job can be registered with every allocation placed.
+    pub fn is_go(&self) -> bool {
+        !self.failed_tg_allocs_present
+    }
+}
+
+impl From<&JobPlanResponse> for PlanReport {
+    fn from(plan: &JobPlanResponse) -> Self {
+        let updates = plan
+            .annotations
+            .as_ref()
+            .and_then(|annotations| annotations.desired_tg_updates.as_ref());
+
+        PlanReport {
+            will_create_evals: !plan.created_evals.is_empty(),
+            destructive_updates: updates.map_or(0, |updates| {
+                updates.values().map(|u| u.destructive_update).sum()
+            }),
+            in_place_updates: updates.map_or(0, |updates| {
+                updates.values().map(|u| u.in_place_update).sum()
+            }),
+            warnings: plan.warnings_list(),
+            failed_tg_allocs_present: plan
+                .failed_tg_allocs
+                .as_ref()
+                .is_some_and(|failed| !failed.is_empty()),
+        }
+    }
+}
+
+/// Encode a request carrying a job, setting the job's Consul and Vault tokens
+/// from the write options. Nomad reads these credentials from the job rather
+/// than from request headers.
+fn job_request_body<T: Serialize>(
+    request: &T,
+    opts: &WriteOptions,
+) -> Result<serde_json::Value, ClientError> {
+    let mut body = serde_json::to_value(request)
+        .map_err(|err| ClientError::RequestCreationError(err.to_string()))?;
+    if let Some(job) = body
+        .get_mut("Job")
+        .and_then(serde_json::Value::as_object_mut)
+    {
+        if let Some(ref consul_token) = opts.consul_token {
+            job.insert("ConsulToken".to_string(), consul_token.clone().into());
+        }
+        if let Some(ref vault_token) = opts.vault_token {
+            job.insert("VaultToken".to_string(), vault_token.clone().into());
+        }
+    }
+    Ok(body)
+}
+
+/// Split a warnings blob into individual warnings. Nomad joins multiple
+/// warnings into a list with a leading "N warnings:" line and a "* " bullet
+/// for each warning, both of which are removed.
+fn split_warnings(warnings: Option<&str>) -> Vec<String> {
+    let Some(warnings) = warnings else {
+        return Vec::new();
+    };
+
+    warnings
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty() && !is_warnings_header(line))
+        .map(|line| line.strip_prefix("* ").unwrap_or(line).to_string())
+        .collect()
+}
+
+/// Whether the line is the count header Nomad writes ahead of the warnings,
+/// such as `2 warning(s):`.
+fn is_warnings_header(line: &str) -> bool {
+    line.strip_suffix(" warning(s):")
+        .or_else(|| line.strip_suffix(" warnings:"))
+        .or_else(|| line.strip_suffix(" warning:"))
+        .is_some_and(|count| count.parse::<u32>().is_ok())
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "PascalCase")]
 pub struct JobDiff {
@@ -937,9 +1162,128 @@ pub struct ObjectDiff {
     pub objects: Option<Vec<ObjectDiff>>,
 }
 
+pub const DIFF_TYPE_NONE: &str = "None";
+pub const DIFF_TYPE_ADDED: &str = "Added";
+pub const DIFF_TYPE_DELETED: &str = "Deleted";
+pub const DIFF_TYPE_EDITED: &str = "Edited";
+
+/// DiffSummary counts the changes within a job diff, split into changed fields
+/// and changed objects, such as task groups, tasks, and nested blocks.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct DiffSummary {
+    pub fields_added: usize,
+    pub fields_deleted: usize,
+    pub fields_edited: usize,
+    pub objects_added: usize,
+    pub objects_deleted: usize,
+    pub objects_edited: usize,
+}
+
+impl DiffSummary {
+    /// The total number of added fields and objects.
+    pub fn added(&self) -> usize {
+        self.fields_added + self.objects_added
+    }
+
+    /// The total number of deleted fields and objects.
+    pub fn deleted(&self) -> usize {
+        self.fields_deleted + self.objects_deleted
+    }
+
+    /// The total number of edited fields and objects.
+    pub fn edited(&self) -> usize {
+        self.fields_edited + self.objects_edited
+    }
+
+    /// Whether the diff contains no changes.
+    pub fn is_empty(&self) -> bool {
+        self.added() + self.deleted() + self.edited() == 0
+    }
+
+    fn count_field(&mut self, type_: &str) {
+        match type_ {
+            DIFF_TYPE_ADDED => self.fields_added += 1,
+            DIFF_TYPE_DELETED => self.fields_deleted += 1,
+            DIFF_TYPE_EDITED => self.fields_edited += 1,
+            _ => {}
+        }
+    }
+
+    fn count_object(&mut self, type_: &str) {
+        match type_ {
+            DIFF_TYPE_ADDED => self.objects_added += 1,
+            DIFF_TYPE_DELETED => self.objects_deleted += 1,
+            DIFF_TYPE_EDITED => self.objects_edited += 1,
+            _ => {}
+        }
+    }
+
+    fn count_children(
+        &mut self,
+        fields: &Option<Vec<FieldDiff>>,
+        objects: &Option<Vec<ObjectDiff>>,
+    ) {
+        for field in fields.iter().flatten() {
+            self.count_field(&field.type_);
+        }
+        for object in objects.iter().flatten() {
+            self.count_object(&object.type_);
+            self.count_children(&object.fields, &object.objects);
+        }
+    }
+}
+
+impl std::fmt::Display for DiffSummary {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} added, {} edited, {} deleted",
+            self.added(),
+            self.edited(),
+            self.deleted()
+        )
+    }
+}
+
+impl JobDiff {
+    /// Count the added, deleted, and edited fields and objects within the
+    /// diff, including every task group and task.
+    pub fn summarize(&self) -> DiffSummary {
+        let mut summary = DiffSummary::default();
+        summary.count_children(&self.fields, &self.objects);
+
+        for task_group in self.task_groups.iter().flatten() {
+            summary.count_object(&task_group.type_);
+            task_group.summarize_into(&mut summary);
+        }
+        summary
+    }
+}
+
+impl TaskGroupDiff {
+    /// Count the added, deleted, and edited fields and objects within the
+    /// task group diff, including every task. The task group itself is not
+    /// counted.
+    pub fn summarize(&self) -> DiffSummary {
+        let mut summary = DiffSummary::default();
+        self.summarize_into(&mut summary);
+        summary
+    }
+
+    fn summarize_into(&self, summary: &mut DiffSummary) {
+        summary.count_children(&self.fields, &self.objects);
+
+        for task in self.tasks.iter().flatten() {
+            summary.count_object(&task.type_);
+            summary.count_children(&task.fields, &task.objects);
+        }
+    }
+}
+
 #[derive(Debug, Clone, Deserialize)]
 #[serde(rename_all = "PascalCase")]
 pub struct PlanAnnotations {
+    #[serde(rename = "DesiredTGUpdates")]
     pub desired_tg_updates: Option<HashMap<String, DesiredUpdates>>,
     pub preempted_allocs: Option<Vec<AllocationStub>>,
 }
@@ -1013,6 +1357,7 @@ impl JobDispatchRequest {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "PascalCase")]
 pub struct JobDispatchResponse {
+    #[serde(rename = "DispatchedJobID")]
     pub dispatched_job_id: String,
     #[serde(rename = "EvalID")]
     pub eval_id: String,
@@ -1036,6 +1381,16 @@ pub struct JobRevertRequest {
     pub enforce_prior_version: Option<u64>,
 }
 
+impl JobRevertRequest {
+    pub fn new(job_id: String, job_version: u64) -> Self {
+        JobRevertRequest {
+            job_id,
+            job_version,
+            enforce_prior_version: None,
+        }
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "PascalCase")]
 pub struct JobStabilityRequest {
@@ -1045,6 +1400,16 @@ pub struct JobStabilityRequest {
     pub stable: bool,
 }
 
+impl JobStabilityRequest {
+    pub fn new(job_id: String, job_version: u64, stable: bool) -> Self {
+        JobStabilityRequest {
+            job_id,
+            job_version,
+            stable,
+        }
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "PascalCase")]
 pub struct JobStabilityResponse {
@@ -1100,6 +1465,32 @@ pub struct ScalingRequest {
     pub meta: Option<HashMap<String, serde_json::Value>>,
 }
 
+impl ScalingRequest {
+    /// Create a new scaling request which sets the count of the named task
+    /// group.
+    pub fn new(group: String, count: i64) -> Self {
+        ScalingRequest {
+            count: Some(count),
+            target: HashMap::from([("Group".to_string(), group)]),
+            error: None,
+            message: None,
+            meta: None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct JobScaleStatusResponse {
+    #[serde(rename = "JobID")]
+    pub job_id: String,
+    pub namespace: String,
+    pub job_create_index: u64,
+    pub job_modify_index: u64,
+    pub job_stopped: bool,
+    pub task_groups: HashMap<String, TaskGroupScaleStatus>,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "PascalCase")]
 pub struct TaskGroupScaleStatus {
@@ -1119,7 +1510,8 @@ pub struct ScalingEvent {
     pub previous_count: i64,
     pub error: bool,
     pub message: Option<String>,
-    pub meta: Option<HashMap<String, String>>,
+    pub meta: Option<HashMap<String, serde_json::Value>>,
+    #[serde(rename = "EvalID")]
     pub eval_id: Option<String>,
 }
 
@@ -1177,7 +1569,8 @@ impl<'a> Endpoint<'a> {
     ///
     /// # Arguments
     /// * `job_dispatch_request` - The job dispatch request containing the job
-    ///   ID and optional payload.
+    ///   ID and optional payload. The payload must not exceed the
+    ///   `DispatchLimits` of the client configuration.
     /// * `opts` - Optional write options for the request.
     ///
     /// # Returns
@@ -1188,6 +1581,17 @@ impl<'a> Endpoint<'a> {
         job_dispatch_request: &JobDispatchRequest,
         opts: Option<WriteOptions>,
     ) -> Result<JobDispatchResponse, ClientError> {
+        let max_payload_size = self.client.config.dispatch_limits.max_payload_size;
+        if let Some(ref payload) = job_dispatch_request.payload
+            && payload.len() > max_payload_size
+        {
+            return Err(ClientError::InvalidInputError(format!(
+                "Dispatch payload of {} bytes exceeds the limit of {} bytes",
+                payload.len(),
+                max_payload_size
+            )));
+        }
+
         let req = self
             .client
             .set_request_write_options(
@@ -1204,6 +1608,61 @@ impl<'a> Endpoint<'a> {
             .await
     }
 
+    /// Dispatch an instance of a parameterized job, validating the provided
+    /// meta against the job before dispatching. Every key within the job's
+    /// `meta_required` must be provided. Keys declared within `meta_optional`
+    /// which are not provided default to the value within the job's own meta,
+    /// when it has one.
+    ///
+    /// # Arguments
+    /// * `job_id` - The ID of the parameterized job to dispatch.
+    /// * `provided_meta` - The meta to dispatch the job with.
+    /// * `opts` - Optional write options, used for both reading and
+    ///   dispatching the job.
+    ///
+    /// # Returns
+    /// A `Result` containing the job dispatch response, or an
+    /// `InvalidInputError` listing the missing keys if any required meta was
+    /// not provided.
+    pub async fn dispatch_with_defaults(
+        &self,
+        job_id: &str,
+        provided_meta: HashMap<String, String>,
+        opts: Option<WriteOptions>,
+    ) -> Result<JobDispatchResponse, ClientError> {
+        let opts = opts.unwrap_or_default();
+        let job = self.get(job_id, Some(QueryOptions::from(&opts))).await?;
+
+        let parameterized = job.parameterized_job.ok_or_else(|| {
+            ClientError::InvalidInputError(format!("Job '{}' is not parameterized", job_id))
+        })?;
+        let meta_required = parameterized.meta_required.unwrap_or_default();
+        let meta_optional = parameterized.meta_optional.unwrap_or_default();
+
+        let missing: Vec<&str> = meta_required
+            .iter()
+            .filter(|key| !provided_meta.contains_key(*key))
+            .map(String::as_str)
+            .collect();
+        if !missing.is_empty() {
+            return Err(ClientError::InvalidInputError(format!(
+                "Missing required dispatch meta keys: {}",
+                missing.join(", ")
+            )));
+        }
+
+        let defaults = job.meta.unwrap_or_default();
+        let mut meta = provided_meta;
+        for key in meta_optional.iter() {
+            if let Some(value) = defaults.get(key) {
+                meta.entry(key.clone()).or_insert_with(|| value.clone());
+            }
+        }
+
+        let req = JobDispatchRequest::new(job_id.to_string()).with_meta(meta);
+        self.dispatch(&req, Some(opts)).await
+    }
+
     /// Force and evaluation of a job.
     ///
     /// # Arguments
@@ -1278,11 +1737,31 @@ impl<'a> Endpoint<'a> {
         self.client.send_with_response::<Job>(req).await
     }
 
+    /// Get a specific job by its ID, returning `None` rather than an error when
+    /// it does not exist.
+    ///
+    /// # Arguments
+    /// * `job_id` - A string representing the ID of the job to retrieve.
+    /// * `opts` - Optional query options for the request.
+    ///
+    /// # Returns
+    /// A `Result` containing the job, `None` if it does not exist, or an error
+    /// if the request fails.
+    pub async fn get_optional(
+        &self,
+        job_id: &str,
+        opts: Option<QueryOptions>,
+    ) -> Result<Option<Job>, ClientError> {
+        crate::not_found_to_none(self.get(job_id, opts).await)
+    }
+
     /// Get the latest deployment for a job.
     ///
     /// # Arguments
     /// * `job_id` - A string representing the ID of the job to query.
-    /// * `opts` - Optional query options for the request.
+    /// * `opts` - Optional query options for the request. For multiregion
+    ///   jobs, use `QueryOptions::with_global` to consider the deployments of
+    ///   every region.
     ///
     /// # Returns
     /// A `Result` containing the latest `Deployment` object or a `ClientError`.
@@ -1339,6 +1818,9 @@ impl<'a> Endpoint<'a> {
         jobs_list_request: Option<&JobsListRequest>,
         opts: Option<QueryOptions>,
     ) -> Result<Vec<JobStub>, ClientError> {
+        let opts = opts.unwrap_or_default();
+        let sort_order = opts.resolve_sort_order(SortOrder::Ascending);
+
         // Determine if meta information is requested and set our meta value
         // accordingly for the query string.
         let meta = match jobs_list_request {
@@ -1349,10 +1831,46 @@ impl<'a> Endpoint<'a> {
         let req = self.client.set_request_query_options(
             self.client
                 .build_request(Method::GET, &format!("/v1/jobs?meta={}", meta)),
-            &opts.unwrap_or_default(),
+            &opts,
         );
         let mut jobs = self.client.send_with_response::<Vec<JobStub>>(req).await?;
-        jobs.sort_by(|a, b| a.id.cmp(&b.id));
+        sort_order.sort_by(&mut jobs, |a, b| a.id.cmp(&b.id));
+        Ok(jobs)
+    }
+
+    /// List all registered jobs, automatically following pagination until
+    /// every page has been read.
+    ///
+    /// All jobs are buffered in memory before being returned, which may be
+    /// significant on large clusters. Use `list` with `QueryOptions::per_page`
+    /// and `QueryOptions::next_token` to control paging manually.
+    ///
+    /// # Arguments
+    /// * `jobs_list_request` - An optional reference to a `JobsListRequest`
+    ///   struct containing parameters for the request.
+    /// * `opts` - Optional query options for the request. The page size is
+    ///   capped at `QUERY_MAX_PER_PAGE`.
+    ///
+    /// # Returns
+    /// A `Result` containing a vector of `JobStub` structs or a `ClientError`.
+    pub async fn list_all(
+        &self,
+        jobs_list_request: Option<&JobsListRequest>,
+        opts: Option<QueryOptions>,
+    ) -> Result<Vec<JobStub>, ClientError> {
+        let opts = opts.unwrap_or_default();
+        let sort_order = opts.resolve_sort_order(SortOrder::Ascending);
+
+        let meta = match jobs_list_request {
+            Some(req) => req.meta.unwrap_or(false),
+            None => false,
+        };
+
+        let mut jobs = self
+            .client
+            .send_paginated::<JobStub>(&format!("/v1/jobs?meta={}", meta), opts)
+            .await?;
+        sort_order.sort_by(&mut jobs, |a, b| a.id.cmp(&b.id));
         Ok(jobs)
     }
 
@@ -1372,6 +1890,8 @@ impl<'a> Endpoint<'a> {
         job_allocations_list_request: &JobAllocationsListRequest,
         opts: Option<QueryOptions>,
     ) -> Result<Vec<AllocationStub>, ClientError> {
+        let opts = opts.unwrap_or_default();
+        let sort_order = opts.resolve_sort_order(SortOrder::Descending);
         let req = self.client.set_request_query_options(
             self.client.build_request(
                 Method::GET,
@@ -1380,7 +1900,7 @@ impl<'a> Endpoint<'a> {
                     job_allocations_list_request.job_id, job_allocations_list_request.all_allocs
                 ),
             ),
-            &opts.unwrap_or_default(),
+            &opts,
         );
 
         let mut allocs = self
@@ -1388,7 +1908,7 @@ impl<'a> Endpoint<'a> {
             .send_with_response::<Vec<AllocationStub>>(req)
             .await?;
 
-        allocs.sort_by(|a, b| b.create_index.cmp(&a.create_index));
+        sort_order.sort_by(&mut allocs, |a, b| a.create_index.cmp(&b.create_index));
         Ok(allocs)
     }
 
@@ -1407,6 +1927,8 @@ impl<'a> Endpoint<'a> {
         job_list_deployments_request: &JobListDeploymentsRequest,
         opts: Option<QueryOptions>,
     ) -> Result<Vec<Deployment>, ClientError> {
+        let opts = opts.unwrap_or_default();
+        let sort_order = opts.resolve_sort_order(SortOrder::Descending);
         let req = self.client.set_request_query_options(
             self.client.build_request(
                 Method::GET,
@@ -1415,14 +1937,14 @@ impl<'a> Endpoint<'a> {
                     job_list_deployments_request.job_id, job_list_deployments_request.all
                 ),
             ),
-            &opts.unwrap_or_default(),
+            &opts,
         );
 
         let mut deployments = self
             .client
             .send_with_response::<Vec<Deployment>>(req)
             .await?;
-        deployments.sort_by(|a, b| b.create_index.cmp(&a.create_index));
+        sort_order.sort_by(&mut deployments, |a, b| a.create_index.cmp(&b.create_index));
         Ok(deployments)
     }
 
@@ -1440,20 +1962,125 @@ impl<'a> Endpoint<'a> {
         job_id: &str,
         opts: Option<QueryOptions>,
     ) -> Result<Vec<Evaluation>, ClientError> {
+        let opts = opts.unwrap_or_default();
+        let sort_order = opts.resolve_sort_order(SortOrder::Descending);
         let req = self.client.set_request_query_options(
             self.client
                 .build_request(Method::GET, &format!("/v1/job/{}/evaluations", job_id)),
-            &opts.unwrap_or_default(),
+            &opts,
         );
 
         let mut evals = self
             .client
             .send_with_response::<Vec<Evaluation>>(req)
             .await?;
-        evals.sort_by(|a, b| b.create_index.cmp(&a.create_index));
+        sort_order.sort_by(&mut evals, |a, b| a.create_index.cmp(&b.create_index));
         Ok(evals)
     }
 
+    /// List all registered jobs within a single namespace.
+    ///
+    /// # Arguments
+    /// * `namespace` - The namespace to list the jobs of. This overrides any
+    ///   namespace set within `opts`. Use `namespace::NAMESPACE_WILDCARD`
+    ///   (`"*"`) to list the jobs of every namespace in a single call.
+    /// * `jobs_list_request` - An optional reference to a `JobsListRequest`
+    ///   struct containing parameters for the request.
+    /// * `opts` - Optional query options for the request.
+    ///
+    /// # Returns
+    /// A `Result` containing a vector of `JobStub` structs or a `ClientError`.
+    pub async fn list_in_namespace(
+        &self,
+        namespace: &str,
+        jobs_list_request: Option<&JobsListRequest>,
+        opts: Option<QueryOptions>,
+    ) -> Result<Vec<JobStub>, ClientError> {
+        if namespace.is_empty() {
+            return Err(ClientError::InvalidInputError(
+                "Namespace must be set".to_string(),
+            ));
+        }
+
+        let mut opts = opts.unwrap_or_default();
+        opts.namespace = Some(namespace.to_string());
+        self.list(jobs_list_request, Some(opts)).await
+    }
+
+    /// Parse an HCL job specification into a `Job`, without registering it.
+    ///
+    /// # Arguments
+    /// * `hcl` - The HCL2 job specification to parse.
+    /// * `canonicalize` - Whether to populate the job with Nomad's default
+    ///   values.
+    /// * `opts` - Optional write options for the request.
+    ///
+    /// # Returns
+    /// A `Result` containing the parsed `Job` or a `ClientError`.
+    pub async fn parse(
+        &self,
+        hcl: &str,
+        canonicalize: bool,
+        opts: Option<WriteOptions>,
+    ) -> Result<Job, ClientError> {
+        self.parse_with_variables(hcl, None, canonicalize, opts)
+            .await
+    }
+
+    /// Parse an HCL job specification into a `Job`, setting the values of the
+    /// HCL2 variables declared within the specification.
+    ///
+    /// # Arguments
+    /// * `hcl` - The HCL2 job specification to parse.
+    /// * `variables` - Optional values of the HCL2 variables, keyed by the
+    ///   variable name.
+    /// * `canonicalize` - Whether to populate the job with Nomad's default
+    ///   values.
+    /// * `opts` - Optional write options for the request.
+    ///
+    /// # Returns
+    /// A `Result` containing the parsed `Job` or a `ClientError`.
+    pub async fn parse_with_variables(
+        &self,
+        hcl: &str,
+        variables: Option<&HashMap<String, String>>,
+        canonicalize: bool,
+        opts: Option<WriteOptions>,
+    ) -> Result<Job, ClientError> {
+        if hcl.trim().is_empty() {
+            return Err(ClientError::InvalidInputError(
+                "Job HCL must be set".to_string(),
+            ));
+        }
+
+        // Nomad accepts the variables as the contents of an HCL variables
+        // file. JSON string escaping is a valid subset of HCL string syntax,
+        // once template sequences are escaped so values are taken literally.
+        let variables = variables.map(|variables| {
+            variables
+                .iter()
+                .map(|(name, value)| {
+                    let value = value.replace("${", "$${").replace("%{", "%%{");
+                    format!("{} = {}\n", name, serde_json::Value::from(value))
+                })
+                .collect::<String>()
+        });
+
+        let req = self
+            .client
+            .set_request_write_options(
+                self.client.build_request(Method::POST, "/v1/jobs/parse"),
+                &opts.unwrap_or_default(),
+            )
+            .json(&JobParseRequest {
+                job_hcl: hcl,
+                canonicalize,
+                variables,
+            });
+
+        self.client.send_with_response::<Job>(req).await
+    }
+
     /// Perform a job rgistration plan.
     ///
     /// # Arguments
@@ -1477,6 +2104,7 @@ impl<'a> Endpoint<'a> {
             }
         }
 
+        let opts = opts.unwrap_or_default();
         let req = self
             .client
             .set_request_write_options(
@@ -1484,13 +2112,57 @@ impl<'a> Endpoint<'a> {
                     Method::POST,
                     &format!("/v1/job/{}/plan", job_plan_request.job.id.as_ref().unwrap()),
                 ),
-                &opts.unwrap_or_default(),
+                &opts,
             )
-            .json(&job_plan_request);
+            .json(&job_request_body(job_plan_request, &opts)?);
 
         self.client.send_with_response::<JobPlanResponse>(req).await
     }
 
+    /// Plan a job, including the diff, and summarize the result as a
+    /// `PlanReport`. Nothing is changed within the cluster, so this can be used
+    /// as a dry run of registering the job.
+    ///
+    /// # Arguments
+    /// * `job` - The job to plan.
+    /// * `opts` - Optional write options for the request.
+    ///
+    /// # Returns
+    /// A `Result` containing the `PlanReport` or an error if the request fails.
+    pub async fn plan_and_report(
+        &self,
+        job: &Job,
+        opts: Option<WriteOptions>,
+    ) -> Result<PlanReport, ClientError> {
+        let req = JobPlanRequest {
+            job,
+            diff: true,
+            policy_override: false,
+        };
+        let plan = self.plan(&req, opts).await?;
+        Ok(PlanReport::from(&plan))
+    }
+
+    /// Stop a job and purge it from the Nomad state, so it no longer appears
+    /// within job listings and its history is removed. Use `deregister` for
+    /// control over the remaining deregistration options.
+    ///
+    /// # Arguments
+    /// * `job_id` - The ID of the job to purge.
+    /// * `opts` - Optional write options for the request.
+    ///
+    /// # Returns
+    /// A `Result` containing a `JobDeregisterResponse` or a `ClientError`.
+    pub async fn purge(
+        &self,
+        job_id: &str,
+        opts: Option<WriteOptions>,
+    ) -> Result<JobDeregisterResponse, ClientError> {
+        let mut job_deregister_request = JobDeregisterRequest::new(job_id.to_string());
+        job_deregister_request.purge = true;
+        self.deregister(&job_deregister_request, opts).await
+    }
+
     /// Register is used to run a new job or update on existing job.
     ///
     /// # Arguments
@@ -1505,20 +2177,259 @@ impl<'a> Endpoint<'a> {
         &self,
         job_register_request: &JobRegisterRequest<'_>,
         opts: Option<WriteOptions>,
+    ) -> Result<JobRegisterResponse, ClientError> {
+        let opts = opts.unwrap_or_default();
+        let req = self
+            .client
+            .set_request_write_options(self.client.build_request(Method::POST, "/v1/jobs"), &opts)
+            .json(&job_request_body(job_register_request, &opts)?);
+
+        self.client
+            .send_with_response::<JobRegisterResponse>(req)
+            .await
+    }
+
+    /// Register a job using an idempotency token, so the request can be
+    /// safely retried. Retrying with the same token after a failure, such as a
+    /// timeout where the outcome is unknown, allows Nomad to identify the
+    /// repeated request rather than treating it as a new registration.
+    ///
+    /// # Arguments
+    /// * `job_register_request` - A reference to a `JobRegisterRequest` struct
+    ///   containing the job to register and any additional registration
+    ///   options.
+    /// * `token` - The idempotency token, which must be unique to the intended
+    ///   registration and reused for every retry of it. This overrides any
+    ///   token set within `opts`.
+    /// * `opts` - Optional write options for the request.
+    ///
+    /// # Returns
+    /// A `Result` containing a `JobRegisterResponse` or a `ClientError`.
+    pub async fn register_idempotent(
+        &self,
+        job_register_request: &JobRegisterRequest<'_>,
+        token: &str,
+        opts: Option<WriteOptions>,
+    ) -> Result<JobRegisterResponse, ClientError> {
+        if token.is_empty() {
+            return Err(ClientError::InvalidInputError(
+                "Idempotency token must be set".to_string(),
+            ));
+        }
+
+        let opts = opts
+            .unwrap_or_default()
+            .with_idempotency_token(token.to_string());
+        self.regsiter(job_register_request, Some(opts)).await
+    }
+
+    /// Register a job from any supported specification source. HCL sources are
+    /// first parsed by Nomad and raw JSON sources are decoded locally. The
+    /// original source is attached to the registration as a `JobSubmission`,
+    /// so it can later be read back from Nomad.
+    ///
+    /// # Arguments
+    /// * `spec` - The job specification to register.
+    /// * `opts` - Optional write options for the request. Any idempotency,
+    ///   Consul, or Vault token is only sent with the registration.
+    ///
+    /// # Returns
+    /// A `Result` containing a `JobRegisterResponse` or a `ClientError`.
+    pub async fn register_spec(
+        &self,
+        spec: JobSpecSource,
+        opts: Option<WriteOptions>,
+    ) -> Result<JobRegisterResponse, ClientError> {
+        let opts = opts.unwrap_or_default();
+
+        let (job, source, format) = match spec {
+            JobSpecSource::Json(job) => {
+                let source = serde_json::to_string(&job).map_err(|e| {
+                    ClientError::InvalidInputError(format!("Failed to encode job: {}", e))
+                })?;
+                (*job, source, "json")
+            }
+            JobSpecSource::RawJson(source) => {
+                let job = match serde_json::from_str::<RawJobSpec>(&source) {
+                    Ok(RawJobSpec::Wrapped { job } | RawJobSpec::Bare(job)) => job,
+                    Err(e) => {
+                        return Err(ClientError::InvalidInputError(format!(
+                            "Failed to decode job JSON: {}",
+                            e
+                        )));
+                    }
+                };
+                (job, source, "json")
+            }
+            JobSpecSource::Hcl(source) => {
+                let parse_opts = WriteOptions {
+                    region: opts.region.clone(),
+                    namespace: opts.namespace.clone(),
+                    auth_token: opts.auth_token.clone(),
+                    headers: opts.headers.clone(),
+                    ..Default::default()
+                };
+                let job = self.parse(&source, false, Some(parse_opts)).await?;
+                (job, source, "hcl2")
+            }
+        };
+
+        let mut job_register_request = JobRegisterRequest::new(&job);
+        job_register_request.submission = Some(JobSubmission {
+            source,
+            format: format.to_string(),
+            variable_flags: None,
+            variables: None,
+        });
+        self.regsiter(&job_register_request, Some(opts)).await
+    }
+
+    /// Revert a job to a previous version.
+    ///
+    /// # Arguments
+    /// * `job_revert_request` - A reference to a `JobRevertRequest` struct
+    ///   containing the job ID and the version to revert to.
+    /// * `opts` - Optional write options for the request.
+    ///
+    /// # Returns
+    /// A `Result` containing a `JobRegisterResponse` or a `ClientError`.
+    pub async fn revert(
+        &self,
+        job_revert_request: &JobRevertRequest,
+        opts: Option<WriteOptions>,
+    ) -> Result<JobRegisterResponse, ClientError> {
+        if job_revert_request.job_id.is_empty() {
+            return Err(ClientError::InvalidInputError(
+                "Job ID must be set".to_string(),
+            ));
+        }
+
+        let req = self
+            .client
+            .set_request_write_options(
+                self.client.build_request(
+                    Method::POST,
+                    &format!("/v1/job/{}/revert", job_revert_request.job_id),
+                ),
+                &opts.unwrap_or_default(),
+            )
+            .json(job_revert_request);
+
+        self.client
+            .send_with_response::<JobRegisterResponse>(req)
+            .await
+    }
+
+    /// Scale the count of a task group within a job.
+    ///
+    /// # Arguments
+    /// * `job_id` - A string representing the ID of the job to scale.
+    /// * `scaling_request` - A reference to a `ScalingRequest` struct
+    ///   containing the target task group and desired count.
+    /// * `opts` - Optional write options for the request.
+    ///
+    /// # Returns
+    /// A `Result` containing a `JobRegisterResponse` or a `ClientError`.
+    pub async fn scale(
+        &self,
+        job_id: &str,
+        scaling_request: &ScalingRequest,
+        opts: Option<WriteOptions>,
     ) -> Result<JobRegisterResponse, ClientError> {
         let req = self
             .client
             .set_request_write_options(
-                self.client.build_request(Method::POST, "/v1/jobs"),
+                self.client
+                    .build_request(Method::POST, &format!("/v1/job/{}/scale", job_id)),
                 &opts.unwrap_or_default(),
             )
-            .json(&job_register_request);
+            .json(scaling_request);
 
         self.client
             .send_with_response::<JobRegisterResponse>(req)
             .await
     }
 
+    /// Get the scaling status of each task group within a job.
+    ///
+    /// # Arguments
+    /// * `job_id` - A string representing the ID of the job to query.
+    /// * `opts` - Optional query options for the request.
+    ///
+    /// # Returns
+    /// A `Result` containing a `JobScaleStatusResponse` or a `ClientError`.
+    pub async fn scale_status(
+        &self,
+        job_id: &str,
+        opts: Option<QueryOptions>,
+    ) -> Result<JobScaleStatusResponse, ClientError> {
+        let req = self.client.set_request_query_options(
+            self.client
+                .build_request(Method::GET, &format!("/v1/job/{}/scale", job_id)),
+            &opts.unwrap_or_default(),
+        );
+
+        self.client
+            .send_with_response::<JobScaleStatusResponse>(req)
+            .await
+    }
+
+    /// Set the stability of a job version. Marking a version as stable makes
+    /// it the target of future auto-reverts.
+    ///
+    /// # Arguments
+    /// * `job_stability_request` - A reference to a `JobStabilityRequest`
+    ///   struct containing the job ID, version, and desired stability.
+    /// * `opts` - Optional write options for the request.
+    ///
+    /// # Returns
+    /// A `Result` containing a `JobStabilityResponse` or a `ClientError`.
+    pub async fn set_stability(
+        &self,
+        job_stability_request: &JobStabilityRequest,
+        opts: Option<WriteOptions>,
+    ) -> Result<JobStabilityResponse, ClientError> {
+        if job_stability_request.job_id.is_empty() {
+            return Err(ClientError::InvalidInputError(
+                "Job ID must be set".to_string(),
+            ));
+        }
+
+        let req = self
+            .client
+            .set_request_write_options(
+                self.client.build_request(
+                    Method::POST,
+                    &format!("/v1/job/{}/stable", job_stability_request.job_id),
+                ),
+                &opts.unwrap_or_default(),
+            )
+            .json(job_stability_request);
+
+        self.client
+            .send_with_response::<JobStabilityResponse>(req)
+            .await
+    }
+
+    /// Stop a job, leaving it registered so it can be inspected or started
+    /// again. Use `deregister` for control over the remaining deregistration
+    /// options.
+    ///
+    /// # Arguments
+    /// * `job_id` - The ID of the job to stop.
+    /// * `opts` - Optional write options for the request.
+    ///
+    /// # Returns
+    /// A `Result` containing a `JobDeregisterResponse` or a `ClientError`.
+    pub async fn stop(
+        &self,
+        job_id: &str,
+        opts: Option<WriteOptions>,
+    ) -> Result<JobDeregisterResponse, ClientError> {
+        let job_deregister_request = JobDeregisterRequest::new(job_id.to_string());
+        self.deregister(&job_deregister_request, opts).await
+    }
+
     /// Validate a job.
     ///
     /// # Arguments
@@ -1545,4 +2456,33 @@ impl<'a> Endpoint<'a> {
             .send_with_response::<JobValidateResponse>(req)
             .await
     }
+
+    /// List all versions of a job.
+    ///
+    /// # Arguments
+    /// * `job_id` - A string representing the ID of the job to query.
+    /// * `diffs` - Whether the response should include the diffs between each
+    ///   version of the job.
+    /// * `opts` - Optional query options for the request.
+    ///
+    /// # Returns
+    /// A `Result` containing a `JobVersionsResponse` or a `ClientError`.
+    pub async fn versions(
+        &self,
+        job_id: &str,
+        diffs: bool,
+        opts: Option<QueryOptions>,
+    ) -> Result<JobVersionsResponse, ClientError> {
+        let req = self.client.set_request_query_options(
+            self.client.build_request(
+                Method::GET,
+                &format!("/v1/job/{}/versions?diffs={}", job_id, diffs),
+            ),
+            &opts.unwrap_or_default(),
+        );
+
+        self.client
+            .send_with_response::<JobVersionsResponse>(req)
+            .await
+    }
 }
@@ -0,0 +1,382 @@
+use crate::ClientError;
+use crate::job::{
+    Constraint, JOB_TYPE_BATCH, JOB_TYPE_SERVICE, JOB_TYPE_SYSBATCH, JOB_TYPE_SYSTEM, Job,
+    JobTaskGroup, JobUpdateStrategy, NetworkResource, RestartPolicy, Service, Task, TaskResources,
+};
+use std::collections::{HashMap, HashSet};
+use std::time::Duration;
+
+/// JobBuilder constructs a `Job` using chainable methods, validating the
+/// result when calling `build`.
+#[derive(Debug)]
+pub struct JobBuilder {
+    job: Job,
+}
+
+impl JobBuilder {
+    /// Create a new `JobBuilder` for a service job with the given name, which
+    /// is also used as the job ID unless set via `id`.
+    pub fn new(name: &str) -> Self {
+        Self {
+            job: Job {
+                name: name.to_string(),
+                task_groups: Vec::new(),
+                ..Default::default()
+            },
+        }
+    }
+
+    pub fn id(mut self, id: &str) -> Self {
+        self.job.id = Some(id.to_string());
+        self
+    }
+
+    pub fn namespace(mut self, namespace: &str) -> Self {
+        self.job.namespace = Some(namespace.to_string());
+        self
+    }
+
+    pub fn region(mut self, region: &str) -> Self {
+        self.job.region = Some(region.to_string());
+        self
+    }
+
+    /// Set the type of the job, such as `JOB_TYPE_SERVICE` or `JOB_TYPE_BATCH`.
+    pub fn job_type(mut self, job_type: &str) -> Self {
+        self.job.type_ = Some(job_type.to_string());
+        self
+    }
+
+    pub fn datacenters(mut self, datacenters: &[&str]) -> Self {
+        self.job.datacenters = Some(datacenters.iter().map(|dc| dc.to_string()).collect());
+        self
+    }
+
+    pub fn node_pool(mut self, node_pool: &str) -> Self {
+        self.job.node_pool = Some(node_pool.to_string());
+        self
+    }
+
+    pub fn priority(mut self, priority: i32) -> Self {
+        self.job.priority = Some(priority);
+        self
+    }
+
+    pub fn meta(mut self, key: &str, value: &str) -> Self {
+        self.job
+            .meta
+            .get_or_insert_with(HashMap::new)
+            .insert(key.to_string(), value.to_string());
+        self
+    }
+
+    pub fn constraint(mut self, constraint: Constraint) -> Self {
+        self.job
+            .constraints
+            .get_or_insert_with(Vec::new)
+            .push(constraint);
+        self
+    }
+
+    pub fn update_strategy(mut self, update_strategy: JobUpdateStrategy) -> Self {
+        self.job.update = Some(update_strategy);
+        self
+    }
+
+    pub fn add_task_group(mut self, task_group: JobTaskGroup) -> Self {
+        self.job.task_groups.push(task_group);
+        self
+    }
+
+    /// Validate and return the constructed job.
+    ///
+    /// # Returns
+    /// A `Result` containing the `Job` or a `ClientError::InvalidInputError`
+    /// if the job is missing required fields, has an unknown type, or contains
+    /// duplicate task group names.
+    pub fn build(mut self) -> Result<Job, ClientError> {
+        if self.job.name.is_empty() {
+            return Err(ClientError::InvalidInputError(
+                "Job name must be set".to_string(),
+            ));
+        }
+        if self.job.id.as_deref().is_some_and(str::is_empty) {
+            return Err(ClientError::InvalidInputError(
+                "Job ID must not be empty".to_string(),
+            ));
+        }
+        if let Some(ref job_type) = self.job.type_
+            && ![
+                JOB_TYPE_SERVICE,
+                JOB_TYPE_BATCH,
+                JOB_TYPE_SYSTEM,
+                JOB_TYPE_SYSBATCH,
+            ]
+            .contains(&job_type.as_str())
+        {
+            return Err(ClientError::InvalidInputError(format!(
+                "Invalid job type: {:?}",
+                job_type
+            )));
+        }
+        if let Some(priority) = self.job.priority
+            && !(1..=100).contains(&priority)
+        {
+            return Err(ClientError::InvalidInputError(format!(
+                "Job priority must be between 1 and 100, got {}",
+                priority
+            )));
+        }
+        if self.job.task_groups.is_empty() {
+            return Err(ClientError::InvalidInputError(
+                "Job must contain at least one task group".to_string(),
+            ));
+        }
+
+        let mut names = HashSet::new();
+        for task_group in &self.job.task_groups {
+            if !names.insert(task_group.name.as_str()) {
+                return Err(ClientError::InvalidInputError(format!(
+                    "Duplicate task group name: {}",
+                    task_group.name
+                )));
+            }
+        }
+
+        if self.job.id.is_none() {
+            self.job.id = Some(self.job.name.clone());
+        }
+        Ok(self.job)
+    }
+}
+
+/// TaskGroupBuilder constructs a `JobTaskGroup` using chainable methods,
+/// validating the result when calling `build`.
+#[derive(Debug)]
+pub struct TaskGroupBuilder {
+    task_group: JobTaskGroup,
+}
+
+impl TaskGroupBuilder {
+    /// Create a new `TaskGroupBuilder` for a task group with the given name.
+    pub fn new(name: &str) -> Self {
+        Self {
+            task_group: JobTaskGroup::new(name.to_string(), Vec::new()),
+        }
+    }
+
+    pub fn count(mut self, count: i32) -> Self {
+        self.task_group.count = Some(count);
+        self
+    }
+
+    pub fn add_task(mut self, task: Task) -> Self {
+        self.task_group.tasks.push(task);
+        self
+    }
+
+    pub fn constraint(mut self, constraint: Constraint) -> Self {
+        self.task_group
+            .constraints
+            .get_or_insert_with(Vec::new)
+            .push(constraint);
+        self
+    }
+
+    pub fn network(mut self, network: NetworkResource) -> Self {
+        self.task_group
+            .networks
+            .get_or_insert_with(Vec::new)
+            .push(network);
+        self
+    }
+
+    pub fn service(mut self, service: Service) -> Self {
+        self.task_group
+            .services
+            .get_or_insert_with(Vec::new)
+            .push(service);
+        self
+    }
+
+    pub fn meta(mut self, key: &str, value: &str) -> Self {
+        self.task_group
+            .meta
+            .get_or_insert_with(HashMap::new)
+            .insert(key.to_string(), value.to_string());
+        self
+    }
+
+    pub fn restart_policy(mut self, restart_policy: RestartPolicy) -> Self {
+        self.task_group.restart_policy = Some(restart_policy);
+        self
+    }
+
+    pub fn update_strategy(mut self, update_strategy: JobUpdateStrategy) -> Self {
+        self.task_group.update = Some(update_strategy);
+        self
+    }
+
+    /// Validate and return the constructed task group.
+    ///
+    /// # Returns
+    /// A `Result` containing the `JobTaskGroup` or a
+    /// `ClientError::InvalidInputError` if the task group is missing required
+    /// fields or contains duplicate task names.
+    pub fn build(self) -> Result<JobTaskGroup, ClientError> {
+        if self.task_group.name.is_empty() {
+            return Err(ClientError::InvalidInputError(
+                "Task group name must be set".to_string(),
+            ));
+        }
+        if self.task_group.count.is_some_and(|count| count < 0) {
+            return Err(ClientError::InvalidInputError(format!(
+                "Task group {} count must not be negative",
+                self.task_group.name
+            )));
+        }
+        if self.task_group.tasks.is_empty() {
+            return Err(ClientError::InvalidInputError(format!(
+                "Task group {} must contain at least one task",
+                self.task_group.name
+            )));
+        }
+
+        let mut names = HashSet::new();
+        for task in &self.task_group.tasks {
+            if !names.insert(task.name.as_str()) {
+                return Err(ClientError::InvalidInputError(format!(
+                    "Duplicate task name in task group {}: {}",
+                    self.task_group.name, task.name
+                )));
+            }
+        }
+        Ok(self.task_group)
+    }
+}
+
+/// TaskBuilder constructs a `Task` using chainable methods, validating the
+/// result when calling `build`.
+#[derive(Debug)]
+pub struct TaskBuilder {
+    task: Task,
+    kill_timeout: Option<Duration>,
+}
+
+impl TaskBuilder {
+    /// Create a new `TaskBuilder` for a task with the given name which is run
+    /// using the given driver.
+    pub fn new(name: &str, driver: &str) -> Self {
+        Self {
+            task: Task::new(name.to_string(), driver.to_string()),
+            kill_timeout: None,
+        }
+    }
+
+    /// Set a single driver configuration value.
+    pub fn config(mut self, key: &str, value: serde_json::Value) -> Self {
+        self.task
+            .config
+            .get_or_insert_with(HashMap::new)
+            .insert(key.to_string(), value);
+        self
+    }
+
+    pub fn env(mut self, key: &str, value: &str) -> Self {
+        self.task
+            .env
+            .get_or_insert_with(HashMap::new)
+            .insert(key.to_string(), value.to_string());
+        self
+    }
+
+    pub fn meta(mut self, key: &str, value: &str) -> Self {
+        self.task
+            .meta
+            .get_or_insert_with(HashMap::new)
+            .insert(key.to_string(), value.to_string());
+        self
+    }
+
+    pub fn constraint(mut self, constraint: Constraint) -> Self {
+        self.task
+            .constraints
+            .get_or_insert_with(Vec::new)
+            .push(constraint);
+        self
+    }
+
+    pub fn service(mut self, service: Service) -> Self {
+        self.task
+            .services
+            .get_or_insert_with(Vec::new)
+            .push(service);
+        self
+    }
+
+    pub fn resources(mut self, resources: TaskResources) -> Self {
+        self.task.resources = Some(resources);
+        self
+    }
+
+    /// Set the CPU in MHz and memory in MB reserved for the task.
+    pub fn cpu_and_memory(mut self, cpu: i64, memory_mb: i64) -> Self {
+        let resources = self.task.resources.get_or_insert(TaskResources {
+            cpu: None,
+            cores: None,
+            memory_mb: None,
+            memory_max_mb: None,
+            disk_mb: None,
+            networks: None,
+            devices: None,
+        });
+        resources.cpu = Some(cpu);
+        resources.memory_mb = Some(memory_mb);
+        self
+    }
+
+    pub fn kill_timeout(mut self, kill_timeout: Duration) -> Self {
+        self.kill_timeout = Some(kill_timeout);
+        self
+    }
+
+    pub fn leader(mut self, leader: bool) -> Self {
+        self.task.leader = Some(leader);
+        self
+    }
+
+    pub fn user(mut self, user: &str) -> Self {
+        self.task.user = Some(user.to_string());
+        self
+    }
+
+    /// Validate and return the constructed task.
+    ///
+    /// # Returns
+    /// A `Result` containing the `Task` or a `ClientError::InvalidInputError`
+    /// if the task is missing required fields or its kill timeout is too large
+    /// to encode.
+    pub fn build(mut self) -> Result<Task, ClientError> {
+        if self.task.name.is_empty() {
+            return Err(ClientError::InvalidInputError(
+                "Task name must be set".to_string(),
+            ));
+        }
+        if self.task.driver.is_empty() {
+            return Err(ClientError::InvalidInputError(format!(
+                "Task {} driver must be set",
+                self.task.name
+            )));
+        }
+        if let Some(kill_timeout) = self.kill_timeout {
+            let nanos = u64::try_from(kill_timeout.as_nanos()).map_err(|_| {
+                ClientError::InvalidInputError(format!(
+                    "Task {} kill timeout is too large",
+                    self.task.name
+                ))
+            })?;
+            self.task.kill_timeout = Some(nanos);
+        }
+        Ok(self.task)
+    }
+}
@@ -0,0 +1,295 @@
+use crate::{ClientError, DESERIALIZATION_ERROR_SNIPPET_LEN};
+use reqwest::StatusCode;
+use serde::de::DeserializeOwned;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum State {
+    /// Waiting for the opening bracket of the array.
+    Start,
+    /// After the opening bracket, waiting for the first element or the
+    /// closing bracket of an empty array.
+    First,
+    /// After an element, waiting for a comma or the closing bracket.
+    AfterElement,
+    /// After a comma, waiting for the next element.
+    Next,
+    /// Within an element.
+    Element,
+    /// After the closing bracket of the array.
+    End,
+}
+
+/// An incremental decoder for a JSON array, which decodes each element as soon
+/// as its bytes have been received. Only the bytes of the element currently
+/// being received are buffered, rather than the entire response body.
+pub(crate) struct ArrayDecoder<T> {
+    status: StatusCode,
+    state: State,
+    element: Vec<u8>,
+    depth: usize,
+    in_string: bool,
+    escaped: bool,
+    items: Vec<T>,
+}
+
+impl<T: DeserializeOwned> ArrayDecoder<T> {
+    pub(crate) fn new(status: StatusCode) -> Self {
+        Self {
+            status,
+            state: State::Start,
+            element: Vec::new(),
+            depth: 0,
+            in_string: false,
+            escaped: false,
+            items: Vec::new(),
+        }
+    }
+
+    /// Decode the next chunk of the response body.
+    pub(crate) fn decode(&mut self, chunk: &[u8]) -> Result<(), ClientError> {
+        for &byte in chunk {
+            match self.state {
+                State::Start => match byte {
+                    b'[' => self.state = State::First,
+                    byte if byte.is_ascii_whitespace() => {}
+                    _ => return Err(self.error("expected the start of a JSON array")),
+                },
+                State::AfterElement => match byte {
+                    b']' => self.state = State::End,
+                    b',' => self.state = State::Next,
+                    byte if byte.is_ascii_whitespace() => {}
+                    _ => return Err(self.error("expected ',' or ']' after an array element")),
+                },
+                State::First | State::Next => match byte {
+                    b']' if self.state == State::First => self.state = State::End,
+                    b',' | b']' => return Err(self.error("expected an array element")),
+                    byte if byte.is_ascii_whitespace() => {}
+                    byte => {
+                        self.state = State::Element;
+                        self.decode_element_byte(byte)?;
+                    }
+                },
+                State::Element => self.decode_element_byte(byte)?,
+                State::End => {
+                    if !byte.is_ascii_whitespace() {
+                        return Err(self.error("trailing characters after the JSON array"));
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Complete decoding, returning every element of the array.
+    pub(crate) fn finish(self) -> Result<Vec<T>, ClientError> {
+        match self.state {
+            State::End => Ok(self.items),
+            _ => Err(self.error("unexpected end of the JSON array")),
+        }
+    }
+
+    fn decode_element_byte(&mut self, byte: u8) -> Result<(), ClientError> {
+        if self.in_string {
+            self.element.push(byte);
+            if self.escaped {
+                self.escaped = false;
+            } else if byte == b'\\' {
+                self.escaped = true;
+            } else if byte == b'"' {
+                self.in_string = false;
+                if self.depth == 0 {
+                    self.complete_element(State::AfterElement)?;
+                }
+            }
+            return Ok(());
+        }
+
+        match byte {
+            b'"' => {
+                self.element.push(byte);
+                self.in_string = true;
+            }
+            b'{' | b'[' => {
+                self.element.push(byte);
+                self.depth += 1;
+            }
+            // A closing bracket at depth zero ends the array itself, after an
+            // element which is a number or literal. A closing brace at depth
+            // zero has no matching opening brace.
+            b']' if self.depth == 0 => self.complete_element(State::End)?,
+            b'}' if self.depth == 0 => return Err(self.error("unexpected '}' in the JSON array")),
+            b'}' | b']' => {
+                self.element.push(byte);
+                self.depth -= 1;
+                if self.depth == 0 {
+                    self.complete_element(State::AfterElement)?;
+                }
+            }
+            b',' if self.depth == 0 => self.complete_element(State::Next)?,
+            byte if byte.is_ascii_whitespace() && self.depth == 0 => {
+                self.complete_element(State::AfterElement)?
+            }
+            byte => self.element.push(byte),
+        }
+        Ok(())
+    }
+
+    /// Decode the buffered element and move to the given state.
+    fn complete_element(&mut self, next: State) -> Result<(), ClientError> {
+        let item =
+            serde_json::from_slice(&self.element).map_err(|err| self.error(&err.to_string()))?;
+        self.items.push(item);
+        self.element.clear();
+        self.state = next;
+        Ok(())
+    }
+
+    fn error(&self, message: &str) -> ClientError {
+        let snippet = &self.element[..self.element.len().min(DESERIALIZATION_ERROR_SNIPPET_LEN)];
+        ClientError::DeserializationError {
+            status: self.status.as_u16(),
+            message: message.to_string(),
+            body_snippet: String::from_utf8_lossy(snippet).into_owned(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde::Deserialize;
+
+    #[derive(Debug, Deserialize, PartialEq)]
+    #[serde(rename_all = "PascalCase")]
+    struct Item {
+        #[serde(rename = "ID")]
+        id: u64,
+        name: String,
+        tags: Vec<String>,
+    }
+
+    /// Decode the body split into chunks of `chunk_size` bytes.
+    fn decode_chunked<T: DeserializeOwned>(
+        body: &[u8],
+        chunk_size: usize,
+    ) -> Result<Vec<T>, ClientError> {
+        let mut decoder = ArrayDecoder::new(StatusCode::OK);
+        for chunk in body.chunks(chunk_size) {
+            decoder.decode(chunk)?;
+        }
+        decoder.finish()
+    }
+
+    #[test]
+    fn decodes_large_array_across_chunk_boundaries() {
+        // The strings contain the structural characters of the array, and
+        // escaped quotes, which must not end an element early.
+        let items: Vec<serde_json::Value> = (0..10_000)
+            .map(|id| {
+                serde_json::json!({
+                    "ID": id,
+                    "Name": format!("alloc \"{}\" [x], {{y}}", id),
+                    "Tags": [format!("tag-{}", id), "a,b]\\"],
+                })
+            })
+            .collect();
+        let body = serde_json::to_vec_pretty(&items).unwrap();
+
+        for chunk_size in [1, 7, 64, 4096, body.len()] {
+            let decoded = decode_chunked::<Item>(&body, chunk_size).unwrap();
+            assert_eq!(decoded.len(), 10_000);
+            for (idx, item) in decoded.iter().enumerate() {
+                assert_eq!(item.id, idx as u64);
+                assert_eq!(item.name, format!("alloc \"{}\" [x], {{y}}", idx));
+                assert_eq!(
+                    item.tags,
+                    vec![format!("tag-{}", idx), "a,b]\\".to_string()]
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn decodes_scalar_elements() {
+        let body = br#" [1, -2.5,true , null,"a\"b", [3], {"k": "]"}] "#;
+        let decoded = decode_chunked::<serde_json::Value>(body, 3).unwrap();
+        assert_eq!(
+            decoded,
+            vec![
+                serde_json::json!(1),
+                serde_json::json!(-2.5),
+                serde_json::json!(true),
+                serde_json::json!(null),
+                serde_json::json!("a\"b"),
+                serde_json::json!([3]),
+                serde_json::json!({"k": "]"}),
+            ]
+        );
+    }
+
+    #[test]
+    fn decodes_empty_array() {
+        let decoded = decode_chunked::<Item>(b" [ ]\n", 1).unwrap();
+        assert!(decoded.is_empty());
+    }
+
+    #[test]
+    fn truncated_trailing_element_is_an_error() {
+        let body = br#"[{"ID": 1, "Name": "a", "Tags": []}, {"ID": 2, "Name": "b""#;
+        let err = decode_chunked::<Item>(body, 5).unwrap_err();
+        match err {
+            ClientError::DeserializationError {
+                status,
+                message,
+                body_snippet,
+            } => {
+                assert_eq!(status, 200);
+                assert_eq!(message, "unexpected end of the JSON array");
+                assert_eq!(body_snippet, r#"{"ID": 2, "Name": "b""#);
+            }
+            err => panic!("unexpected error: {err:?}"),
+        }
+    }
+
+    #[test]
+    fn invalid_element_is_an_error() {
+        let body = br#"[{"ID": 1, "Name": "a", "Tags": []}, {"ID": "two"}]"#;
+        let err = decode_chunked::<Item>(body, 16).unwrap_err();
+        assert!(
+            matches!(err, ClientError::DeserializationError { ref body_snippet, .. } if body_snippet == r#"{"ID": "two"}"#),
+            "unexpected error: {err:?}"
+        );
+    }
+
+    #[test]
+    fn non_array_body_is_an_error() {
+        let err = decode_chunked::<Item>(br#"{"ID": 1}"#, 4).unwrap_err();
+        assert!(matches!(err, ClientError::DeserializationError { .. }));
+
+        let err = decode_chunked::<Item>(b"[] []", 4).unwrap_err();
+        assert!(matches!(err, ClientError::DeserializationError { .. }));
+    }
+
+    #[test]
+    fn malformed_array_is_an_error() {
+        for body in [
+            "[1,,2]",
+            "[,1]",
+            "[1,]",
+            "[,]",
+            "[1 2]",
+            "[1}",
+            "[{}}",
+            r#"[{} {}]"#,
+            r#"["a""b"]"#,
+        ] {
+            for chunk_size in [1, body.len()] {
+                let result = decode_chunked::<serde_json::Value>(body.as_bytes(), chunk_size);
+                assert!(
+                    matches!(result, Err(ClientError::DeserializationError { .. })),
+                    "{body} decoded as {result:?}"
+                );
+            }
+        }
+    }
+}
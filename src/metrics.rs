@@ -0,0 +1,101 @@
+use crate::option::QueryOptions;
+use crate::{ClientError, Nomad};
+use reqwest::Method;
+use serde::Deserialize;
+use std::collections::HashMap;
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct MetricsSummary {
+    pub timestamp: String,
+    pub gauges: Vec<GaugeValue>,
+    pub points: Vec<PointValue>,
+    pub counters: Vec<SampledValue>,
+    pub samples: Vec<SampledValue>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct GaugeValue {
+    pub name: String,
+    pub value: f64,
+    #[serde(default)]
+    pub labels: HashMap<String, String>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct PointValue {
+    pub name: String,
+    pub points: Vec<f64>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct SampledValue {
+    pub name: String,
+    pub count: u64,
+    pub rate: f64,
+    pub sum: f64,
+    pub min: f64,
+    pub max: f64,
+    pub mean: f64,
+    pub stddev: f64,
+    #[serde(default)]
+    pub labels: HashMap<String, String>,
+}
+
+pub struct Endpoint<'a> {
+    client: &'a Nomad,
+}
+
+impl<'a> Endpoint<'a> {
+    /// Create a new `Endpoint` with the given `Nomad` client to interact with
+    /// the metrics endpoint.
+    pub fn new(client: &'a Nomad) -> Self {
+        Self { client }
+    }
+
+    /// Get the metrics of the Nomad agent in the Prometheus text exposition
+    /// format. This requires `prometheus_metrics` to be enabled within the
+    /// agent's telemetry configuration.
+    ///
+    /// # Arguments
+    /// * `opts` - Optional query options for the request.
+    ///
+    /// # Returns
+    /// A `Result` containing the exposition text or an error if the request
+    /// fails.
+    pub async fn prometheus(&self, opts: Option<QueryOptions>) -> Result<String, ClientError> {
+        let req = self
+            .client
+            .set_request_query_options(
+                self.client.build_request(Method::GET, "/v1/metrics"),
+                &opts.unwrap_or_default(),
+            )
+            .query(&[("format", "prometheus")]);
+
+        self.client
+            .send_raw(req)
+            .await?
+            .text()
+            .await
+            .map_err(crate::network_error)
+    }
+
+    /// Get the most recent interval of metrics collected by the Nomad agent.
+    ///
+    /// # Arguments
+    /// * `opts` - Optional query options for the request.
+    ///
+    /// # Returns
+    /// A `Result` containing the `MetricsSummary` or an error if the request
+    /// fails.
+    pub async fn summary(&self, opts: Option<QueryOptions>) -> Result<MetricsSummary, ClientError> {
+        let req = self.client.set_request_query_options(
+            self.client.build_request(Method::GET, "/v1/metrics"),
+            &opts.unwrap_or_default(),
+        );
+        self.client.send_with_response::<MetricsSummary>(req).await
+    }
+}
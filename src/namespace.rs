@@ -2,6 +2,11 @@ use crate::option::{QueryOptions, WriteOptions};
 use crate::{ClientError, Nomad};
 use reqwest::Method;
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// The wildcard namespace, which Nomad accepts on list endpoints to return
+/// objects across every namespace the token has access to.
+pub const NAMESPACE_WILDCARD: &str = "*";
 
 #[derive(Debug, Serialize, Deserialize)]
 #[serde(rename_all = "PascalCase")]
@@ -13,7 +18,7 @@ pub struct Namespace {
     pub node_pool_configuration: Option<NamespaceNodePoolConfiguration>,
     pub vault_configuration: Option<NamespaceVaultConfiguration>,
     pub consul_configuration: Option<NamespaceConsulConfiguration>,
-    pub meta: Option<std::collections::HashMap<String, String>>,
+    pub meta: Option<HashMap<String, String>>,
     pub create_index: Option<u64>,
     pub modify_index: Option<u64>,
 }
@@ -68,6 +73,58 @@ pub struct NamespaceConsulConfiguration {
     pub denied: Option<Vec<String>>,
 }
 
+/// Namespaced is implemented by the objects returned from list endpoints which
+/// belong to a namespace, allowing results listed using `NAMESPACE_WILDCARD`
+/// to be grouped with `group_by_namespace`.
+pub trait Namespaced {
+    /// The namespace the object belongs to.
+    fn namespace(&self) -> &str;
+}
+
+macro_rules! impl_namespaced {
+    ($($ty:ty),* $(,)?) => {
+        $(
+            impl Namespaced for $ty {
+                fn namespace(&self) -> &str {
+                    &self.namespace
+                }
+            }
+        )*
+    };
+}
+
+impl_namespaced!(
+    crate::allocation::Allocation,
+    crate::allocation::AllocationStub,
+    crate::csi::CSIVolumeListStub,
+    crate::deployment::Deployment,
+    crate::evaluation::Evaluation,
+    crate::evaluation::EvaluationStub,
+    crate::job::JobStub,
+    crate::service::ServiceRegistration,
+    crate::variable::VariableMetadata,
+);
+
+/// Group a list of objects, typically spanning several namespaces, by the
+/// namespace they belong to. The order of the objects within each namespace is
+/// preserved.
+///
+/// # Arguments
+/// * `items` - The objects to group.
+///
+/// # Returns
+/// A map of namespace name to the objects within that namespace.
+pub fn group_by_namespace<T: Namespaced>(items: Vec<T>) -> HashMap<String, Vec<T>> {
+    let mut grouped: HashMap<String, Vec<T>> = HashMap::new();
+    for item in items {
+        grouped
+            .entry(item.namespace().to_string())
+            .or_default()
+            .push(item);
+    }
+    grouped
+}
+
 pub struct Endpoint<'a> {
     client: &'a Nomad,
 }
@@ -82,10 +139,14 @@ impl<'a> Endpoint<'a> {
     /// Create a new namespace in the Nomad cluster. This can also be used to
     /// update an existing namespace by providing the same name.
     ///
+    /// Nomad does not support check-and-set for namespace writes, so an update
+    /// replaces the namespace regardless of its current modify index.
+    ///
     /// # Arguments
     /// * `namespace` - The `Namespace` object containing the details of the
     ///   namespace to create.
-    /// * `opts` - Optional write options to use for the request.
+    /// * `opts` - Optional write options to use for the request, including
+    ///   any idempotency token.
     ///
     /// # Returns
     /// A `Result` containing the created namespace or an error if the request
@@ -105,6 +166,31 @@ impl<'a> Endpoint<'a> {
         self.client.send_without_response(req).await
     }
 
+    /// Create or update a namespace, then read it back so the stored namespace,
+    /// including its create and modify indexes, is returned. Nomad does not
+    /// include the namespace in the response to the write, so this makes two
+    /// requests.
+    ///
+    /// # Arguments
+    /// * `namespace` - The `Namespace` object containing the details of the
+    ///   namespace to create.
+    /// * `opts` - Optional write options to use for both requests.
+    ///
+    /// # Returns
+    /// A `Result` containing the stored namespace or an error if either
+    /// request fails.
+    pub async fn create_and_fetch(
+        &self,
+        namespace: &Namespace,
+        opts: Option<WriteOptions>,
+    ) -> Result<Namespace, ClientError> {
+        let opts = opts.unwrap_or_default();
+        let query_opts = QueryOptions::from(&opts);
+
+        self.create(namespace, Some(opts)).await?;
+        self.get(&namespace.name, Some(query_opts)).await
+    }
+
     /// Delete a namespace from the Nomad cluster.
     ///
     /// # Arguments
@@ -144,6 +230,24 @@ impl<'a> Endpoint<'a> {
         self.client.send_with_response::<Namespace>(req).await
     }
 
+    /// Get details of a specific namespace by name, returning `None` rather
+    /// than an error when it does not exist.
+    ///
+    /// # Arguments
+    /// * `name` - The name of the namespace to retrieve.
+    /// * `opts` - Optional query options for the request.
+    ///
+    /// # Returns
+    /// A `Result` containing the namespace, `None` if it does not exist, or an
+    /// error if the request fails.
+    pub async fn get_optional(
+        &self,
+        name: &str,
+        opts: Option<QueryOptions>,
+    ) -> Result<Option<Namespace>, ClientError> {
+        crate::not_found_to_none(self.get(name, opts).await)
+    }
+
     /// Get the list of namespaces in the Nomad cluster.
     ///
     /// # Arguments
@@ -0,0 +1,436 @@
+use crate::allocation::AllocationStub;
+use crate::filter::Filter;
+use crate::job::TaskResources;
+use crate::option::{QueryOptions, SortOrder, WriteOptions};
+use crate::{ClientError, Nomad};
+use reqwest::Method;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+pub const NODE_STATUS_INIT: &str = "initializing";
+pub const NODE_STATUS_READY: &str = "ready";
+pub const NODE_STATUS_DOWN: &str = "down";
+pub const NODE_STATUS_DISCONNECTED: &str = "disconnected";
+
+pub const NODE_SCHEDULING_ELIGIBLE: &str = "eligible";
+pub const NODE_SCHEDULING_INELIGIBLE: &str = "ineligible";
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct Node {
+    #[serde(rename = "ID")]
+    pub id: String,
+    pub name: String,
+    pub datacenter: String,
+    pub node_pool: Option<String>,
+    pub node_class: Option<String>,
+    #[serde(rename = "HTTPAddr")]
+    pub http_addr: String,
+    #[serde(rename = "TLSEnabled")]
+    pub tls_enabled: bool,
+    pub attributes: Option<HashMap<String, String>>,
+    pub meta: Option<HashMap<String, String>>,
+    pub resources: Option<TaskResources>,
+    pub reserved: Option<TaskResources>,
+    pub status: String,
+    pub status_description: Option<String>,
+    pub status_updated_at: Option<i64>,
+    pub scheduling_eligibility: String,
+    pub drain: bool,
+    pub create_index: u64,
+    pub modify_index: u64,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct NodeStub {
+    #[serde(rename = "ID")]
+    pub id: String,
+    pub name: String,
+    pub address: String,
+    pub datacenter: String,
+    pub node_pool: Option<String>,
+    pub node_class: Option<String>,
+    pub version: String,
+    pub attributes: Option<HashMap<String, String>>,
+    pub status: String,
+    pub status_description: Option<String>,
+    pub scheduling_eligibility: String,
+    pub drain: bool,
+    pub create_index: u64,
+    pub modify_index: u64,
+}
+
+/// DrainSpec describes how a node should be drained.
+#[derive(Debug, Clone)]
+pub struct DrainSpec {
+    /// The duration, in nanoseconds, after which remaining allocations are
+    /// force stopped. A value of `-1` forces an immediate drain, while `0`
+    /// allows the drain to run without a deadline.
+    pub deadline: i64,
+
+    /// Whether system job allocations should be left running on the node.
+    pub ignore_system_jobs: bool,
+}
+
+impl DrainSpec {
+    pub fn new(deadline: i64, ignore_system_jobs: bool) -> Self {
+        DrainSpec {
+            deadline,
+            ignore_system_jobs,
+        }
+    }
+
+    /// Create a drain specification whose deadline is the given duration,
+    /// converted to nanoseconds. Deadlines too large to represent are capped
+    /// at the largest deadline Nomad accepts.
+    pub fn with_deadline(deadline: time::Duration, ignore_system_jobs: bool) -> Self {
+        Self::new(
+            crate::duration_nanos::to_nanos(&deadline).unwrap_or(i64::MAX),
+            ignore_system_jobs,
+        )
+    }
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "PascalCase")]
+struct NodeDrainSpecBody {
+    deadline: i64,
+    ignore_system_jobs: bool,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "PascalCase")]
+struct NodeUpdateDrainRequest<'a> {
+    #[serde(rename = "NodeID")]
+    node_id: &'a str,
+    drain_spec: Option<NodeDrainSpecBody>,
+    mark_eligible: bool,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "PascalCase")]
+struct NodeUpdateEligibilityRequest<'a> {
+    #[serde(rename = "NodeID")]
+    node_id: &'a str,
+    eligibility: &'a str,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct NodeDrainUpdateResponse {
+    pub node_modify_index: u64,
+    #[serde(rename = "EvalIDs")]
+    pub eval_ids: Option<Vec<String>>,
+    pub eval_create_index: u64,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct NodeEligibilityUpdateResponse {
+    pub node_modify_index: u64,
+    #[serde(rename = "EvalIDs")]
+    pub eval_ids: Option<Vec<String>>,
+    pub eval_create_index: u64,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct NodePurgeResponse {
+    pub node_modify_index: u64,
+    #[serde(rename = "EvalIDs")]
+    pub eval_ids: Option<Vec<String>>,
+    pub eval_create_index: u64,
+}
+
+pub struct Endpoint<'a> {
+    client: &'a Nomad,
+}
+
+impl<'a> Endpoint<'a> {
+    /// Create a new `Endpoint` with the given `Nomad` client to interact with
+    /// the node endpoints.
+    pub fn new(client: &'a Nomad) -> Self {
+        Self { client }
+    }
+
+    /// Get a specific client node by its ID.
+    ///
+    /// # Arguments
+    /// * `node_id` - The ID of the node to retrieve.
+    /// * `opts` - Optional query options for the request.
+    ///
+    /// # Returns
+    /// A `Result` containing the `Node` object or an error if the request
+    /// fails.
+    pub async fn get(
+        &self,
+        node_id: &str,
+        opts: Option<QueryOptions>,
+    ) -> Result<Node, ClientError> {
+        let req = self.client.set_request_query_options(
+            self.client
+                .build_request(Method::GET, &format!("/v1/node/{}", node_id)),
+            &opts.unwrap_or_default(),
+        );
+        self.client.send_with_response::<Node>(req).await
+    }
+
+    /// Get a specific client node by its ID, returning `None` rather than an
+    /// error when it does not exist.
+    ///
+    /// # Arguments
+    /// * `node_id` - The ID of the node to retrieve.
+    /// * `opts` - Optional query options for the request.
+    ///
+    /// # Returns
+    /// A `Result` containing the `Node`, `None` if it does not exist, or an
+    /// error if the request fails.
+    pub async fn get_optional(
+        &self,
+        node_id: &str,
+        opts: Option<QueryOptions>,
+    ) -> Result<Option<Node>, ClientError> {
+        crate::not_found_to_none(self.get(node_id, opts).await)
+    }
+
+    /// Get the list of client nodes registered in the Nomad cluster.
+    ///
+    /// # Arguments
+    /// * `opts` - Optional query options for the request.
+    ///
+    /// # Returns
+    /// A `Result` containing a vector of `NodeStub` objects or an error if the
+    /// request fails.
+    pub async fn list(&self, opts: Option<QueryOptions>) -> Result<Vec<NodeStub>, ClientError> {
+        let req = self.client.set_request_query_options(
+            self.client.build_request(Method::GET, "/v1/nodes"),
+            &opts.unwrap_or_default(),
+        );
+        self.client.send_with_response::<Vec<NodeStub>>(req).await
+    }
+
+    /// List all allocations placed on a specific client node.
+    ///
+    /// # Arguments
+    /// * `node_id` - The ID of the node.
+    /// * `opts` - Optional query options for the request.
+    ///
+    /// # Returns
+    /// A `Result` containing a vector of `AllocationStub` objects or an error
+    /// if the request fails.
+    pub async fn list_allocations(
+        &self,
+        node_id: &str,
+        opts: Option<QueryOptions>,
+    ) -> Result<Vec<AllocationStub>, ClientError> {
+        let opts = opts.unwrap_or_default();
+        let sort_order = opts.resolve_sort_order(SortOrder::Descending);
+        let req = self.client.set_request_query_options(
+            self.client
+                .build_request(Method::GET, &format!("/v1/node/{}/allocations", node_id)),
+            &opts,
+        );
+        let mut allocations = self
+            .client
+            .send_with_response::<Vec<AllocationStub>>(req)
+            .await?;
+
+        // Sort by CreateIndex, highest first unless another order is requested
+        sort_order.sort_by(&mut allocations, |a, b| a.create_index.cmp(&b.create_index));
+
+        Ok(allocations)
+    }
+
+    /// Get the list of client nodes which are currently draining.
+    ///
+    /// The nodes are selected server-side using the filter expression
+    /// `Drain == true`. Any filter set within `opts` is combined with it using
+    /// `and`.
+    ///
+    /// # Arguments
+    /// * `opts` - Optional query options for the request.
+    ///
+    /// # Returns
+    /// A `Result` containing a vector of `NodeStub` objects or an error if the
+    /// request fails.
+    pub async fn list_draining(
+        &self,
+        opts: Option<QueryOptions>,
+    ) -> Result<Vec<NodeStub>, ClientError> {
+        let opts = opts
+            .unwrap_or_default()
+            .and_filter_expr(Filter::eq("Drain", true));
+        self.list(Some(opts)).await
+    }
+
+    /// Get the list of client nodes which are ineligible for scheduling.
+    ///
+    /// The nodes are selected server-side using the filter expression
+    /// `SchedulingEligibility == "ineligible"`. Any filter set within `opts`
+    /// is combined with it using `and`.
+    ///
+    /// # Arguments
+    /// * `opts` - Optional query options for the request.
+    ///
+    /// # Returns
+    /// A `Result` containing a vector of `NodeStub` objects or an error if the
+    /// request fails.
+    pub async fn list_ineligible(
+        &self,
+        opts: Option<QueryOptions>,
+    ) -> Result<Vec<NodeStub>, ClientError> {
+        let opts = opts.unwrap_or_default().and_filter_expr(Filter::eq(
+            "SchedulingEligibility",
+            NODE_SCHEDULING_INELIGIBLE,
+        ));
+        self.list(Some(opts)).await
+    }
+
+    /// Garbage collect every terminal allocation on a client node, removing
+    /// their local data to reclaim disk space. This is a client API, answered
+    /// by the agent of the node. Requests sent to a server are forwarded to
+    /// that node, so the node must be reachable from the servers.
+    ///
+    /// # Arguments
+    /// * `node_id` - The ID of the node to garbage collect.
+    /// * `opts` - Optional query options for the request.
+    ///
+    /// # Returns
+    /// A `Result` indicating success or failure of the operation.
+    pub async fn node_gc(
+        &self,
+        node_id: &str,
+        opts: Option<QueryOptions>,
+    ) -> Result<(), ClientError> {
+        if node_id.is_empty() {
+            return Err(ClientError::InvalidInputError(
+                "Node ID must be set".to_string(),
+            ));
+        }
+
+        let req = self
+            .client
+            .set_request_query_options(
+                self.client.build_request(Method::GET, "/v1/client/gc"),
+                &opts.unwrap_or_default(),
+            )
+            .query(&[("node_id", node_id)]);
+        self.client.send_without_response(req).await
+    }
+
+    /// Purge a client node from the Nomad cluster.
+    ///
+    /// This is destructive: the node and its allocations are removed from the
+    /// Raft state of the servers, and the allocations are rescheduled. It
+    /// should only be used for nodes which are permanently gone. A node which
+    /// is still running will re-register itself.
+    ///
+    /// # Arguments
+    /// * `node_id` - The ID of the node to purge.
+    /// * `opts` - Optional write options for the request.
+    ///
+    /// # Returns
+    /// A `Result` containing the `NodePurgeResponse` or an error if the
+    /// request fails.
+    pub async fn purge(
+        &self,
+        node_id: &str,
+        opts: Option<WriteOptions>,
+    ) -> Result<NodePurgeResponse, ClientError> {
+        if node_id.is_empty() {
+            return Err(ClientError::InvalidInputError(
+                "Node ID must be set".to_string(),
+            ));
+        }
+
+        let req = self.client.set_request_write_options(
+            self.client
+                .build_request(Method::POST, &format!("/v1/node/{}/purge", node_id)),
+            &opts.unwrap_or_default(),
+        );
+        self.client
+            .send_with_response::<NodePurgeResponse>(req)
+            .await
+    }
+
+    /// Start or cancel the drain of a client node.
+    ///
+    /// # Arguments
+    /// * `node_id` - The ID of the node to drain.
+    /// * `spec` - The drain specification to apply to the node, or `None` to
+    ///   cancel an ongoing drain.
+    /// * `mark_eligible` - Whether the node should be marked eligible for
+    ///   scheduling when the drain is cancelled. Nomad ignores this when a
+    ///   drain is started.
+    /// * `opts` - Optional write options for the request.
+    ///
+    /// # Returns
+    /// A `Result` containing the `NodeDrainUpdateResponse` or an error if the
+    /// request fails.
+    pub async fn update_drain(
+        &self,
+        node_id: &str,
+        spec: Option<DrainSpec>,
+        mark_eligible: bool,
+        opts: Option<WriteOptions>,
+    ) -> Result<NodeDrainUpdateResponse, ClientError> {
+        let body = NodeUpdateDrainRequest {
+            node_id,
+            drain_spec: spec.map(|spec| NodeDrainSpecBody {
+                deadline: spec.deadline,
+                ignore_system_jobs: spec.ignore_system_jobs,
+            }),
+            mark_eligible,
+        };
+
+        let req = self
+            .client
+            .set_request_write_options(
+                self.client
+                    .build_request(Method::POST, &format!("/v1/node/{}/drain", node_id)),
+                &opts.unwrap_or_default(),
+            )
+            .json(&body);
+        self.client
+            .send_with_response::<NodeDrainUpdateResponse>(req)
+            .await
+    }
+
+    /// Toggle the scheduling eligibility of a client node.
+    ///
+    /// # Arguments
+    /// * `node_id` - The ID of the node to update.
+    /// * `eligible` - Whether the node should be eligible for scheduling.
+    /// * `opts` - Optional write options for the request.
+    ///
+    /// # Returns
+    /// A `Result` containing the `NodeEligibilityUpdateResponse` or an error if
+    /// the request fails.
+    pub async fn update_eligibility(
+        &self,
+        node_id: &str,
+        eligible: bool,
+        opts: Option<WriteOptions>,
+    ) -> Result<NodeEligibilityUpdateResponse, ClientError> {
+        let eligibility = match eligible {
+            true => NODE_SCHEDULING_ELIGIBLE,
+            false => NODE_SCHEDULING_INELIGIBLE,
+        };
+
+        let req = self
+            .client
+            .set_request_write_options(
+                self.client
+                    .build_request(Method::POST, &format!("/v1/node/{}/eligibility", node_id)),
+                &opts.unwrap_or_default(),
+            )
+            .json(&NodeUpdateEligibilityRequest {
+                node_id,
+                eligibility,
+            });
+        self.client
+            .send_with_response::<NodeEligibilityUpdateResponse>(req)
+            .await
+    }
+}
@@ -1,7 +1,9 @@
 use reqwest::Method;
 use serde::{Deserialize, Serialize};
 
-use crate::option::{QueryOptions, WriteOptions};
+use crate::job::JobStub;
+use crate::node::NodeStub;
+use crate::option::{QueryMeta, QueryOptions, WriteOptions};
 use crate::{ClientError, Nomad};
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -58,7 +60,35 @@ impl<'a> Endpoint<'a> {
                 &opts.unwrap_or_default(),
             )
             .json(node_pool);
-        self.client.send_without_response(req).await
+        self.client
+            .send_without_response(req)
+            .await
+            .map_err(node_pool_error)
+    }
+
+    /// Create or update a node pool, then read it back so the stored node
+    /// pool, including its create and modify indexes, is returned. Nomad does
+    /// not include the node pool in the response to the write, so this makes
+    /// two requests.
+    ///
+    /// # Arguments
+    /// * `node_pool` - The `NodePool` object containing the details of the
+    ///   node pool to create.
+    /// * `opts` - Optional write options to use for both requests.
+    ///
+    /// # Returns
+    /// A `Result` containing the stored node pool or an error if either
+    /// request fails.
+    pub async fn create_and_fetch(
+        &self,
+        node_pool: &NodePool,
+        opts: Option<WriteOptions>,
+    ) -> Result<NodePool, ClientError> {
+        let opts = opts.unwrap_or_default();
+        let query_opts = QueryOptions::from(&opts);
+
+        self.create(node_pool, Some(opts)).await?;
+        self.get(&node_pool.name, Some(query_opts)).await
     }
 
     pub async fn delete(&self, name: &str, opts: Option<WriteOptions>) -> Result<(), ClientError> {
@@ -67,7 +97,10 @@ impl<'a> Endpoint<'a> {
                 .build_request(Method::DELETE, &format!("/v1/node/pool/{}", name)),
             &opts.unwrap_or_default(),
         );
-        self.client.send_without_response(req).await
+        self.client
+            .send_without_response(req)
+            .await
+            .map_err(node_pool_error)
     }
 
     pub async fn get(
@@ -80,7 +113,10 @@ impl<'a> Endpoint<'a> {
                 .build_request(Method::GET, &format!("/v1/node/pool/{}", name)),
             &opts.unwrap_or_default(),
         );
-        self.client.send_with_response::<NodePool>(req).await
+        self.client
+            .send_with_response::<NodePool>(req)
+            .await
+            .map_err(node_pool_error)
     }
 
     pub async fn list(&self, opts: Option<QueryOptions>) -> Result<Vec<NodePool>, ClientError> {
@@ -88,6 +124,141 @@ impl<'a> Endpoint<'a> {
             self.client.build_request(Method::GET, "/v1/node/pools"),
             &opts.unwrap_or_default(),
         );
-        self.client.send_with_response::<Vec<NodePool>>(req).await
+        self.client
+            .send_with_response::<Vec<NodePool>>(req)
+            .await
+            .map_err(node_pool_error)
+    }
+
+    /// Get the list of jobs which run within a node pool.
+    ///
+    /// # Arguments
+    /// * `name` - The name of the node pool.
+    /// * `opts` - Optional query options to filter and paginate the results.
+    ///
+    /// # Returns
+    /// A `Result` containing a vector of `JobStub` objects or an error if the
+    /// request fails.
+    pub async fn list_jobs(
+        &self,
+        name: &str,
+        opts: Option<QueryOptions>,
+    ) -> Result<Vec<JobStub>, ClientError> {
+        self.list_jobs_with_meta(name, opts)
+            .await
+            .map(|(jobs, _)| jobs)
+    }
+
+    /// Get the list of jobs which run within a node pool along with the query
+    /// metadata of the response. The `next_token` of the metadata can be used
+    /// to request the following page.
+    ///
+    /// # Arguments
+    /// * `name` - The name of the node pool.
+    /// * `opts` - Optional query options to filter and paginate the results.
+    ///
+    /// # Returns
+    /// A `Result` containing a vector of `JobStub` objects and the `QueryMeta`
+    /// of the response, or an error if the request fails.
+    pub async fn list_jobs_with_meta(
+        &self,
+        name: &str,
+        opts: Option<QueryOptions>,
+    ) -> Result<(Vec<JobStub>, QueryMeta), ClientError> {
+        self.list_members(name, "jobs", opts).await
+    }
+
+    /// Get the list of client nodes within a node pool.
+    ///
+    /// # Arguments
+    /// * `name` - The name of the node pool.
+    /// * `opts` - Optional query options to filter and paginate the results.
+    ///
+    /// # Returns
+    /// A `Result` containing a vector of `NodeStub` objects or an error if the
+    /// request fails.
+    pub async fn list_nodes(
+        &self,
+        name: &str,
+        opts: Option<QueryOptions>,
+    ) -> Result<Vec<NodeStub>, ClientError> {
+        self.list_nodes_with_meta(name, opts)
+            .await
+            .map(|(nodes, _)| nodes)
+    }
+
+    /// Get the list of client nodes within a node pool along with the query
+    /// metadata of the response. The `next_token` of the metadata can be used
+    /// to request the following page.
+    ///
+    /// # Arguments
+    /// * `name` - The name of the node pool.
+    /// * `opts` - Optional query options to filter and paginate the results.
+    ///
+    /// # Returns
+    /// A `Result` containing a vector of `NodeStub` objects and the
+    /// `QueryMeta` of the response, or an error if the request fails.
+    pub async fn list_nodes_with_meta(
+        &self,
+        name: &str,
+        opts: Option<QueryOptions>,
+    ) -> Result<(Vec<NodeStub>, QueryMeta), ClientError> {
+        self.list_members(name, "nodes", opts).await
+    }
+
+    /// List the jobs or nodes of a node pool, as selected by `members`.
+    async fn list_members<T: serde::de::DeserializeOwned>(
+        &self,
+        name: &str,
+        members: &str,
+        opts: Option<QueryOptions>,
+    ) -> Result<(Vec<T>, QueryMeta), ClientError> {
+        if name.is_empty() {
+            return Err(ClientError::InvalidInputError(
+                "Node pool name must be set".to_string(),
+            ));
+        }
+
+        let req = self.client.set_request_query_options(
+            self.client
+                .build_request(Method::GET, &format!("/v1/node/pool/{}/{}", name, members)),
+            &opts.unwrap_or_default(),
+        );
+        self.client
+            .send_with_response_meta::<Vec<T>>(req)
+            .await
+            .map_err(node_pool_error)
+    }
+}
+
+/// The message used when the cluster does not support node pools.
+const NODE_POOLS_UNSUPPORTED: &str = "Node pools require Nomad 1.6 or later";
+
+/// Clusters running Nomad older than 1.6 do not have the node pool endpoints.
+/// The requests are instead routed to the node endpoints, which respond that
+/// the node was not found or that the method is not allowed, so these errors
+/// are rewritten to explain the real cause. A node pool which does not exist
+/// is reported by Nomad as "node pool not found", and is left unchanged.
+fn node_pool_error(err: ClientError) -> ClientError {
+    match err {
+        ClientError::ServerError {
+            status,
+            message,
+            body,
+        } if (status == 404 && !body.to_lowercase().contains("node pool")) || status == 405 => {
+            ClientError::ServerError {
+                status,
+                message: Some(format!(
+                    "{}: {}",
+                    NODE_POOLS_UNSUPPORTED,
+                    message.as_deref().unwrap_or(&body)
+                )),
+                body,
+            }
+        }
+        ClientError::EnterpriseOnly(message) => {
+            ClientError::EnterpriseOnly(format!("{}: {}", NODE_POOLS_UNSUPPORTED, message))
+        }
+        err => err,
     }
 }
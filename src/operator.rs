@@ -0,0 +1,575 @@
+use crate::option::{QueryOptions, WriteOptions};
+use crate::{ClientError, Nomad};
+use bytes::Bytes;
+use reqwest::{Method, StatusCode};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+pub const SCHEDULER_ALGORITHM_BINPACK: &str = "binpack";
+pub const SCHEDULER_ALGORITHM_SPREAD: &str = "spread";
+
+pub const ROOT_KEY_STATE_ACTIVE: &str = "active";
+pub const ROOT_KEY_STATE_INACTIVE: &str = "inactive";
+pub const ROOT_KEY_STATE_REKEYING: &str = "rekeying";
+pub const ROOT_KEY_STATE_DEPRECATED: &str = "deprecated";
+pub const ROOT_KEY_STATE_PREPUBLISHED: &str = "prepublished";
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct RaftConfiguration {
+    pub servers: Vec<RaftServer>,
+    pub index: u64,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct RaftServer {
+    #[serde(rename = "ID")]
+    pub id: String,
+    pub node: String,
+    pub address: String,
+    pub leader: bool,
+    pub voter: bool,
+    #[serde(rename = "RaftProtocol")]
+    pub raft_version: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct SchedulerConfiguration {
+    pub scheduler_algorithm: Option<String>,
+    pub preemption_config: Option<PreemptionConfig>,
+    pub memory_oversubscription_enabled: Option<bool>,
+    pub reject_job_registration: Option<bool>,
+    pub pause_eval_broker: Option<bool>,
+    pub create_index: Option<u64>,
+    pub modify_index: Option<u64>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct PreemptionConfig {
+    pub system_scheduler_enabled: bool,
+    pub sys_batch_scheduler_enabled: bool,
+    pub batch_scheduler_enabled: bool,
+    pub service_scheduler_enabled: bool,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct SchedulerConfigurationResponse {
+    pub scheduler_config: SchedulerConfiguration,
+    pub index: u64,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct SchedulerSetConfigResponse {
+    pub updated: bool,
+    pub index: u64,
+}
+
+/// The Autopilot configuration, which controls how the servers clean up dead
+/// servers and promote new ones.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct AutopilotConfiguration {
+    /// Remove dead servers from the Raft peer set when a new server joins.
+    pub cleanup_dead_servers: bool,
+    /// The maximum time a server may go without contacting the leader before
+    /// it is considered unhealthy, as a Go duration string such as `200ms`.
+    pub last_contact_threshold: String,
+    /// The maximum number of log entries a server may trail the leader by
+    /// before it is considered unhealthy.
+    pub max_trailing_logs: u64,
+    /// The minimum number of servers before dead servers are cleaned up.
+    pub min_quorum: u64,
+    /// The time a server must be stable before it is promoted to a voter, as a
+    /// Go duration string such as `10s`.
+    pub server_stabilization_time: String,
+    pub enable_redundancy_zones: bool,
+    pub disable_upgrade_migration: bool,
+    pub enable_custom_upgrades: bool,
+    pub create_index: Option<u64>,
+    pub modify_index: Option<u64>,
+}
+
+/// The health of the servers, as determined by Autopilot.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct OperatorHealthReply {
+    pub healthy: bool,
+    /// The number of servers which could fail without losing quorum.
+    pub failure_tolerance: i32,
+    pub servers: Vec<ServerHealth>,
+}
+
+/// The health of a single server, as determined by Autopilot.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct ServerHealth {
+    #[serde(rename = "ID")]
+    pub id: String,
+    pub name: String,
+    pub address: String,
+    pub serf_status: String,
+    pub version: String,
+    pub leader: bool,
+    /// The time since the server last contacted the leader, as a Go duration
+    /// string such as `15.2ms`.
+    pub last_contact: String,
+    pub last_term: u64,
+    pub last_index: u64,
+    pub healthy: bool,
+    pub voter: bool,
+    #[serde(with = "time::serde::rfc3339")]
+    pub stable_since: time::OffsetDateTime,
+}
+
+/// RootKeyMeta describes a root key of the keyring used to encrypt Variables,
+/// without the key material itself.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct RootKeyMeta {
+    #[serde(rename = "KeyID")]
+    pub key_id: String,
+    pub algorithm: String,
+    /// The time the key was created, in nanoseconds since the Unix epoch.
+    pub create_time: i64,
+    pub state: String,
+    #[serde(default)]
+    pub create_index: u64,
+    #[serde(default)]
+    pub modify_index: u64,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct KeyringRotateResponse {
+    key: RootKeyMeta,
+}
+
+/// The Nomad Enterprise license of the servers.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct License {
+    #[serde(rename = "LicenseID")]
+    pub license_id: String,
+    #[serde(rename = "CustomerID")]
+    pub customer_id: String,
+    #[serde(rename = "InstallationID")]
+    pub installation_id: String,
+    #[serde(with = "time::serde::rfc3339")]
+    pub issue_time: time::OffsetDateTime,
+    #[serde(default, with = "time::serde::rfc3339::option")]
+    pub start_time: Option<time::OffsetDateTime>,
+    #[serde(with = "time::serde::rfc3339")]
+    pub expiration_time: time::OffsetDateTime,
+    #[serde(default, with = "time::serde::rfc3339::option")]
+    pub termination_time: Option<time::OffsetDateTime>,
+    pub product: String,
+    pub flags: Option<HashMap<String, serde_json::Value>>,
+    pub modules: Option<Vec<String>>,
+    pub features: Option<Vec<String>>,
+}
+
+#[derive(Debug, Clone)]
+pub struct LicenseReply {
+    pub license: License,
+    /// Whether the license within the server configuration is older than the
+    /// license in use, such as after the license was updated via the API.
+    pub config_outdated: bool,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct LicenseResponse {
+    license: Option<License>,
+    #[serde(default)]
+    config_outdated: bool,
+}
+
+pub struct Endpoint<'a> {
+    client: &'a Nomad,
+}
+
+impl<'a> Endpoint<'a> {
+    /// Create a new `Endpoint` with the given `Nomad` client to interact with
+    /// the operator endpoints.
+    pub fn new(client: &'a Nomad) -> Self {
+        Self { client }
+    }
+
+    /// Get the Autopilot configuration of the Nomad servers.
+    ///
+    /// # Arguments
+    /// * `opts` - Optional query options for the request.
+    ///
+    /// # Returns
+    /// A `Result` containing the `AutopilotConfiguration` or an error if the
+    /// request fails.
+    pub async fn autopilot_get_configuration(
+        &self,
+        opts: Option<QueryOptions>,
+    ) -> Result<AutopilotConfiguration, ClientError> {
+        let req = self.client.set_request_query_options(
+            self.client
+                .build_request(Method::GET, "/v1/operator/autopilot/configuration"),
+            &opts.unwrap_or_default(),
+        );
+        self.client
+            .send_with_response::<AutopilotConfiguration>(req)
+            .await
+    }
+
+    /// Update the Autopilot configuration of the Nomad servers.
+    ///
+    /// # Arguments
+    /// * `autopilot_configuration` - The Autopilot configuration to apply.
+    /// * `cas` - Optional check-and-set index. When set, the update is only
+    ///   applied if the configuration's current modify index matches.
+    /// * `opts` - Optional write options for the request.
+    ///
+    /// # Returns
+    /// A `Result` containing whether the configuration was updated or an error
+    /// if the request fails.
+    pub async fn autopilot_set_configuration(
+        &self,
+        autopilot_configuration: &AutopilotConfiguration,
+        cas: Option<u64>,
+        opts: Option<WriteOptions>,
+    ) -> Result<bool, ClientError> {
+        let mut req = self
+            .client
+            .set_request_write_options(
+                self.client
+                    .build_request(Method::PUT, "/v1/operator/autopilot/configuration"),
+                &opts.unwrap_or_default(),
+            )
+            .json(autopilot_configuration);
+
+        if let Some(cas) = cas {
+            req = req.query(&[("cas", cas)]);
+        }
+        self.client.send_with_response::<bool>(req).await
+    }
+
+    /// Get the health of the Nomad servers, as determined by Autopilot.
+    ///
+    /// # Arguments
+    /// * `opts` - Optional query options for the request.
+    ///
+    /// # Returns
+    /// A `Result` containing the `OperatorHealthReply` or an error if the
+    /// request fails. An unhealthy cluster is reported via the `healthy` field
+    /// rather than as an error.
+    pub async fn autopilot_health(
+        &self,
+        opts: Option<QueryOptions>,
+    ) -> Result<OperatorHealthReply, ClientError> {
+        let req = self.client.set_request_query_options(
+            self.client
+                .build_request(Method::GET, "/v1/operator/autopilot/health"),
+            &opts.unwrap_or_default(),
+        );
+        self.client
+            .send(req, async |response| {
+                let status = response.status();
+
+                // Nomad responds with a 429 status code when the servers are
+                // unhealthy, but still includes the health report.
+                if status.is_success() || status == StatusCode::TOO_MANY_REQUESTS {
+                    let body = response.bytes().await.map_err(crate::network_error)?;
+                    return crate::decode_body::<OperatorHealthReply>(status, &body);
+                }
+
+                match response.text().await {
+                    Ok(body) => Err(crate::server_error(status, body)),
+                    Err(err) => Err(crate::network_error(err)),
+                }
+            })
+            .await
+    }
+
+    /// Get the metadata of the root keys within the keyring used to encrypt
+    /// Variables.
+    ///
+    /// # Arguments
+    /// * `opts` - Optional query options for the request.
+    ///
+    /// # Returns
+    /// A `Result` containing a vector of `RootKeyMeta` objects or an error if
+    /// the request fails.
+    pub async fn keyring_list(
+        &self,
+        opts: Option<QueryOptions>,
+    ) -> Result<Vec<RootKeyMeta>, ClientError> {
+        let req = self.client.set_request_query_options(
+            self.client
+                .build_request(Method::GET, "/v1/operator/keyring/keys"),
+            &opts.unwrap_or_default(),
+        );
+        self.client
+            .send_with_response::<Vec<RootKeyMeta>>(req)
+            .await
+    }
+
+    /// Remove a root key from the keyring. The active key cannot be removed,
+    /// and any Variables still encrypted with the key become unreadable.
+    ///
+    /// # Arguments
+    /// * `key_id` - The ID of the root key to remove.
+    /// * `opts` - Optional write options for the request.
+    ///
+    /// # Returns
+    /// A `Result` indicating success or failure of the operation.
+    pub async fn keyring_remove(
+        &self,
+        key_id: &str,
+        opts: Option<WriteOptions>,
+    ) -> Result<(), ClientError> {
+        if key_id.is_empty() {
+            return Err(ClientError::InvalidInputError(
+                "Key ID must be set".to_string(),
+            ));
+        }
+
+        let req = self.client.set_request_write_options(
+            self.client.build_request(
+                Method::DELETE,
+                &format!("/v1/operator/keyring/key/{}", key_id),
+            ),
+            &opts.unwrap_or_default(),
+        );
+        self.client.send_without_response(req).await
+    }
+
+    /// Generate a new root key and make it the active key used to encrypt
+    /// Variables.
+    ///
+    /// # Arguments
+    /// * `full` - Whether to also re-encrypt all existing Variables with the
+    ///   new key. Otherwise, existing Variables keep using the previous key.
+    /// * `opts` - Optional write options for the request.
+    ///
+    /// # Returns
+    /// A `Result` containing the `RootKeyMeta` of the new key or an error if
+    /// the request fails.
+    pub async fn keyring_rotate(
+        &self,
+        full: bool,
+        opts: Option<WriteOptions>,
+    ) -> Result<RootKeyMeta, ClientError> {
+        let req = self
+            .client
+            .set_request_write_options(
+                self.client
+                    .build_request(Method::POST, "/v1/operator/keyring/rotate"),
+                &opts.unwrap_or_default(),
+            )
+            .query(&[("full", full)]);
+        self.client
+            .send_with_response::<KeyringRotateResponse>(req)
+            .await
+            .map(|resp| resp.key)
+    }
+
+    /// Get the Nomad Enterprise license of the servers.
+    ///
+    /// # Arguments
+    /// * `opts` - Optional query options for the request.
+    ///
+    /// # Returns
+    /// A `Result` containing the `LicenseReply` or an error if the request
+    /// fails. Clusters without a license, such as those running Nomad CE,
+    /// result in `ClientError::EnterpriseOnly`.
+    pub async fn license_get(
+        &self,
+        opts: Option<QueryOptions>,
+    ) -> Result<LicenseReply, ClientError> {
+        let req = self.client.set_request_query_options(
+            self.client
+                .build_request(Method::GET, "/v1/operator/license"),
+            &opts.unwrap_or_default(),
+        );
+        let resp = self
+            .client
+            .send_with_response::<LicenseResponse>(req)
+            .await?;
+
+        match resp.license {
+            Some(license) => Ok(LicenseReply {
+                license,
+                config_outdated: resp.config_outdated,
+            }),
+            None => Err(ClientError::EnterpriseOnly(
+                "no license is installed".to_string(),
+            )),
+        }
+    }
+
+    /// Get the current Raft peer configuration of the Nomad servers.
+    ///
+    /// # Arguments
+    /// * `opts` - Optional query options for the request.
+    ///
+    /// # Returns
+    /// A `Result` containing the `RaftConfiguration` or an error if the request
+    /// fails.
+    pub async fn raft_get_configuration(
+        &self,
+        opts: Option<QueryOptions>,
+    ) -> Result<RaftConfiguration, ClientError> {
+        let req = self.client.set_request_query_options(
+            self.client
+                .build_request(Method::GET, "/v1/operator/raft/configuration"),
+            &opts.unwrap_or_default(),
+        );
+        self.client
+            .send_with_response::<RaftConfiguration>(req)
+            .await
+    }
+
+    /// Remove a Nomad server from the Raft configuration by its ID. This is
+    /// used to clean up servers which have failed and will not return.
+    ///
+    /// # Arguments
+    /// * `id` - The Raft ID of the server to remove.
+    /// * `opts` - Optional write options for the request.
+    ///
+    /// # Returns
+    /// A `Result` indicating success or failure of the operation.
+    pub async fn raft_remove_peer_by_id(
+        &self,
+        id: &str,
+        opts: Option<WriteOptions>,
+    ) -> Result<(), ClientError> {
+        if id.is_empty() {
+            return Err(ClientError::InvalidInputError(
+                "Raft peer ID must be set".to_string(),
+            ));
+        }
+
+        let req = self
+            .client
+            .set_request_write_options(
+                self.client
+                    .build_request(Method::DELETE, "/v1/operator/raft/peer"),
+                &opts.unwrap_or_default(),
+            )
+            .query(&[("id", id)]);
+        self.client.send_without_response(req).await
+    }
+
+    /// Get the current scheduler configuration of the Nomad cluster.
+    ///
+    /// # Arguments
+    /// * `opts` - Optional query options for the request.
+    ///
+    /// # Returns
+    /// A `Result` containing the `SchedulerConfigurationResponse` or an error
+    /// if the request fails.
+    pub async fn scheduler_get_configuration(
+        &self,
+        opts: Option<QueryOptions>,
+    ) -> Result<SchedulerConfigurationResponse, ClientError> {
+        let req = self.client.set_request_query_options(
+            self.client
+                .build_request(Method::GET, "/v1/operator/scheduler/configuration"),
+            &opts.unwrap_or_default(),
+        );
+        self.client
+            .send_with_response::<SchedulerConfigurationResponse>(req)
+            .await
+    }
+
+    /// Update the scheduler configuration of the Nomad cluster.
+    ///
+    /// # Arguments
+    /// * `scheduler_configuration` - The scheduler configuration to apply.
+    /// * `cas` - Optional check-and-set index. When set, the update is only
+    ///   applied if the configuration's current modify index matches, which is
+    ///   reported via the `updated` field of the response.
+    /// * `opts` - Optional write options for the request.
+    ///
+    /// # Returns
+    /// A `Result` containing the `SchedulerSetConfigResponse` or an error if
+    /// the request fails.
+    pub async fn scheduler_set_configuration(
+        &self,
+        scheduler_configuration: &SchedulerConfiguration,
+        cas: Option<u64>,
+        opts: Option<WriteOptions>,
+    ) -> Result<SchedulerSetConfigResponse, ClientError> {
+        let mut req = self
+            .client
+            .set_request_write_options(
+                self.client
+                    .build_request(Method::PUT, "/v1/operator/scheduler/configuration"),
+                &opts.unwrap_or_default(),
+            )
+            .json(scheduler_configuration);
+
+        if let Some(cas) = cas {
+            req = req.query(&[("cas", cas)]);
+        }
+        self.client
+            .send_with_response::<SchedulerSetConfigResponse>(req)
+            .await
+    }
+
+    /// Save a snapshot of the current state of the Nomad servers.
+    ///
+    /// The snapshot is a gzipped archive rather than JSON, so it is returned as
+    /// raw bytes. The entire snapshot is buffered in memory, which can be
+    /// large on clusters with significant state.
+    ///
+    /// # Arguments
+    /// * `opts` - Optional query options for the request. Setting
+    ///   `allow_stale` allows any server to produce the snapshot.
+    ///
+    /// # Returns
+    /// A `Result` containing the snapshot bytes or an error if the request
+    /// fails.
+    pub async fn snapshot_save(&self, opts: Option<QueryOptions>) -> Result<Bytes, ClientError> {
+        // The request must not set an "Accept: application/json" header, as
+        // the response is a binary archive.
+        let req = self.client.set_request_query_options(
+            self.client
+                .build_request(Method::GET, "/v1/operator/snapshot"),
+            &opts.unwrap_or_default(),
+        );
+
+        self.client
+            .send_raw(req)
+            .await?
+            .bytes()
+            .await
+            .map_err(crate::network_error)
+    }
+
+    /// Restore the state of the Nomad servers from a snapshot previously taken
+    /// using `snapshot_save`.
+    ///
+    /// # Arguments
+    /// * `snapshot` - The snapshot archive to restore.
+    /// * `opts` - Optional write options for the request.
+    ///
+    /// # Returns
+    /// A `Result` indicating success or failure of the operation.
+    pub async fn snapshot_restore(
+        &self,
+        snapshot: Bytes,
+        opts: Option<WriteOptions>,
+    ) -> Result<(), ClientError> {
+        let req = self
+            .client
+            .set_request_write_options(
+                self.client
+                    .build_request(Method::PUT, "/v1/operator/snapshot"),
+                &opts.unwrap_or_default(),
+            )
+            .body(snapshot);
+        self.client.send_without_response(req).await
+    }
+}
@@ -1,7 +1,19 @@
-#[derive(Default)]
+use reqwest::header::HeaderMap;
+use std::cmp::Ordering;
+
+/// The largest page size requested when automatically paginating through list
+/// endpoints. Callers can request smaller pages using `QueryOptions::per_page`.
+pub const QUERY_MAX_PER_PAGE: i32 = 1000;
+
+#[derive(Clone, Default)]
 pub struct QueryOptions {
     pub region: Option<String>,
     pub namespace: Option<String>,
+
+    /// Allow any server to answer the read, rather than only the leader. The
+    /// response may be slightly out of date, but reads which tolerate this,
+    /// such as listings and blocking queries, are spread across the servers.
+    /// Sent as the `stale` query parameter only when `true`.
     pub allow_stale: Option<bool>,
     pub wait_index: Option<u64>,
     pub wait_time: Option<u64>,
@@ -13,6 +25,16 @@ pub struct QueryOptions {
     pub per_page: Option<i32>,
     pub next_token: Option<String>,
     pub reverse: Option<bool>,
+
+    /// Read multiregion jobs across every region they are deployed to, rather
+    /// than only the region the request is made to. Only sent when `true`.
+    pub global: Option<bool>,
+
+    /// The order list methods sort their results in. When unset, each list
+    /// method applies its documented default order, such as jobs by ID
+    /// ascending or allocations by create index descending, unless `reverse`
+    /// is set, in which case the order returned by Nomad is kept.
+    pub sort_order: Option<SortOrder>,
 }
 
 impl QueryOptions {
@@ -33,6 +55,8 @@ impl QueryOptions {
             per_page: None,
             next_token: None,
             reverse: None,
+            global: None,
+            sort_order: None,
         }
     }
     pub fn with_region(mut self, region: String) -> Self {
@@ -47,10 +71,17 @@ impl QueryOptions {
         self.allow_stale = Some(allow_stale);
         self
     }
+    /// Allow the read to be answered by any server, which is shorthand for
+    /// `with_allow_stale(true)`.
+    pub fn stale(self) -> Self {
+        self.with_allow_stale(true)
+    }
     pub fn with_wait_index(mut self, wait_index: u64) -> Self {
         self.wait_index = Some(wait_index);
         self
     }
+    /// Set the maximum duration, in nanoseconds, that a blocking query waits
+    /// for the index to change. This is only used when `wait_index` is set.
     pub fn with_wait_time(mut self, wait_time: u64) -> Self {
         self.wait_time = Some(wait_time);
         self
@@ -75,6 +106,21 @@ impl QueryOptions {
         self.filter = Some(filter);
         self
     }
+    pub fn with_filter_expr(mut self, filter: crate::filter::Filter) -> Self {
+        self.filter = Some(filter.to_string());
+        self
+    }
+    /// Combine the filter expression with any filter already set, so that
+    /// both must match.
+    pub fn and_filter_expr(mut self, filter: crate::filter::Filter) -> Self {
+        let filter = match self.filter.take() {
+            Some(existing) if !existing.is_empty() => {
+                crate::filter::Filter::raw(&existing).and(filter)
+            }
+            _ => filter,
+        };
+        self.with_filter_expr(filter)
+    }
     pub fn with_per_page(mut self, per_page: i32) -> Self {
         self.per_page = Some(per_page);
         self
@@ -87,6 +133,46 @@ impl QueryOptions {
         self.reverse = Some(reverse);
         self
     }
+    pub fn with_global(mut self, global: bool) -> Self {
+        self.global = Some(global);
+        self
+    }
+    pub fn with_sort_order(mut self, sort_order: SortOrder) -> Self {
+        self.sort_order = Some(sort_order);
+        self
+    }
+
+    /// The order a list method sorts its results in, given the default order
+    /// of the method.
+    pub(crate) fn resolve_sort_order(&self, default: SortOrder) -> SortOrder {
+        match self.sort_order {
+            Some(sort_order) => sort_order,
+            None if self.reverse == Some(true) => SortOrder::Server,
+            None => default,
+        }
+    }
+}
+
+/// The order list methods sort their results in, relative to the sort key of
+/// the method, such as the job ID or the create index.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SortOrder {
+    Ascending,
+    Descending,
+    /// Keep the order returned by Nomad, which is ordered by the
+    /// `reverse` query option.
+    Server,
+}
+
+impl SortOrder {
+    /// Sort the items, where `compare` orders them ascending.
+    pub(crate) fn sort_by<T>(self, items: &mut [T], compare: impl Fn(&T, &T) -> Ordering) {
+        match self {
+            SortOrder::Ascending => items.sort_by(compare),
+            SortOrder::Descending => items.sort_by(|a, b| compare(b, a)),
+            SortOrder::Server => {}
+        }
+    }
 }
 
 #[derive(Default)]
@@ -96,6 +182,16 @@ pub struct WriteOptions {
     pub auth_token: Option<String>,
     pub headers: Option<std::collections::HashMap<String, String>>,
     pub idempotency_token: Option<String>,
+
+    /// The Consul token used to authorize the job's Consul usage when
+    /// registering or planning a job. Nomad only reads it from the job, so it
+    /// is set as the job's `ConsulToken` and ignored by other requests.
+    pub consul_token: Option<String>,
+
+    /// The Vault token used to authorize the job's Vault policies when
+    /// registering or planning a job. Nomad only reads it from the job, so it
+    /// is set as the job's `VaultToken` and ignored by other requests.
+    pub vault_token: Option<String>,
 }
 
 impl WriteOptions {
@@ -108,6 +204,8 @@ impl WriteOptions {
             auth_token: None,
             headers: None,
             idempotency_token: None,
+            consul_token: None,
+            vault_token: None,
         }
     }
     pub fn with_region(mut self, region: String) -> Self {
@@ -130,4 +228,67 @@ impl WriteOptions {
         self.idempotency_token = Some(token);
         self
     }
+    pub fn with_consul_token(mut self, consul_token: String) -> Self {
+        self.consul_token = Some(consul_token);
+        self
+    }
+    pub fn with_vault_token(mut self, vault_token: String) -> Self {
+        self.vault_token = Some(vault_token);
+        self
+    }
+}
+
+/// Build the query options for reading back the result of a write, so the
+/// read targets the same region and namespace using the same credentials.
+impl From<&WriteOptions> for QueryOptions {
+    fn from(opts: &WriteOptions) -> Self {
+        QueryOptions {
+            region: opts.region.clone(),
+            namespace: opts.namespace.clone(),
+            auth_token: opts.auth_token.clone(),
+            headers: opts.headers.clone(),
+            ..Default::default()
+        }
+    }
+}
+
+/// QueryMeta contains the metadata Nomad returns via response headers on read
+/// requests.
+#[derive(Debug, Clone, Default)]
+pub struct QueryMeta {
+    /// The Raft index of the data returned, taken from `X-Nomad-Index`. This
+    /// should be used as the `wait_index` of subsequent blocking queries.
+    pub last_index: u64,
+
+    /// The time in milliseconds since the server last contacted the leader,
+    /// taken from `X-Nomad-LastContact`.
+    pub last_contact: u64,
+
+    /// Whether the server knows of a cluster leader, taken from
+    /// `X-Nomad-KnownLeader`.
+    pub known_leader: bool,
+
+    /// The token to pass as `QueryOptions::next_token` to fetch the next page
+    /// of results, taken from `X-Nomad-NextToken`. This is `None` when there
+    /// are no further pages.
+    pub next_token: Option<String>,
+}
+
+impl QueryMeta {
+    pub(crate) fn from_headers(headers: &HeaderMap) -> Self {
+        let header_str = |name: &str| headers.get(name).and_then(|value| value.to_str().ok());
+
+        QueryMeta {
+            last_index: header_str("X-Nomad-Index")
+                .and_then(|value| value.parse().ok())
+                .unwrap_or_default(),
+            last_contact: header_str("X-Nomad-LastContact")
+                .and_then(|value| value.parse().ok())
+                .unwrap_or_default(),
+            known_leader: header_str("X-Nomad-KnownLeader") == Some("true"),
+            next_token: header_str("X-Nomad-NextToken")
+                .filter(|value| !value.is_empty())
+                .map(|value| value.to_string()),
+        }
+    }
 }
@@ -0,0 +1,184 @@
+use crate::option::{QueryOptions, WriteOptions};
+use crate::{ClientError, Nomad};
+use reqwest::Method;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct QuotaSpec {
+    pub name: String,
+    pub description: Option<String>,
+    pub limits: Vec<QuotaLimit>,
+    pub create_index: Option<u64>,
+    pub modify_index: Option<u64>,
+}
+
+impl QuotaSpec {
+    /// Create a new quota specification object with the specified name and
+    /// limits.
+    ///
+    /// # Arguments
+    /// * `name` - The name of the quota specification.
+    /// * `limits` - The per region limits of the quota.
+    ///
+    /// # Returns
+    /// A new `QuotaSpec` object.
+    pub fn new(name: String, limits: Vec<QuotaLimit>) -> Self {
+        Self {
+            name,
+            description: None,
+            limits,
+            create_index: None,
+            modify_index: None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct QuotaLimit {
+    pub region: String,
+    pub region_limit: Option<QuotaResources>,
+    pub variables_limit: Option<i32>,
+    pub hash: Option<String>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct QuotaResources {
+    #[serde(rename = "CPU")]
+    pub cpu: Option<i64>,
+    pub cores: Option<i64>,
+    #[serde(rename = "MemoryMB")]
+    pub memory_mb: Option<i64>,
+    #[serde(rename = "MemoryMaxMB")]
+    pub memory_max_mb: Option<i64>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct QuotaUsage {
+    pub name: String,
+    /// The resources used within each region, keyed by the hash of the
+    /// matching quota limit.
+    pub used: HashMap<String, QuotaLimit>,
+    pub create_index: u64,
+    pub modify_index: u64,
+}
+
+pub struct Endpoint<'a> {
+    client: &'a Nomad,
+}
+
+impl<'a> Endpoint<'a> {
+    /// Create a new `Endpoint` with the given `Nomad` client to interact with
+    /// the quota endpoints. Quotas require Nomad Enterprise.
+    pub fn new(client: &'a Nomad) -> Self {
+        Self { client }
+    }
+
+    /// Create a new quota specification in the Nomad cluster.
+    ///
+    /// # Arguments
+    /// * `quota_spec` - The quota specification to create.
+    /// * `opts` - Optional write options for the request.
+    ///
+    /// # Returns
+    /// A `Result` indicating success or failure of the operation.
+    pub async fn create(
+        &self,
+        quota_spec: &QuotaSpec,
+        opts: Option<WriteOptions>,
+    ) -> Result<(), ClientError> {
+        if quota_spec.name.is_empty() {
+            return Err(ClientError::InvalidInputError(
+                "Quota name must be set".to_string(),
+            ));
+        }
+
+        let req = self
+            .client
+            .set_request_write_options(
+                self.client.build_request(Method::POST, "/v1/quota"),
+                &opts.unwrap_or_default(),
+            )
+            .json(quota_spec);
+        self.client.send_without_response(req).await
+    }
+
+    /// Delete a quota specification by its name.
+    ///
+    /// # Arguments
+    /// * `name` - The name of the quota specification to delete.
+    /// * `opts` - Optional write options for the request.
+    ///
+    /// # Returns
+    /// A `Result` indicating success or failure of the operation.
+    pub async fn delete(&self, name: &str, opts: Option<WriteOptions>) -> Result<(), ClientError> {
+        let req = self.client.set_request_write_options(
+            self.client
+                .build_request(Method::DELETE, &format!("/v1/quota/{}", name)),
+            &opts.unwrap_or_default(),
+        );
+        self.client.send_without_response(req).await
+    }
+
+    /// Get a quota specification by its name.
+    ///
+    /// # Arguments
+    /// * `name` - The name of the quota specification to retrieve.
+    /// * `opts` - Optional query options for the request.
+    ///
+    /// # Returns
+    /// A `Result` containing the `QuotaSpec` object or an error if the request
+    /// fails.
+    pub async fn get(
+        &self,
+        name: &str,
+        opts: Option<QueryOptions>,
+    ) -> Result<QuotaSpec, ClientError> {
+        let req = self.client.set_request_query_options(
+            self.client
+                .build_request(Method::GET, &format!("/v1/quota/{}", name)),
+            &opts.unwrap_or_default(),
+        );
+        self.client.send_with_response::<QuotaSpec>(req).await
+    }
+
+    /// Get the list of quota specifications in the Nomad cluster.
+    ///
+    /// # Arguments
+    /// * `opts` - Optional query options to filter the results.
+    ///
+    /// # Returns
+    /// A `Result` containing a vector of `QuotaSpec` objects or an error if the
+    /// request fails.
+    pub async fn list(&self, opts: Option<QueryOptions>) -> Result<Vec<QuotaSpec>, ClientError> {
+        let req = self.client.set_request_query_options(
+            self.client.build_request(Method::GET, "/v1/quotas"),
+            &opts.unwrap_or_default(),
+        );
+        self.client.send_with_response::<Vec<QuotaSpec>>(req).await
+    }
+
+    /// Get the current usage of every quota specification in the Nomad
+    /// cluster.
+    ///
+    /// # Arguments
+    /// * `opts` - Optional query options to filter the results.
+    ///
+    /// # Returns
+    /// A `Result` containing a vector of `QuotaUsage` objects or an error if
+    /// the request fails.
+    pub async fn list_usage(
+        &self,
+        opts: Option<QueryOptions>,
+    ) -> Result<Vec<QuotaUsage>, ClientError> {
+        let req = self.client.set_request_query_options(
+            self.client.build_request(Method::GET, "/v1/quota/usages"),
+            &opts.unwrap_or_default(),
+        );
+        self.client.send_with_response::<Vec<QuotaUsage>>(req).await
+    }
+}
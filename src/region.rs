@@ -1,5 +1,12 @@
+use crate::option::QueryOptions;
 use crate::{ClientError, Nomad};
+use futures_util::StreamExt;
 use reqwest::Method;
+use std::collections::HashMap;
+
+/// The maximum number of regions queried at the same time by
+/// `Endpoint::for_each_region`.
+pub const REGION_FAN_OUT_CONCURRENCY: usize = 4;
 
 pub struct Endpoint<'a> {
     client: &'a Nomad,
@@ -12,6 +19,40 @@ impl<'a> Endpoint<'a> {
         Self { client }
     }
 
+    /// Run the same request against every region known to the cluster. The
+    /// closure is called once per region, with the region name and query
+    /// options which target that region, and at most
+    /// `REGION_FAN_OUT_CONCURRENCY` requests are in flight at once.
+    ///
+    /// # Arguments
+    /// * `f` - The request to run for each region.
+    ///
+    /// # Returns
+    /// A `Result` containing the result of each region's request keyed by the
+    /// region name, or an error if the regions could not be listed. A failed
+    /// request only affects the entry of its own region.
+    pub async fn for_each_region<T, F, Fut>(
+        &self,
+        f: F,
+    ) -> Result<HashMap<String, Result<T, ClientError>>, ClientError>
+    where
+        F: Fn(String, QueryOptions) -> Fut,
+        Fut: Future<Output = Result<T, ClientError>>,
+    {
+        let regions = self.list().await?;
+
+        let results = futures_util::stream::iter(regions)
+            .map(|region| {
+                let opts = QueryOptions::new().with_region(region.clone());
+                let fut = f(region.clone(), opts);
+                async move { (region, fut.await) }
+            })
+            .buffer_unordered(REGION_FAN_OUT_CONCURRENCY)
+            .collect::<HashMap<_, _>>()
+            .await;
+        Ok(results)
+    }
+
     /// Fetches the list of regions from the Nomad server.
     ///
     /// # Returns
@@ -0,0 +1,125 @@
+use crate::job::ScalingPolicy;
+use crate::option::{QueryOptions, SortOrder};
+use crate::{ClientError, Nomad};
+use reqwest::Method;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+pub const SCALING_POLICY_TYPE_HORIZONTAL: &str = "horizontal";
+pub const SCALING_POLICY_TYPE_VERTICAL_CPU: &str = "vertical_cpu";
+pub const SCALING_POLICY_TYPE_VERTICAL_MEM: &str = "vertical_mem";
+
+/// ScalingPolicyDef is a scaling policy along with the identifiers of the
+/// target it scales, as returned by the scaling policy endpoints.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct ScalingPolicyDef {
+    #[serde(rename = "ID")]
+    pub id: String,
+    pub namespace: Option<String>,
+    #[serde(rename = "Type")]
+    pub policy_type: String,
+    pub target: HashMap<String, String>,
+    #[serde(flatten)]
+    pub scaling: ScalingPolicy,
+    pub create_index: u64,
+    pub modify_index: u64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct ScalingPolicyStub {
+    #[serde(rename = "ID")]
+    pub id: String,
+    pub enabled: bool,
+    #[serde(rename = "Type")]
+    pub policy_type: String,
+    pub target: HashMap<String, String>,
+    pub create_index: u64,
+    pub modify_index: u64,
+}
+
+pub struct Endpoint<'a> {
+    client: &'a Nomad,
+}
+
+impl<'a> Endpoint<'a> {
+    /// Create a new `Endpoint` with the given `Nomad` client to interact with
+    /// the scaling policy endpoints.
+    pub fn new(client: &'a Nomad) -> Self {
+        Self { client }
+    }
+
+    /// Get a specific scaling policy by its ID.
+    ///
+    /// # Arguments
+    /// * `id` - The ID of the scaling policy to retrieve.
+    /// * `opts` - Optional query options for the request.
+    ///
+    /// # Returns
+    /// A `Result` containing the `ScalingPolicyDef` or an error if the request
+    /// fails.
+    pub async fn get_policy(
+        &self,
+        id: &str,
+        opts: Option<QueryOptions>,
+    ) -> Result<ScalingPolicyDef, ClientError> {
+        if id.is_empty() {
+            return Err(ClientError::InvalidInputError(
+                "Scaling policy ID must be set".to_string(),
+            ));
+        }
+
+        let req = self.client.set_request_query_options(
+            self.client
+                .build_request(Method::GET, &format!("/v1/scaling/policy/{}", id)),
+            &opts.unwrap_or_default(),
+        );
+        self.client
+            .send_with_response::<ScalingPolicyDef>(req)
+            .await
+    }
+
+    /// Get the list of scaling policies in the Nomad cluster.
+    ///
+    /// # Arguments
+    /// * `job` - Optional job ID to only list the policies of.
+    /// * `policy_type` - Optional policy type, such as
+    ///   `SCALING_POLICY_TYPE_HORIZONTAL`, to only list policies of.
+    /// * `opts` - Optional query options for the request.
+    ///
+    /// # Returns
+    /// A `Result` containing a vector of `ScalingPolicyStub` objects or an
+    /// error if the request fails.
+    pub async fn list_policies(
+        &self,
+        job: Option<&str>,
+        policy_type: Option<&str>,
+        opts: Option<QueryOptions>,
+    ) -> Result<Vec<ScalingPolicyStub>, ClientError> {
+        let opts = opts.unwrap_or_default();
+        let sort_order = opts.resolve_sort_order(SortOrder::Descending);
+        let mut req = self.client.set_request_query_options(
+            self.client
+                .build_request(Method::GET, "/v1/scaling/policies"),
+            &opts,
+        );
+
+        if let Some(job) = job {
+            req = req.query(&[("job", job)]);
+        }
+        if let Some(policy_type) = policy_type {
+            req = req.query(&[("type", policy_type)]);
+        }
+
+        let mut policies = self
+            .client
+            .send_with_response::<Vec<ScalingPolicyStub>>(req)
+            .await?;
+
+        // Sort by CreateIndex, highest first unless another order is requested
+        sort_order.sort_by(&mut policies, |a, b| a.create_index.cmp(&b.create_index));
+
+        Ok(policies)
+    }
+}
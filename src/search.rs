@@ -0,0 +1,146 @@
+use crate::option::QueryOptions;
+use crate::{ClientError, Nomad};
+use reqwest::Method;
+use serde::{Deserialize, Deserializer, Serialize};
+use std::collections::HashMap;
+
+/// The context limits the type of objects a search is performed against.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum SearchContext {
+    Allocs,
+    #[serde(rename = "deployment")]
+    Deployments,
+    Evals,
+    Jobs,
+    Namespaces,
+    Nodes,
+    #[serde(rename = "node_pools")]
+    NodePools,
+    Plugins,
+    Quotas,
+    #[serde(rename = "scaling_policy")]
+    ScalingPolicies,
+    #[serde(rename = "vars")]
+    Variables,
+    Volumes,
+    All,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "PascalCase")]
+struct SearchRequest<'a> {
+    prefix: &'a str,
+    context: SearchContext,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "PascalCase")]
+struct FuzzySearchRequest<'a> {
+    text: &'a str,
+    context: SearchContext,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct SearchResponse {
+    #[serde(deserialize_with = "null_matches_as_empty")]
+    pub matches: HashMap<String, Vec<String>>,
+    pub truncations: HashMap<String, bool>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct FuzzySearchResponse {
+    #[serde(deserialize_with = "null_matches_as_empty")]
+    pub matches: HashMap<String, Vec<FuzzyMatch>>,
+    pub truncations: HashMap<String, bool>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct FuzzyMatch {
+    #[serde(rename = "ID")]
+    pub id: String,
+    pub scope: Option<Vec<String>>,
+}
+
+/// Decode the matches of a search, treating the `null` Nomad returns for a
+/// context without any matches as an empty list.
+fn null_matches_as_empty<'de, D, T>(deserializer: D) -> Result<HashMap<String, Vec<T>>, D::Error>
+where
+    D: Deserializer<'de>,
+    T: Deserialize<'de>,
+{
+    let matches = HashMap::<String, Option<Vec<T>>>::deserialize(deserializer)?;
+    Ok(matches
+        .into_iter()
+        .map(|(context, matches)| (context, matches.unwrap_or_default()))
+        .collect())
+}
+
+pub struct Endpoint<'a> {
+    client: &'a Nomad,
+}
+
+impl<'a> Endpoint<'a> {
+    /// Create a new `Endpoint` with the given `Nomad` client to interact with
+    /// the search endpoints.
+    pub fn new(client: &'a Nomad) -> Self {
+        Self { client }
+    }
+
+    /// Search for objects whose name or ID contains the passed text.
+    ///
+    /// # Arguments
+    /// * `text` - The text to search for.
+    /// * `context` - The type of objects to search.
+    /// * `opts` - Optional query options for the request.
+    ///
+    /// # Returns
+    /// A `Result` containing the `FuzzySearchResponse` or an error if the
+    /// request fails.
+    pub async fn fuzzy_search(
+        &self,
+        text: &str,
+        context: SearchContext,
+        opts: Option<QueryOptions>,
+    ) -> Result<FuzzySearchResponse, ClientError> {
+        let req = self
+            .client
+            .set_request_query_options(
+                self.client.build_request(Method::POST, "/v1/search/fuzzy"),
+                &opts.unwrap_or_default(),
+            )
+            .json(&FuzzySearchRequest { text, context });
+        self.client
+            .send_with_response::<FuzzySearchResponse>(req)
+            .await
+    }
+
+    /// Search for objects whose ID starts with the passed prefix.
+    ///
+    /// # Arguments
+    /// * `prefix` - The ID prefix to search for.
+    /// * `context` - The type of objects to search.
+    /// * `opts` - Optional query options for the request.
+    ///
+    /// # Returns
+    /// A `Result` containing the `SearchResponse` or an error if the request
+    /// fails.
+    pub async fn prefix_search(
+        &self,
+        prefix: &str,
+        context: SearchContext,
+        opts: Option<QueryOptions>,
+    ) -> Result<SearchResponse, ClientError> {
+        let req = self
+            .client
+            .set_request_query_options(
+                self.client.build_request(Method::POST, "/v1/search"),
+                &opts.unwrap_or_default(),
+            )
+            .json(&SearchRequest { prefix, context });
+        self.client.send_with_response::<SearchResponse>(req).await
+    }
+}
@@ -0,0 +1,175 @@
+use crate::option::{QueryOptions, WriteOptions};
+use crate::{ClientError, Nomad};
+use reqwest::Method;
+use serde::{Deserialize, Serialize};
+
+pub const SENTINEL_POLICY_SCOPE_SUBMIT_JOB: &str = "submit-job";
+pub const SENTINEL_POLICY_SCOPE_SUBMIT_HOST_VOLUME: &str = "submit-host-volume";
+
+/// The enforcement level determines whether a failing policy can be
+/// overridden.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum EnforcementLevel {
+    Advisory,
+    SoftMandatory,
+    HardMandatory,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct SentinelPolicy {
+    pub name: String,
+    pub description: Option<String>,
+    pub scope: String,
+    pub enforcement_level: EnforcementLevel,
+    pub policy: String,
+    pub create_index: Option<u64>,
+    pub modify_index: Option<u64>,
+}
+
+impl SentinelPolicy {
+    /// Create a new Sentinel policy object.
+    ///
+    /// # Arguments
+    /// * `name` - The name of the Sentinel policy.
+    /// * `scope` - The scope the policy applies to, such as `submit-job`.
+    /// * `enforcement_level` - The enforcement level of the policy.
+    /// * `policy` - The Sentinel source code of the policy.
+    ///
+    /// # Returns
+    /// A new `SentinelPolicy` object.
+    pub fn new(
+        name: String,
+        scope: String,
+        enforcement_level: EnforcementLevel,
+        policy: String,
+    ) -> Self {
+        Self {
+            name,
+            description: None,
+            scope,
+            enforcement_level,
+            policy,
+            create_index: None,
+            modify_index: None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct SentinelPolicyListStub {
+    pub name: String,
+    pub description: Option<String>,
+    pub scope: String,
+    pub enforcement_level: EnforcementLevel,
+    pub create_index: u64,
+    pub modify_index: u64,
+}
+
+pub struct Endpoint<'a> {
+    client: &'a Nomad,
+}
+
+impl<'a> Endpoint<'a> {
+    /// Create a new `Endpoint` with the given `Nomad` client to interact with
+    /// the Sentinel policy endpoints. Sentinel policies require Nomad
+    /// Enterprise.
+    pub fn new(client: &'a Nomad) -> Self {
+        Self { client }
+    }
+
+    /// Delete a Sentinel policy by its name.
+    ///
+    /// # Arguments
+    /// * `name` - The name of the Sentinel policy to delete.
+    /// * `opts` - Optional write options for the request.
+    ///
+    /// # Returns
+    /// A `Result` indicating success or failure of the operation.
+    pub async fn delete(&self, name: &str, opts: Option<WriteOptions>) -> Result<(), ClientError> {
+        let req = self.client.set_request_write_options(
+            self.client
+                .build_request(Method::DELETE, &format!("/v1/sentinel/policy/{}", name)),
+            &opts.unwrap_or_default(),
+        );
+        self.client.send_without_response(req).await
+    }
+
+    /// Get a Sentinel policy by its name.
+    ///
+    /// # Arguments
+    /// * `name` - The name of the Sentinel policy to retrieve.
+    /// * `opts` - Optional query options for the request.
+    ///
+    /// # Returns
+    /// A `Result` containing the `SentinelPolicy` object or an error if the
+    /// request fails.
+    pub async fn get(
+        &self,
+        name: &str,
+        opts: Option<QueryOptions>,
+    ) -> Result<SentinelPolicy, ClientError> {
+        let req = self.client.set_request_query_options(
+            self.client
+                .build_request(Method::GET, &format!("/v1/sentinel/policy/{}", name)),
+            &opts.unwrap_or_default(),
+        );
+        self.client.send_with_response::<SentinelPolicy>(req).await
+    }
+
+    /// Get the list of Sentinel policies in the Nomad cluster.
+    ///
+    /// # Arguments
+    /// * `opts` - Optional query options to filter the results.
+    ///
+    /// # Returns
+    /// A `Result` containing a vector of `SentinelPolicyListStub` objects or
+    /// an error if the request fails.
+    pub async fn list(
+        &self,
+        opts: Option<QueryOptions>,
+    ) -> Result<Vec<SentinelPolicyListStub>, ClientError> {
+        let req = self.client.set_request_query_options(
+            self.client
+                .build_request(Method::GET, "/v1/sentinel/policies"),
+            &opts.unwrap_or_default(),
+        );
+        self.client
+            .send_with_response::<Vec<SentinelPolicyListStub>>(req)
+            .await
+    }
+
+    /// Create or update a Sentinel policy in the Nomad cluster.
+    ///
+    /// # Arguments
+    /// * `policy` - The Sentinel policy to create or update.
+    /// * `opts` - Optional write options for the request.
+    ///
+    /// # Returns
+    /// A `Result` indicating success or failure of the operation.
+    pub async fn upsert(
+        &self,
+        policy: &SentinelPolicy,
+        opts: Option<WriteOptions>,
+    ) -> Result<(), ClientError> {
+        if policy.name.is_empty() {
+            return Err(ClientError::InvalidInputError(
+                "Sentinel policy name must be set".to_string(),
+            ));
+        }
+
+        let req = self
+            .client
+            .set_request_write_options(
+                self.client.build_request(
+                    Method::POST,
+                    &format!("/v1/sentinel/policy/{}", policy.name),
+                ),
+                &opts.unwrap_or_default(),
+            )
+            .json(policy);
+        self.client.send_without_response(req).await
+    }
+}
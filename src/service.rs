@@ -1,4 +1,6 @@
+use crate::option::{QueryMeta, QueryOptions, SortOrder, WriteOptions};
 use crate::{ClientError, Nomad};
+use reqwest::Method;
 use serde::{Deserialize, Serialize};
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -18,12 +20,16 @@ pub struct ServiceRegistrationStub {
 #[derive(Debug, Serialize, Deserialize)]
 #[serde(rename_all = "PascalCase")]
 pub struct ServiceRegistration {
+    #[serde(rename = "ID")]
     pub id: String,
     pub service_name: String,
     pub namespace: String,
+    #[serde(rename = "NodeID")]
     pub node_id: String,
     pub datacenter: String,
+    #[serde(rename = "JobID")]
     pub job_id: String,
+    #[serde(rename = "AllocID")]
     pub alloc_id: String,
     pub tags: Vec<String>,
     pub address: String,
@@ -47,13 +53,16 @@ impl<'a> Endpoint<'a> {
     ///
     /// # Arguments
     /// * `name` - The name of the service to delete.
+    /// * `opts` - Optional write options for the request.
     ///
     /// # Returns
     /// A `Result` indicating success or failure of the operation.
-    pub async fn delete(&self, name: &str) -> Result<(), ClientError> {
-        let req = self
-            .client
-            .build_request(reqwest::Method::DELETE, &format!("/v1/service/{}", name));
+    pub async fn delete(&self, name: &str, opts: Option<WriteOptions>) -> Result<(), ClientError> {
+        let req = self.client.set_request_write_options(
+            self.client
+                .build_request(Method::DELETE, &format!("/v1/service/{}", name)),
+            &opts.unwrap_or_default(),
+        );
         self.client.send_without_response(req).await
     }
 
@@ -61,14 +70,21 @@ impl<'a> Endpoint<'a> {
     ///
     /// # Arguments
     /// * `name` - The name of the service to retrieve.
+    /// * `opts` - Optional query options for the request.
     ///
     /// # Returns
     /// A `Result` containing a vector of `ServiceRegistration` or an error if
     /// the request fails.
-    pub async fn get(&self, name: &str) -> Result<Vec<ServiceRegistration>, ClientError> {
-        let req = self
-            .client
-            .build_request(reqwest::Method::GET, &format!("/v1/service/{}", name));
+    pub async fn get(
+        &self,
+        name: &str,
+        opts: Option<QueryOptions>,
+    ) -> Result<Vec<ServiceRegistration>, ClientError> {
+        let req = self.client.set_request_query_options(
+            self.client
+                .build_request(Method::GET, &format!("/v1/service/{}", name)),
+            &opts.unwrap_or_default(),
+        );
         self.client
             .send_with_response::<Vec<ServiceRegistration>>(req)
             .await
@@ -76,15 +92,74 @@ impl<'a> Endpoint<'a> {
 
     /// Get the list of services registered in the Nomad cluster.
     ///
+    /// # Arguments
+    /// * `opts` - Optional query options to filter the results.
+    ///
     /// # Returns
     /// A `Result` containing a vector of `ServiceRegistrationList` or an error
     /// if the request fails.
-    pub async fn list(&self) -> Result<Vec<ServiceRegistrationList>, ClientError> {
-        let req = self
-            .client
-            .build_request(reqwest::Method::GET, "/v1/services");
-        self.client
-            .send_with_response::<Vec<ServiceRegistrationList>>(req)
+    pub async fn list(
+        &self,
+        opts: Option<QueryOptions>,
+    ) -> Result<Vec<ServiceRegistrationList>, ClientError> {
+        self.list_with_meta(opts)
             .await
+            .map(|(services, _)| services)
+    }
+
+    /// Get the list of services registered within a single namespace.
+    ///
+    /// # Arguments
+    /// * `namespace` - The namespace to list the services of. This overrides
+    ///   any namespace set within `opts`.
+    /// * `opts` - Optional query options to filter the results.
+    ///
+    /// # Returns
+    /// A `Result` containing a vector of `ServiceRegistrationList` or an error
+    /// if the request fails.
+    pub async fn list_by_namespace(
+        &self,
+        namespace: &str,
+        opts: Option<QueryOptions>,
+    ) -> Result<Vec<ServiceRegistrationList>, ClientError> {
+        let mut opts = opts.unwrap_or_default();
+        opts.namespace = Some(namespace.to_string());
+        self.list(Some(opts)).await
+    }
+
+    /// Get the list of services registered in the Nomad cluster along with
+    /// the query metadata of the response. The `next_token` of the metadata
+    /// can be used to request the following page.
+    ///
+    /// # Arguments
+    /// * `opts` - Optional query options to filter and paginate the results.
+    ///
+    /// # Returns
+    /// A `Result` containing a vector of `ServiceRegistrationList` and the
+    /// `QueryMeta` of the response, or an error if the request fails.
+    pub async fn list_with_meta(
+        &self,
+        opts: Option<QueryOptions>,
+    ) -> Result<(Vec<ServiceRegistrationList>, QueryMeta), ClientError> {
+        let opts = opts.unwrap_or_default();
+        let sort_order = opts.resolve_sort_order(SortOrder::Ascending);
+        let req = self.client.set_request_query_options(
+            self.client.build_request(Method::GET, "/v1/services"),
+            &opts,
+        );
+        let (mut services, meta) = self
+            .client
+            .send_with_response_meta::<Vec<ServiceRegistrationList>>(req)
+            .await?;
+
+        // Sort by Namespace, then ServiceName, lowest first unless another
+        // order is requested
+        sort_order.sort_by(&mut services, |a, b| a.namespace.cmp(&b.namespace));
+        for list in services.iter_mut() {
+            sort_order.sort_by(&mut list.services, |a, b| {
+                a.service_name.cmp(&b.service_name)
+            });
+        }
+        Ok((services, meta))
     }
 }
@@ -27,13 +27,34 @@ impl<'a> Endpoint<'a> {
         self.client.send_with_response::<String>(req).await
     }
 
+    /// Get the address of the leader of a specific region, which may differ
+    /// from the region the client is configured for.
+    ///
+    /// # Arguments
+    /// `region`: The region whose leader to get.
+    ///
+    /// # Returns
+    /// A `Result` containing the leader's address as a `String` or an error if
+    /// the request fails.
+    pub async fn get_leader_in_region(&self, region: &str) -> Result<String, ClientError> {
+        self.get_leader(Some(QueryOptions::new().with_region(region.to_string())))
+            .await
+    }
+
     /// Get the list of peers in the Nomad cluster.
     ///
+    /// # Arguments
+    /// `opts`: Optional query options for the request, such as the region to
+    /// list the peers of.
+    ///
     /// # Returns
     /// A `Result` containing a vector of peer addresses as `String`s or an
     /// error if the request fails.
-    pub async fn list_peers(&self) -> Result<Vec<String>, ClientError> {
-        let req = self.client.build_request(Method::GET, "/v1/status/peers");
+    pub async fn list_peers(&self, opts: Option<QueryOptions>) -> Result<Vec<String>, ClientError> {
+        let req = self.client.set_request_query_options(
+            self.client.build_request(Method::GET, "/v1/status/peers"),
+            &opts.unwrap_or_default(),
+        );
         self.client.send_with_response::<Vec<String>>(req).await
     }
 }
@@ -0,0 +1,47 @@
+use crate::option::WriteOptions;
+use crate::{ClientError, Nomad};
+use reqwest::Method;
+
+pub struct Endpoint<'a> {
+    client: &'a Nomad,
+}
+
+impl<'a> Endpoint<'a> {
+    /// Create a new `Endpoint` with the given `Nomad` client to interact with
+    /// the system endpoints.
+    pub fn new(client: &'a Nomad) -> Self {
+        Self { client }
+    }
+
+    /// Trigger a cluster-wide garbage collection of terminal jobs,
+    /// evaluations, allocations, and nodes.
+    ///
+    /// # Arguments
+    /// * `opts` - Optional write options for the request.
+    ///
+    /// # Returns
+    /// A `Result` indicating success or failure of the operation.
+    pub async fn garbage_collect(&self, opts: Option<WriteOptions>) -> Result<(), ClientError> {
+        let req = self.client.set_request_write_options(
+            self.client.build_request(Method::PUT, "/v1/system/gc"),
+            &opts.unwrap_or_default(),
+        );
+        self.client.send_without_response(req).await
+    }
+
+    /// Reconcile the summaries of all registered jobs.
+    ///
+    /// # Arguments
+    /// * `opts` - Optional write options for the request.
+    ///
+    /// # Returns
+    /// A `Result` indicating success or failure of the operation.
+    pub async fn reconcile_summaries(&self, opts: Option<WriteOptions>) -> Result<(), ClientError> {
+        let req = self.client.set_request_write_options(
+            self.client
+                .build_request(Method::PUT, "/v1/system/reconcile/summaries"),
+            &opts.unwrap_or_default(),
+        );
+        self.client.send_without_response(req).await
+    }
+}
@@ -0,0 +1,123 @@
+use crate::ClientError;
+use std::time::Instant;
+use tracing::Span;
+use tracing::field::Empty;
+
+/// The replacement for identifiers removed from request paths.
+const REDACTED: &str = ":id";
+
+/// Path segments which are followed by a user chosen name, such as a job ID
+/// or namespace name.
+const NAMED_SEGMENTS: &[&str] = &[
+    "auth-method",
+    "csi",
+    "job",
+    "name",
+    "namespace",
+    "policy",
+    "pool",
+    "quota",
+    "role",
+    "service",
+];
+
+/// Path segments which are themselves part of an endpoint path and must not be
+/// redacted, even when following one of the `NAMED_SEGMENTS`.
+const ENDPOINT_SEGMENTS: &[&str] = &["name", "pool", "self", "usages"];
+
+/// Create the span recording a single request to the Nomad API. The status and
+/// elapsed time are recorded once the request completes.
+pub(crate) fn request_span(req: &reqwest::Request) -> Span {
+    tracing::info_span!(
+        "nomad_request",
+        method = %req.method(),
+        path = %redact_path(req.url().path()),
+        status = Empty,
+        elapsed_ms = Empty,
+    )
+}
+
+/// Record the outcome of a request on its span, emitting an error event when
+/// it failed. The error message is not logged, as it may contain the response
+/// body.
+pub(crate) fn record_result<T>(span: &Span, start: Instant, result: &Result<T, ClientError>) {
+    span.record("elapsed_ms", start.elapsed().as_millis() as u64);
+
+    if let Err(err) = result {
+        let _enter = span.enter();
+        tracing::error!(error = error_kind(err), "Nomad request failed");
+    }
+}
+
+fn error_kind(err: &ClientError) -> &'static str {
+    match err {
+        ClientError::RequestCreationError(_) => "request_creation",
+        ClientError::DeserializationError { .. } => "deserialization",
+        ClientError::ServerError { .. } => "server",
+        ClientError::PermissionDenied { .. } => "permission_denied",
+        ClientError::NetworkError(_) => "network",
+        ClientError::Timeout(_) => "timeout",
+        ClientError::ConnectionError(_) => "connection",
+        ClientError::InvalidInputError(_) => "invalid_input",
+        ClientError::ConfigurationError(_) => "configuration",
+        ClientError::EnterpriseOnly(_) => "enterprise_only",
+        ClientError::CasConflict { .. } => "cas_conflict",
+        ClientError::IncompleteResponse(_) => "incomplete_response",
+    }
+}
+
+/// Redact the identifiers within a request path, so that spans can be grouped
+/// by endpoint and do not expose object names. UUIDs and the names following
+/// `NAMED_SEGMENTS` are replaced, as is the entire path of a variable.
+fn redact_path(path: &str) -> String {
+    if path
+        .strip_prefix("/v1/var/")
+        .is_some_and(|rest| !rest.is_empty())
+    {
+        return format!("/v1/var/{}", REDACTED);
+    }
+
+    let mut redacted = Vec::new();
+    let mut previous = "";
+    for segment in path.split('/') {
+        let is_name = NAMED_SEGMENTS.contains(&previous) && !ENDPOINT_SEGMENTS.contains(&segment);
+        if !segment.is_empty() && (is_name || is_uuid(segment)) {
+            redacted.push(REDACTED);
+        } else {
+            redacted.push(segment);
+        }
+        previous = segment;
+    }
+    redacted.join("/")
+}
+
+fn is_uuid(segment: &str) -> bool {
+    segment.len() == 36
+        && segment.char_indices().all(|(idx, c)| match idx {
+            8 | 13 | 18 | 23 => c == '-',
+            _ => c.is_ascii_hexdigit(),
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn redacts_names_and_uuids() {
+        assert_eq!(redact_path("/v1/quota/team-a"), "/v1/quota/:id");
+        assert_eq!(redact_path("/v1/acl/policy/readonly"), "/v1/acl/policy/:id");
+        assert_eq!(
+            redact_path("/v1/allocation/a8198d79-cfdb-6593-a999-1e9adabcba2e/services"),
+            "/v1/allocation/:id/services"
+        );
+        assert_eq!(redact_path("/v1/var/nomad/jobs/example"), "/v1/var/:id");
+    }
+
+    #[test]
+    fn keeps_endpoint_segments() {
+        assert_eq!(redact_path("/v1/quota/usages"), "/v1/quota/usages");
+        assert_eq!(redact_path("/v1/acl/policy/self"), "/v1/acl/policy/self");
+        assert_eq!(redact_path("/v1/node/pool/gpu"), "/v1/node/pool/:id");
+    }
+}
@@ -0,0 +1,133 @@
+use crate::{ClientError, Config};
+use bytes::Bytes;
+use hyper::Uri;
+use hyper::client::Client;
+use hyper::service::Service;
+use hyperlocal::UnixConnector;
+use reqwest::header::{HeaderValue, USER_AGENT};
+use reqwest::{Request, Response};
+use std::future::Future;
+use std::io;
+use std::path::PathBuf;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+use std::time::Duration;
+
+/// UnixTransport sends requests to a Nomad agent listening on a Unix domain
+/// socket. Requests are built using reqwest as normal, and only the dialing
+/// of the connection differs.
+pub(crate) struct UnixTransport {
+    client: Client<TimeoutConnector>,
+    socket_path: PathBuf,
+    user_agent: HeaderValue,
+}
+
+impl UnixTransport {
+    /// Create a transport for the socket at `socket_path`. Requests bypass the
+    /// reqwest client, so the `User-Agent`, connect timeout, and connection
+    /// pool settings are applied here instead.
+    pub(crate) fn new(socket_path: &str, config: &Config, user_agent: HeaderValue) -> Self {
+        let connector = TimeoutConnector {
+            inner: UnixConnector,
+            connect_timeout: config.connect_timeout,
+        };
+
+        let mut builder = Client::builder();
+        if let Some(pool_max_idle_per_host) = config.pool_max_idle_per_host {
+            builder.pool_max_idle_per_host(pool_max_idle_per_host);
+        }
+        if let Some(pool_idle_timeout) = config.pool_idle_timeout {
+            builder.pool_idle_timeout(pool_idle_timeout);
+        }
+
+        Self {
+            client: builder.build(connector),
+            socket_path: PathBuf::from(socket_path),
+            user_agent,
+        }
+    }
+
+    pub(crate) async fn execute(&self, req: Request) -> Result<Response, ClientError> {
+        let path = match req.url().query() {
+            Some(query) => format!("{}?{}", req.url().path(), query),
+            None => req.url().path().to_string(),
+        };
+
+        // Request bodies are always buffered by this client, other than when
+        // a caller passes a stream, which cannot be replayed onto the socket.
+        let body = match req.body() {
+            Some(body) => body.as_bytes().map(Bytes::copy_from_slice).ok_or_else(|| {
+                ClientError::RequestCreationError(
+                    "Streaming request bodies are not supported over a Unix socket".to_string(),
+                )
+            })?,
+            None => Bytes::new(),
+        };
+
+        let mut builder = hyper::Request::builder()
+            .method(req.method().clone())
+            .uri(hyperlocal::Uri::new(&self.socket_path, &path));
+        if let Some(headers) = builder.headers_mut() {
+            headers.extend(req.headers().clone());
+            headers
+                .entry(USER_AGENT)
+                .or_insert_with(|| self.user_agent.clone());
+        }
+        let request = builder
+            .body(hyper::Body::from(body))
+            .map_err(|err| ClientError::RequestCreationError(err.to_string()))?;
+
+        let response = self.client.request(request).await.map_err(|err| {
+            let timed_out = std::error::Error::source(&err)
+                .and_then(|source| source.downcast_ref::<io::Error>())
+                .is_some_and(|source| source.kind() == io::ErrorKind::TimedOut);
+            if timed_out {
+                ClientError::Timeout(err.to_string())
+            } else if err.is_connect() {
+                ClientError::ConnectionError(err.to_string())
+            } else {
+                ClientError::NetworkError(err.to_string())
+            }
+        })?;
+        Ok(Response::from(response.map(reqwest::Body::from)))
+    }
+}
+
+/// TimeoutConnector dials the Unix socket, failing with
+/// `io::ErrorKind::TimedOut` if the connection is not established within
+/// `Config::connect_timeout`.
+#[derive(Clone)]
+struct TimeoutConnector {
+    inner: UnixConnector,
+    connect_timeout: Option<Duration>,
+}
+
+type ConnectResponse = <UnixConnector as Service<Uri>>::Response;
+
+impl Service<Uri> for TimeoutConnector {
+    type Response = ConnectResponse;
+    type Error = io::Error;
+    type Future = Pin<Box<dyn Future<Output = Result<ConnectResponse, io::Error>> + Send>>;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), io::Error>> {
+        self.inner.poll_ready(cx)
+    }
+
+    fn call(&mut self, uri: Uri) -> Self::Future {
+        let connect = self.inner.call(uri);
+        let connect_timeout = self.connect_timeout;
+        Box::pin(async move {
+            match connect_timeout {
+                Some(connect_timeout) => tokio::time::timeout(connect_timeout, connect)
+                    .await
+                    .map_err(|_| {
+                        io::Error::new(
+                            io::ErrorKind::TimedOut,
+                            "timed out connecting to the Unix socket",
+                        )
+                    })?,
+                None => connect.await,
+            }
+        })
+    }
+}
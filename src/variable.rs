@@ -0,0 +1,258 @@
+use crate::option::{QueryOptions, WriteOptions};
+use crate::{ClientError, Nomad};
+use reqwest::{Method, RequestBuilder, Response, StatusCode};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct Variable {
+    pub path: String,
+    pub namespace: Option<String>,
+    pub items: HashMap<String, String>,
+    pub create_index: Option<u64>,
+    pub modify_index: Option<u64>,
+    pub create_time: Option<i64>,
+    pub modify_time: Option<i64>,
+}
+
+impl Variable {
+    /// Create a new variable object with the specified path and items.
+    ///
+    /// # Arguments
+    /// * `path` - The path of the variable.
+    /// * `items` - The key/value pairs stored within the variable.
+    ///
+    /// # Returns
+    /// A new `Variable` object.
+    pub fn new(path: String, items: HashMap<String, String>) -> Self {
+        Self {
+            path,
+            namespace: None,
+            items,
+            create_index: None,
+            modify_index: None,
+            create_time: None,
+            modify_time: None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct VariableMetadata {
+    pub path: String,
+    pub namespace: String,
+    pub create_index: u64,
+    pub modify_index: u64,
+    pub create_time: i64,
+    pub modify_time: i64,
+}
+
+pub struct Endpoint<'a> {
+    client: &'a Nomad,
+}
+
+impl<'a> Endpoint<'a> {
+    /// Create a new `Endpoint` with the given `Nomad` client to interact with
+    /// the variable endpoints.
+    pub fn new(client: &'a Nomad) -> Self {
+        Self { client }
+    }
+
+    /// Create a new variable in the Nomad cluster. This can also be used to
+    /// update an existing variable by providing the same path.
+    ///
+    /// # Arguments
+    /// * `variable` - The variable to create.
+    /// * `cas` - Optional check-and-set index. When set, the write only
+    ///   succeeds if the variable's current modify index matches. A value of
+    ///   `0` requires that the variable does not already exist.
+    /// * `opts` - Optional write options for the request.
+    ///
+    /// # Returns
+    /// A `Result` indicating success or failure of the operation.
+    pub async fn create(
+        &self,
+        variable: &Variable,
+        cas: Option<u64>,
+        opts: Option<WriteOptions>,
+    ) -> Result<(), ClientError> {
+        let mut req = self
+            .client
+            .set_request_write_options(
+                self.client
+                    .build_request(Method::PUT, &format!("/v1/var/{}", variable.path)),
+                &opts.unwrap_or_default(),
+            )
+            .json(variable);
+
+        if let Some(cas) = cas {
+            req = req.query(&[("cas", cas)]);
+        }
+        self.send_cas(req, cas).await.map(|_| ())
+    }
+
+    /// Create or update a variable using a check-and-set index, returning the
+    /// variable as stored by Nomad.
+    ///
+    /// # Arguments
+    /// * `variable` - The variable to write.
+    /// * `cas` - The modify index the variable is expected to have. Use `0` to
+    ///   only create the variable if it does not already exist.
+    /// * `opts` - Optional write options for the request.
+    ///
+    /// # Returns
+    /// A `Result` containing the stored `Variable` or an error if the request
+    /// fails. If the check-and-set index is stale, `ClientError::CasConflict`
+    /// is returned.
+    pub async fn create_cas(
+        &self,
+        variable: &Variable,
+        cas: u64,
+        opts: Option<WriteOptions>,
+    ) -> Result<Variable, ClientError> {
+        let req = self
+            .client
+            .set_request_write_options(
+                self.client
+                    .build_request(Method::PUT, &format!("/v1/var/{}", variable.path)),
+                &opts.unwrap_or_default(),
+            )
+            .query(&[("cas", cas)])
+            .json(variable);
+
+        let response = self.send_cas(req, Some(cas)).await?;
+        let status = response.status();
+        let body = response.bytes().await.map_err(crate::network_error)?;
+        crate::decode_body::<Variable>(status, &body)
+    }
+
+    /// Delete a variable by its path.
+    ///
+    /// # Arguments
+    /// * `path` - The path of the variable to delete.
+    /// * `cas` - Optional check-and-set index. When set, the delete only
+    ///   succeeds if the variable's current modify index matches.
+    /// * `opts` - Optional write options for the request.
+    ///
+    /// # Returns
+    /// A `Result` indicating success or failure of the operation.
+    pub async fn delete(
+        &self,
+        path: &str,
+        cas: Option<u64>,
+        opts: Option<WriteOptions>,
+    ) -> Result<(), ClientError> {
+        let mut req = self.client.set_request_write_options(
+            self.client
+                .build_request(Method::DELETE, &format!("/v1/var/{}", path)),
+            &opts.unwrap_or_default(),
+        );
+
+        if let Some(cas) = cas {
+            req = req.query(&[("cas", cas)]);
+        }
+        self.send_cas(req, cas).await.map(|_| ())
+    }
+
+    /// Get the variable stored at the specified path.
+    ///
+    /// # Arguments
+    /// * `path` - The path of the variable to retrieve.
+    /// * `opts` - Optional query options for the request.
+    ///
+    /// # Returns
+    /// A `Result` containing the `Variable` object or an error if the request
+    /// fails.
+    pub async fn get(
+        &self,
+        path: &str,
+        opts: Option<QueryOptions>,
+    ) -> Result<Variable, ClientError> {
+        let req = self.client.set_request_query_options(
+            self.client
+                .build_request(Method::GET, &format!("/v1/var/{}", path)),
+            &opts.unwrap_or_default(),
+        );
+        self.client.send_with_response::<Variable>(req).await
+    }
+
+    /// Get the variable stored at the specified path, returning `None` rather
+    /// than an error when it does not exist.
+    ///
+    /// # Arguments
+    /// * `path` - The path of the variable to retrieve.
+    /// * `opts` - Optional query options for the request.
+    ///
+    /// # Returns
+    /// A `Result` containing the `Variable`, `None` if it does not exist, or an
+    /// error if the request fails.
+    pub async fn get_optional(
+        &self,
+        path: &str,
+        opts: Option<QueryOptions>,
+    ) -> Result<Option<Variable>, ClientError> {
+        crate::not_found_to_none(self.get(path, opts).await)
+    }
+
+    /// Get the list of variables in the Nomad cluster. The returned metadata
+    /// does not include the variable items.
+    ///
+    /// # Arguments
+    /// * `prefix` - Optional path prefix used to filter the variables. This
+    ///   overrides any prefix set within `opts`.
+    /// * `opts` - Optional query options for the request.
+    ///
+    /// # Returns
+    /// A `Result` containing a vector of `VariableMetadata` objects or an error
+    /// if the request fails.
+    pub async fn list(
+        &self,
+        prefix: Option<&str>,
+        opts: Option<QueryOptions>,
+    ) -> Result<Vec<VariableMetadata>, ClientError> {
+        let mut opts = opts.unwrap_or_default();
+        if let Some(prefix) = prefix {
+            opts.prefix = Some(prefix.to_string());
+        }
+
+        let req = self
+            .client
+            .set_request_query_options(self.client.build_request(Method::GET, "/v1/vars"), &opts);
+        self.client
+            .send_with_response::<Vec<VariableMetadata>>(req)
+            .await
+    }
+
+    /// Send a variable write request which may include a check-and-set index.
+    /// Nomad responds to a stale index with a 409 status code and the current
+    /// state of the variable, which is mapped to `ClientError::CasConflict`.
+    async fn send_cas(
+        &self,
+        req: RequestBuilder,
+        cas: Option<u64>,
+    ) -> Result<Response, ClientError> {
+        self.client
+            .send(req, async |response| {
+                let status = response.status();
+
+                if status.is_success() {
+                    return Ok(response);
+                }
+
+                let body = response.text().await.map_err(crate::network_error)?;
+
+                match (status, cas) {
+                    (StatusCode::CONFLICT, Some(expected)) => Err(ClientError::CasConflict {
+                        expected,
+                        current: serde_json::from_str::<Variable>(&body)
+                            .ok()
+                            .and_then(|conflict| conflict.modify_index),
+                    }),
+                    _ => Err(crate::server_error(status, body)),
+                }
+            })
+            .await
+    }
+}
@@ -0,0 +1,202 @@
+mod common;
+
+use common::MockNomad;
+use nomad_rs_api::acl_auth_method::{
+    ACL_AUTH_METHOD_TOKEN_LOCALITY_GLOBAL, ACL_AUTH_METHOD_TYPE_OIDC, AuthMethod, AuthMethodConfig,
+    OIDCAuthURLRequest, OIDCCompleteAuthRequest,
+};
+use serde_json::json;
+use std::collections::HashMap;
+
+#[test]
+fn oidc_auth_method_round_trips() {
+    let mut method = AuthMethod::new(
+        "okta".to_string(),
+        ACL_AUTH_METHOD_TYPE_OIDC.to_string(),
+        ACL_AUTH_METHOD_TOKEN_LOCALITY_GLOBAL.to_string(),
+        time::Duration::hours(1),
+    );
+    method.config = Some(AuthMethodConfig {
+        oidc_discovery_url: Some("https://example.okta.com".to_string()),
+        oidc_client_id: Some("nomad".to_string()),
+        oidc_scopes: Some(vec!["groups".to_string()]),
+        bound_audiences: Some(vec!["nomad".to_string()]),
+        allowed_redirect_uris: Some(vec!["http://localhost:4649/oidc/callback".to_string()]),
+        claim_mappings: Some(HashMap::from([
+            ("email".to_string(), "email".to_string()),
+            ("name".to_string(), "full_name".to_string()),
+        ])),
+        list_claim_mappings: Some(HashMap::from([("groups".to_string(), "roles".to_string())])),
+        ..AuthMethodConfig::default()
+    });
+
+    let body = serde_json::to_value(&method).unwrap();
+    assert_eq!(body["MaxTokenTTL"], json!(3_600_000_000_000i64));
+    assert_eq!(body["Type"], "OIDC");
+    assert_eq!(
+        body["Config"]["OIDCDiscoveryURL"],
+        "https://example.okta.com"
+    );
+    assert_eq!(
+        body["Config"]["AllowedRedirectURIs"][0],
+        "http://localhost:4649/oidc/callback"
+    );
+    assert_eq!(
+        body["Config"]["ClaimMappings"],
+        json!({"email": "email", "name": "full_name"})
+    );
+    assert_eq!(
+        body["Config"]["ListClaimMappings"],
+        json!({"groups": "roles"})
+    );
+
+    let decoded: AuthMethod = serde_json::from_value(body).unwrap();
+    assert_eq!(decoded.max_token_ttl, time::Duration::hours(1));
+    let config = decoded.config.unwrap();
+    assert_eq!(config.claim_mappings.unwrap()["name"], "full_name");
+    assert_eq!(config.list_claim_mappings.unwrap()["groups"], "roles");
+    assert_eq!(config.oidc_scopes.unwrap(), vec!["groups"]);
+}
+
+fn login_token() -> serde_json::Value {
+    json!({
+        "AccessorID": "6d1cd9a5-5a47-4b1c-9d0c-2f3e8f1a7b2c",
+        "SecretID": "0e2c4a5b-2b7f-4b5f-8d5a-9d1c6f3e2a7b",
+        "Name": "OIDC-okta",
+        "Type": "client",
+        "Policies": null,
+        "Roles": [{"ID": "8e2b3c4d-1a2b-4c5d-9e8f-7a6b5c4d3e2f", "Name": "engineering"}],
+        "Global": true,
+        "CreateTime": "2024-01-02T15:04:05Z",
+        "ExpirationTime": "2024-01-02T16:04:05Z",
+        "ExpirationTTL": 3_600_000_000_000i64,
+        "CreateIndex": 30,
+        "ModifyIndex": 30,
+    })
+}
+
+/// Assert that no request carried an ACL token, as the login endpoints are
+/// used before the caller has one.
+async fn assert_no_token_sent(nomad: &MockNomad) {
+    for request in nomad.received_requests().await {
+        assert!(request.headers.get("X-Nomad-Token").is_none());
+    }
+}
+
+#[tokio::test]
+async fn oidc_auth_url_sends_method_and_nonce() {
+    let nomad = MockNomad::start().await;
+    nomad
+        .expect_post("/v1/acl/oidc/auth-url")
+        .with_json(json!({
+            "AuthMethodName": "okta",
+            "RedirectURI": "http://localhost:4649/oidc/callback",
+            "ClientNonce": "fpSPuaodKevKfDU3IeXa",
+        }))
+        .returns_json(json!({"AuthURL": "https://example.okta.com/oauth2/v1/authorize"}))
+        .await;
+
+    let request = OIDCAuthURLRequest::new(
+        "okta".to_string(),
+        "http://localhost:4649/oidc/callback".to_string(),
+        "fpSPuaodKevKfDU3IeXa".to_string(),
+    );
+    let response = nomad
+        .client()
+        .acl_auth_method()
+        .acl_oidc_auth_url(&request, None)
+        .await
+        .unwrap();
+
+    assert_eq!(
+        response.auth_url,
+        "https://example.okta.com/oauth2/v1/authorize"
+    );
+    assert_no_token_sent(&nomad).await;
+}
+
+#[tokio::test]
+async fn oidc_complete_auth_returns_token() {
+    let nomad = MockNomad::start().await;
+    nomad
+        .expect_post("/v1/acl/oidc/complete-auth")
+        .with_json(json!({
+            "AuthMethodName": "okta",
+            "ClientNonce": "fpSPuaodKevKfDU3IeXa",
+            "State": "st_1234",
+            "Code": "code_5678",
+            "RedirectURI": "http://localhost:4649/oidc/callback",
+        }))
+        .returns_json(login_token())
+        .await;
+
+    let request = OIDCCompleteAuthRequest {
+        auth_method_name: "okta".to_string(),
+        client_nonce: "fpSPuaodKevKfDU3IeXa".to_string(),
+        state: "st_1234".to_string(),
+        code: "code_5678".to_string(),
+        redirect_uri: "http://localhost:4649/oidc/callback".to_string(),
+    };
+    let token = nomad
+        .client()
+        .acl_auth_method()
+        .acl_oidc_complete_auth(&request, None)
+        .await
+        .unwrap();
+
+    assert_eq!(token.name.as_deref(), Some("OIDC-okta"));
+    assert_eq!(token.expiration_ttl, Some(time::Duration::hours(1)));
+    assert_no_token_sent(&nomad).await;
+}
+
+#[tokio::test]
+async fn acl_login_sends_method_and_jwt() {
+    let nomad = MockNomad::start().await;
+    nomad
+        .expect_post("/v1/acl/login")
+        .with_json(json!({
+            "AuthMethodName": "github-actions",
+            "LoginToken": "eyJhbGciOiJSUzI1NiJ9.e30.c2lnbmF0dXJl",
+        }))
+        .returns_json(login_token())
+        .await;
+
+    let token = nomad
+        .client()
+        .acl_auth_method()
+        .acl_login(
+            "github-actions",
+            "eyJhbGciOiJSUzI1NiJ9.e30.c2lnbmF0dXJl",
+            None,
+        )
+        .await
+        .unwrap();
+
+    assert!(token.global);
+    assert_no_token_sent(&nomad).await;
+}
+
+#[test]
+fn auth_method_deserializes_go_duration_ttl() {
+    // The auth method as returned by GET /v1/acl/auth-method/:name.
+    let method: AuthMethod = serde_json::from_value(json!({
+        "Name": "okta",
+        "Type": "OIDC",
+        "TokenLocality": "global",
+        "TokenNameFormat": "${auth_method_type}-${auth_method_name}",
+        "MaxTokenTTL": "72h0m0s",
+        "Default": true,
+        "Config": {
+            "OIDCDiscoveryURL": "https://example.okta.com",
+            "OIDCClientID": "nomad",
+            "BoundAudiences": ["nomad"],
+            "AllowedRedirectURIs": ["http://localhost:4649/oidc/callback"],
+        },
+        "CreateTime": "2024-01-02T15:04:05.123456789Z",
+        "ModifyTime": "2024-01-02T15:04:05.123456789Z",
+        "CreateIndex": 12,
+        "ModifyIndex": 12,
+    }))
+    .unwrap();
+    assert_eq!(method.max_token_ttl, time::Duration::hours(72));
+}
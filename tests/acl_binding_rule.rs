@@ -0,0 +1,158 @@
+mod common;
+
+use common::MockNomad;
+use nomad_rs_api::ClientError;
+use nomad_rs_api::acl_binding_rule::{ACL_BINDING_RULE_BIND_TYPE_ROLE, ACLBindingRule};
+use serde_json::json;
+
+const RULE_ID: &str = "5f3c2b1a-9d8e-4f7a-b6c5-d4e3f2a1b0c9";
+
+fn rule_json() -> serde_json::Value {
+    json!({
+        "ID": RULE_ID,
+        "Description": "Bind engineers to the engineering role",
+        "AuthMethod": "okta",
+        "Selector": "engineering in list.roles",
+        "BindType": "role",
+        "BindName": "engineering",
+        "CreateIndex": 50,
+        "ModifyIndex": 52,
+    })
+}
+
+fn rule() -> ACLBindingRule {
+    let mut rule = ACLBindingRule::new(
+        "okta".to_string(),
+        ACL_BINDING_RULE_BIND_TYPE_ROLE.to_string(),
+        Some("engineering".to_string()),
+    );
+    rule.selector = Some("engineering in list.roles".to_string());
+    rule
+}
+
+#[tokio::test]
+async fn create_posts_binding_rule() {
+    let nomad = MockNomad::start().await;
+    nomad
+        .expect_post("/v1/acl/binding-rule")
+        .with_json(json!({
+            "ID": null,
+            "Description": null,
+            "AuthMethod": "okta",
+            "Selector": "engineering in list.roles",
+            "BindType": "role",
+            "BindName": "engineering",
+            "CreateIndex": null,
+            "ModifyIndex": null,
+        }))
+        .returns_json(rule_json())
+        .await;
+
+    let created = nomad
+        .client()
+        .acl_binding_rule()
+        .create(&rule(), None)
+        .await
+        .unwrap();
+    assert_eq!(created.id.as_deref(), Some(RULE_ID));
+    assert_eq!(created.bind_name.as_deref(), Some("engineering"));
+}
+
+#[tokio::test]
+async fn create_rejects_rule_with_id() {
+    let nomad = MockNomad::start().await;
+    let mut rule = rule();
+    rule.id = Some(RULE_ID.to_string());
+
+    let err = nomad
+        .client()
+        .acl_binding_rule()
+        .create(&rule, None)
+        .await
+        .unwrap_err();
+    assert!(matches!(err, ClientError::InvalidInputError(_)));
+    assert!(nomad.received_requests().await.is_empty());
+}
+
+#[tokio::test]
+async fn update_posts_rule_to_its_id() {
+    let nomad = MockNomad::start().await;
+    nomad
+        .expect_post(&format!("/v1/acl/binding-rule/{}", RULE_ID))
+        .returns_json(rule_json())
+        .await;
+
+    let client = nomad.client();
+    let mut rule = rule();
+    rule.id = Some(RULE_ID.to_string());
+    let updated = client.acl_binding_rule().update(&rule, None).await.unwrap();
+    assert_eq!(updated.modify_index, Some(52));
+
+    let requests = nomad.received_requests().await;
+    let body: serde_json::Value = serde_json::from_slice(&requests[0].body).unwrap();
+    assert_eq!(body["ID"], RULE_ID);
+
+    rule.id = None;
+    let err = client
+        .acl_binding_rule()
+        .update(&rule, None)
+        .await
+        .unwrap_err();
+    assert!(matches!(err, ClientError::InvalidInputError(_)));
+}
+
+#[tokio::test]
+async fn get_returns_binding_rule() {
+    let nomad = MockNomad::start().await;
+    nomad
+        .expect_get(&format!("/v1/acl/binding-rule/{}", RULE_ID))
+        .returns_json(rule_json())
+        .await;
+
+    let rule = nomad
+        .client()
+        .acl_binding_rule()
+        .get(RULE_ID, None)
+        .await
+        .unwrap();
+    assert_eq!(rule.auth_method, "okta");
+    assert_eq!(rule.bind_type, ACL_BINDING_RULE_BIND_TYPE_ROLE);
+    assert_eq!(rule.selector.as_deref(), Some("engineering in list.roles"));
+}
+
+#[tokio::test]
+async fn list_returns_binding_rule_stubs() {
+    let nomad = MockNomad::start().await;
+    nomad
+        .expect_get("/v1/acl/binding-rules")
+        .returns_json(json!([{
+            "ID": RULE_ID,
+            "Description": "Bind engineers to the engineering role",
+            "AuthMethod": "okta",
+            "Hash": "Ck4mPbM0zRk8c5fH0qZb3Yy1nQ2wL7sT9vX6uE4aD1g=",
+            "CreateIndex": 50,
+            "ModifyIndex": 52,
+        }]))
+        .await;
+
+    let rules = nomad.client().acl_binding_rule().list(None).await.unwrap();
+    assert_eq!(rules.len(), 1);
+    assert_eq!(rules[0].id, RULE_ID);
+    assert_eq!(rules[0].auth_method, "okta");
+}
+
+#[tokio::test]
+async fn delete_deletes_rule_by_id() {
+    let nomad = MockNomad::start().await;
+    nomad
+        .expect_delete(&format!("/v1/acl/binding-rule/{}", RULE_ID))
+        .returns_json(json!(null))
+        .await;
+
+    nomad
+        .client()
+        .acl_binding_rule()
+        .delete(RULE_ID, None)
+        .await
+        .unwrap();
+}
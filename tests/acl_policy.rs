@@ -0,0 +1,32 @@
+mod common;
+
+use common::MockNomad;
+use nomad_rs_api::acl_policy::ACLPolicy;
+use nomad_rs_api::option::WriteOptions;
+use wiremock::ResponseTemplate;
+
+#[tokio::test]
+async fn create_sends_idempotency_token() {
+    let nomad = MockNomad::start().await;
+    nomad
+        .expect_post("/v1/acl/policy/readonly")
+        .with_query("idempotency_token", "create-readonly-1")
+        .returns(ResponseTemplate::new(200))
+        .await;
+
+    let policy = ACLPolicy::new(
+        "readonly".to_string(),
+        r#"namespace "default" { policy = "read" }"#.to_string(),
+    );
+    let opts = WriteOptions::new().with_idempotency_token("create-readonly-1".to_string());
+    nomad
+        .client()
+        .acl_policy()
+        .create(&policy, Some(opts))
+        .await
+        .unwrap();
+
+    let requests = nomad.received_requests().await;
+    let body: serde_json::Value = requests[0].body_json().unwrap();
+    assert_eq!(body["Name"], "readonly");
+}
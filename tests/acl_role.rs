@@ -0,0 +1,168 @@
+mod common;
+
+use common::MockNomad;
+use nomad_rs_api::ClientError;
+use nomad_rs_api::acl_role::{ACLRole, ACLRolePolicyLink};
+use serde_json::json;
+
+const ROLE_ID: &str = "8e2b3c4d-1a2b-4c5d-9e8f-7a6b5c4d3e2f";
+
+fn role_json() -> serde_json::Value {
+    json!({
+        "ID": ROLE_ID,
+        "Name": "engineering",
+        "Description": "Engineering team access",
+        "Policies": [{"Name": "deploy"}, {"Name": "read-logs"}],
+        "CreateIndex": 40,
+        "ModifyIndex": 42,
+    })
+}
+
+fn role() -> ACLRole {
+    ACLRole::new(
+        "engineering".to_string(),
+        vec![
+            ACLRolePolicyLink::new("deploy".to_string()),
+            ACLRolePolicyLink::new("read-logs".to_string()),
+        ],
+    )
+}
+
+#[tokio::test]
+async fn create_posts_role_with_policy_links() {
+    let nomad = MockNomad::start().await;
+    nomad
+        .expect_post("/v1/acl/role")
+        .with_json(json!({
+            "ID": null,
+            "Name": "engineering",
+            "Description": null,
+            "Policies": [{"Name": "deploy"}, {"Name": "read-logs"}],
+            "CreateIndex": null,
+            "ModifyIndex": null,
+        }))
+        .returns_json(role_json())
+        .await;
+
+    let created = nomad
+        .client()
+        .acl_role()
+        .create(&role(), None)
+        .await
+        .unwrap();
+    assert_eq!(created.id.as_deref(), Some(ROLE_ID));
+    assert_eq!(created.policies[1].name, "read-logs");
+}
+
+#[tokio::test]
+async fn create_rejects_role_with_id() {
+    let nomad = MockNomad::start().await;
+    let mut role = role();
+    role.id = Some(ROLE_ID.to_string());
+
+    let err = nomad
+        .client()
+        .acl_role()
+        .create(&role, None)
+        .await
+        .unwrap_err();
+    assert!(matches!(err, ClientError::InvalidInputError(_)));
+    assert!(nomad.received_requests().await.is_empty());
+}
+
+#[tokio::test]
+async fn update_posts_role_to_its_id() {
+    let nomad = MockNomad::start().await;
+    nomad
+        .expect_post(&format!("/v1/acl/role/{}", ROLE_ID))
+        .returns_json(role_json())
+        .await;
+
+    let mut role = role();
+    role.id = Some(ROLE_ID.to_string());
+    let updated = nomad.client().acl_role().update(&role, None).await.unwrap();
+    assert_eq!(updated.modify_index, Some(42));
+
+    let requests = nomad.received_requests().await;
+    let body: serde_json::Value = serde_json::from_slice(&requests[0].body).unwrap();
+    assert_eq!(body["ID"], ROLE_ID);
+    assert_eq!(
+        body["Policies"],
+        json!([{"Name": "deploy"}, {"Name": "read-logs"}])
+    );
+}
+
+#[tokio::test]
+async fn get_and_get_by_name_return_role() {
+    let nomad = MockNomad::start().await;
+    nomad
+        .expect_get(&format!("/v1/acl/role/{}", ROLE_ID))
+        .returns_json(role_json())
+        .await;
+    nomad
+        .expect_get("/v1/acl/role/name/engineering")
+        .returns_json(role_json())
+        .await;
+
+    let client = nomad.client();
+    let role = client.acl_role().get(ROLE_ID, None).await.unwrap();
+    assert_eq!(role.name, "engineering");
+    assert_eq!(role.description.as_deref(), Some("Engineering team access"));
+
+    let role = client
+        .acl_role()
+        .get_by_name("engineering", None)
+        .await
+        .unwrap();
+    assert_eq!(role.id.as_deref(), Some(ROLE_ID));
+}
+
+#[tokio::test]
+async fn list_returns_role_stubs() {
+    let nomad = MockNomad::start().await;
+    let mut stub = role_json();
+    stub["Hash"] = json!("k8Oq0TtJ3VQ1tC3pZ3n0QvVw4sYJ1RkK2m4Wb1Hc5sQ=");
+    nomad
+        .expect_get("/v1/acl/roles")
+        .returns_json(json!([stub]))
+        .await;
+
+    let roles = nomad.client().acl_role().list(None).await.unwrap();
+    assert_eq!(roles.len(), 1);
+    assert_eq!(roles[0].id, ROLE_ID);
+    assert_eq!(roles[0].policies[0].name, "deploy");
+    assert!(roles[0].hash.is_some());
+}
+
+#[tokio::test]
+async fn delete_deletes_role_by_id() {
+    let nomad = MockNomad::start().await;
+    nomad
+        .expect_delete(&format!("/v1/acl/role/{}", ROLE_ID))
+        .returns_json(json!(null))
+        .await;
+
+    nomad
+        .client()
+        .acl_role()
+        .delete(ROLE_ID, None)
+        .await
+        .unwrap();
+}
+
+#[tokio::test]
+async fn empty_role_id_is_rejected() {
+    let nomad = MockNomad::start().await;
+    let client = nomad.client();
+
+    let err = client.acl_role().get("", None).await.unwrap_err();
+    assert!(matches!(err, ClientError::InvalidInputError(_)));
+    let err = client.acl_role().delete("", None).await.unwrap_err();
+    assert!(matches!(err, ClientError::InvalidInputError(_)));
+    let mut role = role();
+    role.id = Some(String::new());
+    let err = client.acl_role().update(&role, None).await.unwrap_err();
+    assert!(matches!(err, ClientError::InvalidInputError(_)));
+
+    assert!(nomad.received_requests().await.is_empty());
+}
@@ -0,0 +1,123 @@
+mod common;
+
+use common::MockNomad;
+use nomad_rs_api::Config;
+use nomad_rs_api::acl_token::{ACLToken, ACLTokenBootstrapRequest};
+use serde_json::json;
+
+fn token(expiration_ttl: serde_json::Value) -> serde_json::Value {
+    json!({
+        "AccessorID": "6d1cd9a5-5a47-4b1c-9d0c-2f3e8f1a7b2c",
+        "SecretID": "0e2c4a5b-2b7f-4b5f-8d5a-9d1c6f3e2a7b",
+        "Name": "deployer",
+        "Type": "client",
+        "Policies": ["deploy"],
+        "Roles": null,
+        "Global": false,
+        "CreateTime": "2024-01-02T15:04:05Z",
+        "ExpirationTime": "2024-01-02T16:04:05Z",
+        "ExpirationTTL": expiration_ttl,
+        "CreateIndex": 30,
+        "ModifyIndex": 30,
+    })
+}
+
+#[test]
+fn expiration_ttl_deserializes_from_nanoseconds() {
+    let token: ACLToken = serde_json::from_value(token(json!(3600000000000i64))).unwrap();
+    assert_eq!(token.expiration_ttl, Some(time::Duration::hours(1)));
+
+    let body = serde_json::to_value(&token).unwrap();
+    assert_eq!(body["ExpirationTTL"], json!(3600000000000i64));
+}
+
+#[test]
+fn expiration_ttl_deserializes_from_go_duration() {
+    for (ttl, expected) in [
+        ("1h0m0s", Some(time::Duration::hours(1))),
+        ("1m30.5s", Some(time::Duration::milliseconds(90_500))),
+        ("", None),
+    ] {
+        let token: ACLToken = serde_json::from_value(token(json!(ttl))).unwrap();
+        assert_eq!(token.expiration_ttl, expected, "{ttl}");
+    }
+
+    assert!(serde_json::from_value::<ACLToken>(token(json!("1 hour"))).is_err());
+}
+
+#[test]
+fn oversized_expiration_ttl_fails_to_serialize() {
+    let mut token: ACLToken = serde_json::from_value(token(json!(null))).unwrap();
+    token.expiration_ttl = Some(time::Duration::MAX);
+    assert!(serde_json::to_value(&token).is_err());
+}
+
+#[test]
+fn expiration_ttl_may_be_unset() {
+    let token: ACLToken = serde_json::from_value(token(json!(null))).unwrap();
+    assert_eq!(token.expiration_ttl, None);
+
+    let mut body = self::token(json!(null));
+    body.as_object_mut().unwrap().remove("ExpirationTTL");
+    let token: ACLToken = serde_json::from_value(body).unwrap();
+    assert_eq!(token.expiration_ttl, None);
+}
+
+#[test]
+fn debug_redacts_secret_id() {
+    let token: ACLToken = serde_json::from_value(token(json!(null))).unwrap();
+    let debug = format!("{token:?}");
+
+    assert!(
+        !debug.contains("0e2c4a5b-2b7f-4b5f-8d5a-9d1c6f3e2a7b"),
+        "{debug}"
+    );
+    assert!(debug.contains("secret_id: \"***\""), "{debug}");
+    assert!(
+        debug.contains("6d1cd9a5-5a47-4b1c-9d0c-2f3e8f1a7b2c"),
+        "{debug}"
+    );
+}
+
+#[test]
+fn debug_redacts_bootstrap_secret_and_config_token() {
+    let secret = "0e2c4a5b-2b7f-4b5f-8d5a-9d1c6f3e2a7b";
+    let request = ACLTokenBootstrapRequest::new(secret.to_string());
+    assert!(!format!("{request:?}").contains(secret));
+
+    let config = Config {
+        token: Some(secret.to_string()),
+        ..Config::default()
+    };
+    let debug = format!("{config:?}");
+    assert!(!debug.contains(secret), "{debug}");
+    assert!(debug.contains("token: Some(\"***\")"), "{debug}");
+}
+
+#[tokio::test]
+async fn list_filtered_filters_on_global_and_policy() {
+    let nomad = MockNomad::start().await;
+    nomad
+        .expect_get("/v1/acl/tokens")
+        .with_query("filter", "Global == true")
+        .returns_json(json!([]))
+        .await;
+    nomad
+        .expect_get("/v1/acl/tokens")
+        .with_query(
+            "filter",
+            r#"Global == false and Policies contains "deploy""#,
+        )
+        .returns_json(json!([]))
+        .await;
+
+    let client = nomad.client();
+    let tokens = client.acl_token().list_global(None).await.unwrap();
+    assert!(tokens.is_empty());
+
+    client
+        .acl_token()
+        .list_filtered(Some(false), Some("deploy"), None)
+        .await
+        .unwrap();
+}
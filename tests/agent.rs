@@ -0,0 +1,240 @@
+mod common;
+
+use common::MockNomad;
+use nomad_rs_api::ClientError;
+use nomad_rs_api::agent::VersionFeature;
+use serde_json::json;
+use wiremock::ResponseTemplate;
+
+#[tokio::test]
+async fn join_repeats_address_param() {
+    let nomad = MockNomad::start().await;
+    nomad
+        .expect_post("/v1/agent/join")
+        .returns_json(json!({"num_joined": 2, "error": ""}))
+        .await;
+
+    let response = nomad
+        .client()
+        .agent()
+        .join(&["10.0.1.5:4648", "10.0.2.5:4648"])
+        .await
+        .unwrap();
+    assert_eq!(response.num_joined, 2);
+    assert!(response.error.is_empty());
+
+    let requests = nomad.received_requests().await;
+    let addresses: Vec<_> = requests[0]
+        .url
+        .query_pairs()
+        .filter(|(name, _)| name == "address")
+        .map(|(_, value)| value.into_owned())
+        .collect();
+    assert_eq!(addresses, vec!["10.0.1.5:4648", "10.0.2.5:4648"]);
+}
+
+#[tokio::test]
+async fn force_leave_posts_node() {
+    let nomad = MockNomad::start().await;
+    nomad
+        .expect_post("/v1/agent/force-leave")
+        .with_query("node", "nomad-3.global")
+        .returns(ResponseTemplate::new(200))
+        .await;
+
+    nomad
+        .client()
+        .agent()
+        .force_leave("nomad-3.global")
+        .await
+        .unwrap();
+}
+
+#[cfg(feature = "stream")]
+#[tokio::test]
+async fn monitor_streams_decodable_frames() {
+    use futures_util::StreamExt;
+    use nomad_rs_api::agent::MonitorLogDecoder;
+
+    let nomad = MockNomad::start().await;
+    nomad
+        .expect_get("/v1/agent/monitor")
+        .with_query("log_level", "warn")
+        .with_query("node_id", "fb2170a8-257d-3c64-b14d-bc06cc94e34c")
+        .returns(
+            wiremock::ResponseTemplate::new(200).set_body_string(concat!(
+                r#"{"Data": "W0lORk9dIGFnZW50OiBzdGFy"}"#,
+                "\n{}\n",
+                r#"{"Data": "dGVkCltXQVJOXSBhZ2VudDogc2xvdwo="}"#,
+            )),
+        )
+        .await;
+
+    let stream = nomad
+        .client()
+        .agent()
+        .monitor(
+            "warn",
+            Some("fb2170a8-257d-3c64-b14d-bc06cc94e34c"),
+            None,
+            None,
+        )
+        .await
+        .unwrap();
+    let mut stream = std::pin::pin!(stream);
+    let mut decoder = MonitorLogDecoder::new();
+    let mut lines = Vec::new();
+    while let Some(chunk) = stream.next().await {
+        lines.extend(decoder.decode(&chunk.unwrap()).unwrap());
+    }
+
+    assert_eq!(lines, vec!["[INFO] agent: started", "[WARN] agent: slow"]);
+}
+
+fn agent_self(version: serde_json::Value, build_tag: &str) -> serde_json::Value {
+    json!({
+        "config": {
+            "Region": "global",
+            "Datacenter": "dc1",
+            "NodeName": "nomad-1",
+            "Version": version,
+            "Server": {"Enabled": true, "BootstrapExpect": 3},
+        },
+        "member": {
+            "Name": "nomad-1.global",
+            "Addr": "10.0.1.5",
+            "Port": 4648,
+            "Tags": {
+                "build": build_tag,
+                "dc": "dc1",
+                "region": "global",
+                "role": "nomad",
+                "vsn": "1",
+            },
+            "Status": "alive",
+            "ProtocolMin": 1,
+            "ProtocolMax": 5,
+            "ProtocolCur": 2,
+            "DelegateMin": 2,
+            "DelegateMax": 5,
+            "DelegateCur": 4,
+        },
+        "stats": {
+            "nomad": {"leader": "true", "server": "true", "known_regions": "1"},
+            "runtime": {"arch": "amd64", "goroutines": "112", "version": "go1.22.5"},
+        },
+    })
+}
+
+#[tokio::test]
+async fn version_parses_agent_self() {
+    let nomad = MockNomad::start().await;
+    nomad
+        .expect_get("/v1/agent/self")
+        .returns_json(agent_self(
+            json!({
+                "Revision": "0f34c85ee63f6472bd2db1e2487611f4b176c70c",
+                "Version": "1.9.0",
+                "VersionPrerelease": "beta.1",
+                "VersionMetadata": "",
+                "BuildDate": "2024-10-09T18:40:56Z",
+            }),
+            "1.9.0-beta.1:0f34c85e",
+        ))
+        .await;
+
+    let version = nomad.client().agent().version(None).await.unwrap();
+
+    assert_eq!(version.version, "1.9.0-beta.1");
+    assert_eq!(
+        version.revision.as_deref(),
+        Some("0f34c85ee63f6472bd2db1e2487611f4b176c70c")
+    );
+    assert_eq!(version.build_date.as_deref(), Some("2024-10-09T18:40:56Z"));
+    assert_eq!(version.numeric(), Some((1, 9, 0)));
+    assert!(version.supports(VersionFeature::Variables));
+    assert!(version.supports(VersionFeature::NodePools));
+}
+
+#[tokio::test]
+async fn version_falls_back_to_build_tag() {
+    let nomad = MockNomad::start().await;
+    nomad
+        .expect_get("/v1/agent/self")
+        .returns_json(agent_self(json!(null), "1.5.6"))
+        .await;
+
+    let version = nomad.client().agent().version(None).await.unwrap();
+
+    assert_eq!(version.version, "1.5.6");
+    assert_eq!(version.revision, None);
+    assert!(version.supports(VersionFeature::Variables));
+    assert!(!version.supports(VersionFeature::NodePools));
+}
+
+#[tokio::test]
+async fn missing_version_is_incomplete_response() {
+    let mut agent = agent_self(json!(null), "");
+    agent["member"]["Tags"]
+        .as_object_mut()
+        .unwrap()
+        .remove("build");
+    let nomad = MockNomad::start().await;
+    nomad.expect_get("/v1/agent/self").returns_json(agent).await;
+
+    let err = nomad.client().agent().version(None).await.unwrap_err();
+    assert!(
+        matches!(err, ClientError::IncompleteResponse(_)),
+        "unexpected error: {err:?}"
+    );
+}
+
+#[tokio::test]
+async fn host_returns_host_data() {
+    let nomad = MockNomad::start().await;
+    nomad
+        .expect_get("/v1/agent/host")
+        .with_query("node_id", "fb2170a8-257d-3c64-b14d-bc06cc94e34c")
+        .returns_json(json!({
+            "AgentID": "fb2170a8-257d-3c64-b14d-bc06cc94e34c",
+            "HostData": {
+                "OS": "Linux nomad-client-1 6.1.0-18-amd64 #1 SMP PREEMPT_DYNAMIC x86_64",
+                "Network": [
+                    {"name": "lo", "flags": "up|loopback", "addrs": "127.0.0.1/8 ::1/128"},
+                    {"name": "eth0", "flags": "up|broadcast|multicast", "addrs": "10.0.1.7/24"},
+                ],
+                "ResolvConf": "nameserver 10.0.0.2\nsearch ec2.internal\n",
+                "Hosts": "127.0.0.1 localhost\n",
+                "Environment": {"HOME": "/root", "PATH": "/usr/bin:/bin"},
+                "Disk": {
+                    "/opt/nomad/data": {"DiskMB": 51175, "UsedMB": 12041},
+                },
+            },
+        }))
+        .await;
+
+    let host = nomad
+        .client()
+        .agent()
+        .host(Some("fb2170a8-257d-3c64-b14d-bc06cc94e34c"), None, None)
+        .await
+        .unwrap();
+    assert_eq!(host.agent_id, "fb2170a8-257d-3c64-b14d-bc06cc94e34c");
+
+    let data = host.host_data.unwrap();
+    assert!(data.os.starts_with("Linux"));
+    assert_eq!(data.network.as_ref().unwrap()[1]["name"], "eth0");
+    assert!(data.resolv_conf.contains("10.0.0.2"));
+    assert_eq!(data.environment.as_ref().unwrap()["HOME"], "/root");
+    let disk = &data.disk.as_ref().unwrap()["/opt/nomad/data"];
+    assert_eq!(disk.disk_mb, 51175);
+    assert_eq!(disk.used_mb, 12041);
+
+    let requests = nomad.received_requests().await;
+    assert!(
+        !requests[0]
+            .url
+            .query_pairs()
+            .any(|(name, _)| name == "server_id")
+    );
+}
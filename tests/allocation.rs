@@ -0,0 +1,643 @@
+mod common;
+
+use common::MockNomad;
+use nomad_rs_api::ClientError;
+use nomad_rs_api::allocation::{AllocatedResources, AllocationStub, ClientStatus, DesiredStatus};
+use serde_json::json;
+use wiremock::ResponseTemplate;
+
+const ALLOC_ID: &str = "a8198d79-cfdb-6593-a999-1e9adabcba2e";
+
+fn alloc_stub(
+    client_status: &str,
+    desired_status: &str,
+    task_states: serde_json::Value,
+) -> AllocationStub {
+    serde_json::from_value(alloc_stub_json(client_status, desired_status, task_states)).unwrap()
+}
+
+fn alloc_stub_json(
+    client_status: &str,
+    desired_status: &str,
+    task_states: serde_json::Value,
+) -> serde_json::Value {
+    json!({
+        "ID": ALLOC_ID,
+        "EvalID": "5456bd7a-9fc0-c0dd-6131-cbee77f57577",
+        "Name": "example.cache[0]",
+        "Namespace": "default",
+        "NodeID": "fb2170a8-257d-3c64-b14d-bc06cc94e34c",
+        "NodeName": "node-1",
+        "JobID": "example",
+        "JobType": "service",
+        "JobVersion": 0,
+        "TaskGroup": "cache",
+        "DesiredStatus": desired_status,
+        "DesiredDescription": "",
+        "ClientStatus": client_status,
+        "ClientDescription": "",
+        "TaskStates": task_states,
+        "PreemptedByAllocation": "",
+        "CreateIndex": 9,
+        "ModifyIndex": 13,
+        "CreateTime": 1700000000000000000i64,
+        "ModifyTime": 1700000001000000000i64,
+    })
+}
+
+#[tokio::test]
+async fn stop_posts_to_allocation() {
+    let nomad = MockNomad::start().await;
+    nomad
+        .expect_post(&format!("/v1/allocation/{}/stop", ALLOC_ID))
+        .with_query("no_shutdown_delay", "true")
+        .returns_json(json!({
+            "EvalID": "5456bd7a-9fc0-c0dd-6131-cbee77f57577",
+            "Index": 54,
+        }))
+        .await;
+
+    let response = nomad
+        .client()
+        .allocation()
+        .stop(ALLOC_ID, true, None)
+        .await
+        .unwrap();
+    assert_eq!(response.eval_id, "5456bd7a-9fc0-c0dd-6131-cbee77f57577");
+    assert_eq!(response.eval_create_index, 54);
+}
+
+#[tokio::test]
+async fn restart_posts_task_name() {
+    let nomad = MockNomad::start().await;
+    nomad
+        .expect_post(&format!("/v1/client/allocation/{}/restart", ALLOC_ID))
+        .with_json(json!({"TaskName": "redis"}))
+        .returns(ResponseTemplate::new(200))
+        .await;
+    nomad
+        .expect_post(&format!("/v1/client/allocation/{}/restart", ALLOC_ID))
+        .with_json(json!({"TaskName": null}))
+        .returns(ResponseTemplate::new(200))
+        .await;
+
+    let client = nomad.client();
+    client
+        .allocation()
+        .restart(ALLOC_ID, Some("redis"), None)
+        .await
+        .unwrap();
+    client
+        .allocation()
+        .restart(ALLOC_ID, None, None)
+        .await
+        .unwrap();
+}
+
+#[tokio::test]
+async fn signal_posts_task_and_signal() {
+    let nomad = MockNomad::start().await;
+    nomad
+        .expect_post(&format!("/v1/client/allocation/{}/signal", ALLOC_ID))
+        .with_json(json!({"Task": "redis", "Signal": "SIGHUP"}))
+        .returns(ResponseTemplate::new(200))
+        .await;
+
+    nomad
+        .client()
+        .allocation()
+        .signal(ALLOC_ID, Some("redis"), "SIGHUP", None)
+        .await
+        .unwrap();
+}
+
+#[tokio::test]
+async fn lifecycle_operations_require_alloc_id_and_signal() {
+    let nomad = MockNomad::start().await;
+    let client = nomad.client();
+    let allocations = client.allocation();
+
+    let err = allocations.stop("", false, None).await.unwrap_err();
+    assert!(matches!(err, ClientError::InvalidInputError(_)));
+    let err = allocations.restart("", None, None).await.unwrap_err();
+    assert!(matches!(err, ClientError::InvalidInputError(_)));
+    let err = allocations
+        .signal("", None, "SIGHUP", None)
+        .await
+        .unwrap_err();
+    assert!(matches!(err, ClientError::InvalidInputError(_)));
+    let err = allocations
+        .signal(ALLOC_ID, None, "", None)
+        .await
+        .unwrap_err();
+    assert!(matches!(err, ClientError::InvalidInputError(_)));
+
+    assert!(nomad.received_requests().await.is_empty());
+}
+
+#[cfg(feature = "stream")]
+#[tokio::test]
+async fn logs_without_follow_returns_full_body() {
+    use futures_util::StreamExt;
+    use nomad_rs_api::allocation::LogType;
+
+    let nomad = MockNomad::start().await;
+    nomad
+        .expect_get(&format!("/v1/client/fs/logs/{}", ALLOC_ID))
+        .with_query("task", "redis")
+        .with_query("type", "stderr")
+        .with_query("follow", "false")
+        .with_query("origin", "start")
+        .returns(ResponseTemplate::new(200).set_body_string(
+            "1:C 01 Jan 2024 00:00:00.000 # Redis is starting\n1:M * Ready to accept connections\n",
+        ))
+        .await;
+
+    let stream = nomad
+        .client()
+        .allocation()
+        .logs(ALLOC_ID, "redis", LogType::Stderr, false, None)
+        .await
+        .unwrap();
+    let mut logs = Vec::new();
+    let mut stream = std::pin::pin!(stream);
+    while let Some(chunk) = stream.next().await {
+        logs.extend_from_slice(&chunk.unwrap());
+    }
+
+    assert_eq!(
+        String::from_utf8(logs).unwrap(),
+        "1:C 01 Jan 2024 00:00:00.000 # Redis is starting\n1:M * Ready to accept connections\n"
+    );
+}
+#[cfg(feature = "stream")]
+#[tokio::test]
+async fn logs_require_allocation_id() {
+    use nomad_rs_api::allocation::LogType;
+
+    let nomad = MockNomad::start().await;
+
+    let result = nomad
+        .client()
+        .allocation()
+        .logs("", "redis", LogType::Stdout, false, None)
+        .await;
+    assert!(matches!(result, Err(ClientError::InvalidInputError(_))));
+    assert!(nomad.received_requests().await.is_empty());
+}
+
+#[cfg(feature = "exec")]
+#[tokio::test]
+async fn exec_requires_allocation_id() {
+    let nomad = MockNomad::start().await;
+
+    let result = nomad
+        .client()
+        .allocation()
+        .exec("", "redis", &["/bin/sh"], true, None)
+        .await;
+    assert!(matches!(result, Err(ClientError::InvalidInputError(_))));
+    assert!(nomad.received_requests().await.is_empty());
+}
+
+fn file_info(name: &str, is_dir: bool) -> serde_json::Value {
+    json!({
+        "Name": name,
+        "IsDir": is_dir,
+        "Size": 4096,
+        "FileMode": if is_dir { "drwxrwxrwx" } else { "-rw-r--r--" },
+        "ModTime": "2024-01-02T15:04:05.123456789Z",
+        "ContentType": if is_dir { "" } else { "text/plain" },
+    })
+}
+
+#[tokio::test]
+async fn fs_operations_encode_path() {
+    let nomad = MockNomad::start().await;
+    let path = "/alloc/data/my dir/a&b=c.txt";
+    nomad
+        .expect_get(&format!("/v1/client/fs/ls/{}", ALLOC_ID))
+        .with_query("path", "/alloc/data/my dir")
+        .returns_json(json!([file_info("a&b=c.txt", false)]))
+        .await;
+    nomad
+        .expect_get(&format!("/v1/client/fs/stat/{}", ALLOC_ID))
+        .with_query("path", path)
+        .returns_json(file_info("a&b=c.txt", false))
+        .await;
+    nomad
+        .expect_get(&format!("/v1/client/fs/cat/{}", ALLOC_ID))
+        .with_query("path", path)
+        .returns(ResponseTemplate::new(200).set_body_string("contents"))
+        .await;
+
+    let client = nomad.client();
+    let files = client
+        .allocation()
+        .fs_ls(ALLOC_ID, "/alloc/data/my dir", None)
+        .await
+        .unwrap();
+    assert_eq!(files[0].name, "a&b=c.txt");
+    assert!(!files[0].is_dir);
+
+    let info = client
+        .allocation()
+        .fs_stat(ALLOC_ID, path, None)
+        .await
+        .unwrap();
+    assert_eq!(info.size, 4096);
+    assert_eq!(info.content_type.as_deref(), Some("text/plain"));
+
+    let contents = client
+        .allocation()
+        .fs_cat(ALLOC_ID, path, None)
+        .await
+        .unwrap();
+    assert_eq!(&contents[..], b"contents");
+
+    // The reserved characters within the path must be escaped, rather than
+    // splitting it into further query parameters.
+    for request in nomad.received_requests().await {
+        let query = request.url.query().unwrap();
+        assert!(!query.contains(' '), "unescaped query: {query}");
+        assert_eq!(request.url.query_pairs().count(), 2, "query: {query}");
+    }
+}
+
+#[tokio::test]
+async fn fs_operations_require_allocation_id() {
+    let nomad = MockNomad::start().await;
+    let client = nomad.client();
+    let allocation = client.allocation();
+
+    let err = allocation.fs_cat("", "alloc/logs", None).await.unwrap_err();
+    assert!(matches!(err, ClientError::InvalidInputError(_)));
+    let err = allocation.fs_ls("", "alloc/logs", None).await.unwrap_err();
+    assert!(matches!(err, ClientError::InvalidInputError(_)));
+    let err = allocation
+        .fs_stat("", "alloc/logs", None)
+        .await
+        .unwrap_err();
+    assert!(matches!(err, ClientError::InvalidInputError(_)));
+    assert!(nomad.received_requests().await.is_empty());
+}
+
+fn resource_usage(rss: u64, percent: f64) -> serde_json::Value {
+    json!({
+        "CpuStats": {
+            "Measured": ["Throttled Periods", "Throttled Time", "Percent"],
+            "Percent": percent,
+            "SystemMode": 0.0,
+            "ThrottledPeriods": 3,
+            "ThrottledTime": 1500000,
+            "TotalTicks": 22.3,
+            "UserMode": 0.0,
+        },
+        "DeviceStats": null,
+        "MemoryStats": {
+            "Cache": 0,
+            "KernelMaxUsage": 0,
+            "KernelUsage": 0,
+            "MappedFile": 0,
+            "MaxUsage": 7438336,
+            "Measured": ["RSS", "Cache", "Swap", "Usage", "Max Usage"],
+            "RSS": rss,
+            "Swap": 0,
+            "Usage": 7335936,
+        },
+    })
+}
+
+#[tokio::test]
+async fn stats_returns_resource_usage() {
+    let nomad = MockNomad::start().await;
+    nomad
+        .expect_get(&format!("/v1/client/allocation/{}/stats", ALLOC_ID))
+        .returns_json(json!({
+            "ResourceUsage": resource_usage(6651904, 0.97),
+            "Tasks": {
+                "redis": {
+                    "Pids": null,
+                    "ResourceUsage": resource_usage(6651904, 0.97),
+                    "Timestamp": 1700000000123456789i64,
+                },
+            },
+            "Timestamp": 1700000000123456789i64,
+        }))
+        .await;
+
+    let stats = nomad
+        .client()
+        .allocation()
+        .stats(ALLOC_ID, None)
+        .await
+        .unwrap();
+
+    assert_eq!(stats.timestamp, 1700000000123456789);
+    assert_eq!(stats.resource_usage.memory_stats.rss, 6651904);
+    assert_eq!(stats.resource_usage.memory_stats.max_usage, Some(7438336));
+    assert_eq!(stats.resource_usage.cpu_stats.throttled_periods, 3);
+
+    let redis = &stats.tasks["redis"].resource_usage;
+    assert_eq!(redis.cpu_stats.percent, 0.97);
+    assert_eq!(redis.memory_stats.measured.as_deref().unwrap()[0], "RSS");
+}
+#[test]
+fn client_status_parses_known_and_unknown_values() {
+    for (raw, status) in [
+        ("pending", ClientStatus::Pending),
+        ("running", ClientStatus::Running),
+        ("complete", ClientStatus::Complete),
+        ("failed", ClientStatus::Failed),
+        ("lost", ClientStatus::Lost),
+        ("unknown", ClientStatus::Unknown),
+        ("draining", ClientStatus::Other("draining".to_string())),
+    ] {
+        let alloc = alloc_stub(raw, "run", json!(null));
+        assert_eq!(alloc.client_status_enum(), status);
+        assert_eq!(serde_json::to_value(&status).unwrap(), json!(raw));
+        assert_eq!(
+            serde_json::from_value::<ClientStatus>(json!(raw)).unwrap(),
+            status
+        );
+    }
+
+    // Allocations on a disconnected client may reconnect and keep running.
+    assert!(!ClientStatus::Unknown.is_terminal());
+}
+
+#[test]
+fn desired_status_parses_known_and_unknown_values() {
+    for (raw, status) in [
+        ("run", DesiredStatus::Run),
+        ("stop", DesiredStatus::Stop),
+        ("evict", DesiredStatus::Evict),
+        ("paused", DesiredStatus::Other("paused".to_string())),
+    ] {
+        let alloc = alloc_stub("running", raw, json!(null));
+        assert_eq!(alloc.desired_status_enum(), status);
+        assert_eq!(serde_json::to_value(&status).unwrap(), json!(raw));
+        assert_eq!(
+            serde_json::from_value::<DesiredStatus>(json!(raw)).unwrap(),
+            status
+        );
+    }
+}
+
+fn task_event(type_: &str, time: i64) -> serde_json::Value {
+    json!({
+        "Type": type_,
+        "Time": time,
+        "DisplayMessage": "",
+        "Message": "",
+        "Signal": 0,
+        "ExitCode": 0,
+        "DriverError": "",
+        "KillTimeout": 0,
+        "KillError": "",
+        "KillReason": "",
+        "RestartReason": "",
+        "SetupError": "",
+        "DriverMessage": "",
+        "TaskSignalReason": "",
+        "TaskSignal": "",
+        "DownloadError": "",
+        "ValidationError": "",
+        "DiskLimit": 0,
+        "FailedSibling": "",
+        "VaultError": "",
+        "GenericSource": "",
+    })
+}
+
+fn task_state(state: &str, events: serde_json::Value) -> serde_json::Value {
+    json!({
+        "State": state,
+        "Failed": false,
+        "Restarts": 0,
+        "Events": events,
+    })
+}
+
+#[test]
+fn lost_allocation_needs_reschedule() {
+    let alloc = alloc_stub(
+        "lost",
+        "run",
+        json!({
+            "redis": task_state(
+                "dead",
+                json!([task_event("Received", 100), task_event("Started", 200)]),
+            ),
+            "sidecar": task_state("dead", json!([task_event("Killed", 300)])),
+        }),
+    );
+
+    assert!(alloc.is_lost());
+    assert!(alloc.is_terminal());
+    assert!(alloc.needs_reschedule());
+    assert_eq!(alloc.last_task_event().unwrap().type_, "Killed");
+
+    let mut rescheduled = alloc.clone();
+    rescheduled.followup_eval_id = Some("7a3f9b2e-1c4d-4e5f-8a6b-0c1d2e3f4a5b".to_string());
+    assert!(!rescheduled.needs_reschedule());
+}
+
+#[test]
+fn followup_eval_id_deserializes() {
+    let mut body = alloc_stub_json("lost", "run", json!(null));
+    body["FollowupEvalID"] = json!("7a3f9b2e-1c4d-4e5f-8a6b-0c1d2e3f4a5b");
+    let alloc: AllocationStub = serde_json::from_value(body).unwrap();
+
+    assert_eq!(
+        alloc.followup_eval_id.as_deref(),
+        Some("7a3f9b2e-1c4d-4e5f-8a6b-0c1d2e3f4a5b")
+    );
+    assert!(!alloc.needs_reschedule());
+}
+
+#[test]
+fn running_allocation_is_not_terminal() {
+    let alloc = alloc_stub(
+        "running",
+        "run",
+        json!({
+            "redis": task_state(
+                "running",
+                json!([task_event("Started", 200), task_event("Received", 100)]),
+            ),
+        }),
+    );
+
+    assert!(!alloc.is_lost());
+    assert!(!alloc.is_terminal());
+    assert!(!alloc.needs_reschedule());
+    assert_eq!(alloc.last_task_event().unwrap().type_, "Started");
+
+    let stopping = alloc_stub("running", "stop", json!(null));
+    assert!(stopping.is_terminal());
+    assert!(!stopping.needs_reschedule());
+    assert!(stopping.last_task_event().is_none());
+}
+
+#[tokio::test]
+async fn gc_gets_client_allocation_gc() {
+    let nomad = MockNomad::start().await;
+    nomad
+        .expect_get(&format!("/v1/client/allocation/{}/gc", ALLOC_ID))
+        .returns(ResponseTemplate::new(200))
+        .await;
+
+    nomad
+        .client()
+        .allocation()
+        .gc(ALLOC_ID, None)
+        .await
+        .unwrap();
+}
+
+#[tokio::test]
+async fn gc_requires_allocation_id() {
+    let nomad = MockNomad::start().await;
+
+    let err = nomad.client().allocation().gc("", None).await.unwrap_err();
+    assert!(matches!(err, ClientError::InvalidInputError(_)));
+    assert!(nomad.received_requests().await.is_empty());
+}
+
+#[tokio::test]
+async fn checks_returns_check_statuses() {
+    let nomad = MockNomad::start().await;
+    nomad
+        .expect_get(&format!("/v1/client/allocation/{}/checks", ALLOC_ID))
+        .returns_json(json!({
+            "92d3cf6bd0c6ba7c7ce8fe3d12f4c0ef": {
+                "Check": "db-check",
+                "Group": "example.cache[0]",
+                "ID": "92d3cf6bd0c6ba7c7ce8fe3d12f4c0ef",
+                "Mode": "healthiness",
+                "Output": "nomad: tcp ok",
+                "Service": "db",
+                "Status": "success",
+                "StatusCode": 0,
+                "Task": "",
+                "Timestamp": 1698361740,
+            },
+            "a6b2e7c8f1d94b0c8e3f5a7d9c1b2e4f": {
+                "Check": "api-ready",
+                "Group": "example.cache[0]",
+                "ID": "a6b2e7c8f1d94b0c8e3f5a7d9c1b2e4f",
+                "Mode": "readiness",
+                "Output": "nomad: http ok",
+                "Service": "api",
+                "Status": "failure",
+                "StatusCode": 503,
+                "Task": "redis",
+                "Timestamp": 1698361745,
+            },
+        }))
+        .await;
+
+    let checks = nomad
+        .client()
+        .allocation()
+        .checks(ALLOC_ID, None)
+        .await
+        .unwrap();
+
+    assert_eq!(checks.len(), 2);
+    let db = &checks["92d3cf6bd0c6ba7c7ce8fe3d12f4c0ef"];
+    assert_eq!(db.check, "db-check");
+    assert_eq!(db.service, "db");
+    assert_eq!(db.status, "success");
+    assert_eq!(db.mode, "healthiness");
+    assert_eq!(db.output.as_deref(), Some("nomad: tcp ok"));
+    assert_eq!(db.timestamp, 1698361740);
+
+    let api = &checks["a6b2e7c8f1d94b0c8e3f5a7d9c1b2e4f"];
+    assert_eq!(api.status_code, Some(503));
+    assert_eq!(api.task.as_deref(), Some("redis"));
+    assert_eq!(api.mode, "readiness");
+}
+
+#[test]
+fn allocated_resources_deserialize() {
+    let resources: AllocatedResources = serde_json::from_value(json!({
+        "Tasks": {
+            "redis": {
+                "Cpu": {"CpuShares": 500, "ReservedCores": null},
+                "Memory": {"MemoryMB": 256, "MemoryMaxMB": 512},
+                "Networks": null,
+                "Devices": [{
+                    "Vendor": "nvidia",
+                    "Type": "gpu",
+                    "Name": "Tesla T4",
+                    "DeviceIDs": ["GPU-7a1a4f24-0b4a-6a9c-7d5f-3c3b1f2e8a10"],
+                }],
+            },
+        },
+        "Shared": {
+            "DiskMB": 300,
+            "Networks": [{
+                "Mode": "host",
+                "Device": "",
+                "CIDR": "",
+                "IP": "10.0.1.5",
+                "MBits": 0,
+                "DNS": null,
+                "ReservedPorts": null,
+                "DynamicPorts": [{"Label": "db", "Value": 24736, "To": 6379, "HostNetwork": "default"}],
+            }],
+            "Ports": [{"Label": "db", "Value": 24736, "To": 6379, "HostIP": "10.0.1.5"}],
+        },
+    }))
+    .unwrap();
+
+    let redis = &resources.tasks.as_ref().unwrap()["redis"];
+    assert_eq!(redis.cpu.cpu_shares, 500);
+    assert_eq!(redis.memory.memory_mb, 256);
+    assert_eq!(redis.memory.memory_max_mb, 512);
+    assert_eq!(redis.devices.as_ref().unwrap()[0].device_type, "gpu");
+
+    assert_eq!(resources.shared.disk_mb, 300);
+    let port = &resources.shared.ports.as_ref().unwrap()[0];
+    assert_eq!((port.value, port.to), (24736, 6379));
+    assert_eq!(port.host_ip, "10.0.1.5");
+}
+
+#[tokio::test]
+async fn services_returns_allocation_services() {
+    let nomad = MockNomad::start().await;
+    nomad
+        .expect_get(&format!("/v1/allocation/{}/services", ALLOC_ID))
+        .returns_json(json!([
+            {
+                "Address": "10.0.1.5",
+                "AllocID": ALLOC_ID,
+                "CreateIndex": 21,
+                "Datacenter": "dc1",
+                "ID": "_nomad-task-a8198d79-cfdb-6593-a999-1e9adabcba2e-group-cache-db-db",
+                "JobID": "example",
+                "ModifyIndex": 21,
+                "Namespace": "default",
+                "NodeID": "fb2170a8-257d-3c64-b14d-bc06cc94e34c",
+                "Port": 24736,
+                "ServiceName": "db",
+                "Tags": ["primary"],
+            },
+        ]))
+        .await;
+
+    let services = nomad
+        .client()
+        .allocation()
+        .services(ALLOC_ID, None)
+        .await
+        .unwrap();
+
+    assert_eq!(services.len(), 1);
+    assert_eq!(services[0].service_name, "db");
+    assert_eq!(services[0].alloc_id, ALLOC_ID);
+    assert_eq!(services[0].port, 24736);
+    assert_eq!(services[0].tags, vec!["primary"]);
+}
@@ -0,0 +1,39 @@
+#![cfg(feature = "blocking")]
+
+mod common;
+
+use common::MockNomad;
+use nomad_rs_api::blocking::BlockingNomad;
+use nomad_rs_api::{ClientError, Config};
+use serde_json::json;
+
+#[test]
+fn region_list_blocks_on_request() {
+    // The mock server runs on its own runtime, as the blocking client must not
+    // be used from within an async context.
+    let runtime = tokio::runtime::Runtime::new().unwrap();
+    let nomad = runtime.block_on(async {
+        let nomad = MockNomad::start().await;
+        nomad
+            .expect_get("/v1/regions")
+            .returns_json(json!(["eu-west", "us-east"]))
+            .await;
+        nomad
+    });
+
+    let client = BlockingNomad::new(nomad.config()).unwrap();
+    assert_eq!(client.region().list().unwrap(), vec!["eu-west", "us-east"]);
+
+    runtime.block_on(async move { drop(nomad) });
+}
+
+#[test]
+fn new_returns_error_for_invalid_config() {
+    let config = Config {
+        ca_cert_path: Some("/nonexistent/ca.pem".to_string()),
+        ..Config::default()
+    };
+
+    let result = BlockingNomad::new(config);
+    assert!(matches!(result, Err(ClientError::ConfigurationError(_))));
+}
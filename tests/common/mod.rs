@@ -0,0 +1,140 @@
+//! A mock Nomad server used to assert the shape of the requests made by the
+//! client and to control the responses it receives.
+//!
+//! Every expectation must be matched exactly once unless `times` is used, and
+//! is verified when the `MockNomad` is dropped at the end of the test.
+
+#![allow(dead_code)]
+
+use nomad_rs_api::{Config, Nomad};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use wiremock::matchers::{body_json, header, method, path, query_param};
+use wiremock::{Mock, MockBuilder, MockServer, Request, ResponseTemplate};
+
+pub struct MockNomad {
+    server: MockServer,
+}
+
+impl MockNomad {
+    /// Start a mock Nomad server listening on a random local port.
+    pub async fn start() -> Self {
+        Self {
+            server: MockServer::start().await,
+        }
+    }
+
+    /// Get a client pointed at the mock server, using the default region.
+    pub fn client(&self) -> Nomad {
+        Nomad::new(self.config())
+    }
+
+    /// Get the configuration of a client pointed at the mock server, to be
+    /// modified by tests which exercise configuration.
+    pub fn config(&self) -> Config {
+        Config {
+            address: self.server.uri(),
+            ..Config::default()
+        }
+    }
+
+    pub fn expect_get(&self, path: &str) -> Expectation<'_> {
+        self.expect("GET", path)
+    }
+
+    pub fn expect_put(&self, path: &str) -> Expectation<'_> {
+        self.expect("PUT", path)
+    }
+
+    pub fn expect_post(&self, path: &str) -> Expectation<'_> {
+        self.expect("POST", path)
+    }
+
+    pub fn expect_delete(&self, path: &str) -> Expectation<'_> {
+        self.expect("DELETE", path)
+    }
+
+    fn expect(&self, http_method: &str, request_path: &str) -> Expectation<'_> {
+        Expectation {
+            server: &self.server,
+            mock: Mock::given(method(http_method)).and(path(request_path)),
+            times: 1,
+        }
+    }
+
+    /// Get every request received by the mock server, in the order received.
+    pub async fn received_requests(&self) -> Vec<Request> {
+        self.server.received_requests().await.unwrap_or_default()
+    }
+}
+
+/// An expected request, which is mounted on the mock server once its response
+/// has been set.
+pub struct Expectation<'a> {
+    server: &'a MockServer,
+    mock: MockBuilder,
+    times: u64,
+}
+
+impl Expectation<'_> {
+    /// Only match requests with the query parameter set to the value.
+    pub fn with_query(mut self, key: &str, value: &str) -> Self {
+        self.mock = self.mock.and(query_param(key, value));
+        self
+    }
+
+    /// Only match requests with the header set to the value.
+    pub fn with_header(mut self, key: &'static str, value: &str) -> Self {
+        self.mock = self.mock.and(header(key, value));
+        self
+    }
+
+    /// Only match requests with a JSON body equal to the value.
+    pub fn with_json(mut self, body: serde_json::Value) -> Self {
+        self.mock = self.mock.and(body_json(body));
+        self
+    }
+
+    /// Expect the request to be made the given number of times, rather than
+    /// exactly once.
+    pub fn times(mut self, times: u64) -> Self {
+        self.times = times;
+        self
+    }
+
+    /// Respond with a 200 status and the JSON body.
+    pub async fn returns_json(self, body: serde_json::Value) {
+        self.returns(ResponseTemplate::new(200).set_body_json(body))
+            .await
+    }
+
+    /// Respond with the status and a plain text body, as Nomad does for most
+    /// errors.
+    pub async fn returns_status(self, status: u16, body: &str) {
+        self.returns(ResponseTemplate::new(status).set_body_string(body))
+            .await
+    }
+
+    /// Respond with each of the responses in turn, expecting one request per
+    /// response. Any further requests receive the last response.
+    pub async fn returns_sequence(mut self, responses: Vec<ResponseTemplate>) {
+        self.times = responses.len() as u64;
+        let next = AtomicUsize::new(0);
+        self.mock
+            .respond_with(move |_: &Request| {
+                let index = next.fetch_add(1, Ordering::SeqCst);
+                responses[index.min(responses.len() - 1)].clone()
+            })
+            .expect(self.times)
+            .mount(self.server)
+            .await
+    }
+
+    /// Respond with the given response.
+    pub async fn returns(self, response: ResponseTemplate) {
+        self.mock
+            .respond_with(response)
+            .expect(self.times)
+            .mount(self.server)
+            .await
+    }
+}
@@ -0,0 +1,170 @@
+mod common;
+
+use common::MockNomad;
+use nomad_rs_api::ClientError;
+use nomad_rs_api::csi::{CSITopology, CSITopologyRequest, CSIVolume, CSIVolumeCapability};
+use serde_json::json;
+use std::collections::HashMap;
+use wiremock::ResponseTemplate;
+
+fn topology(zone: &str) -> CSITopology {
+    CSITopology {
+        segments: HashMap::from([(
+            "topology.ebs.csi.aws.com/zone".to_string(),
+            zone.to_string(),
+        )]),
+    }
+}
+
+#[tokio::test]
+async fn register_sends_volume_with_topology() {
+    let nomad = MockNomad::start().await;
+    nomad
+        .expect_put("/v1/volume/csi/mysql")
+        .returns(ResponseTemplate::new(200))
+        .await;
+
+    let mut volume = CSIVolume::new(
+        "mysql".to_string(),
+        "mysql data".to_string(),
+        "aws-ebs0".to_string(),
+    );
+    volume.external_id = Some("vol-0b756b75620d63af5".to_string());
+    volume.requested_capabilities = Some(vec![CSIVolumeCapability {
+        access_mode: "single-node-writer".to_string(),
+        attachment_mode: "file-system".to_string(),
+    }]);
+    volume.requested_topologies = Some(CSITopologyRequest {
+        required: Some(vec![topology("us-east-1a")]),
+        preferred: Some(vec![topology("us-east-1a"), topology("us-east-1b")]),
+    });
+    nomad
+        .client()
+        .csi_volume()
+        .register(&volume, None)
+        .await
+        .unwrap();
+
+    let requests = nomad.received_requests().await;
+    let body: serde_json::Value = requests[0].body_json().unwrap();
+    let sent = &body["Volumes"][0];
+    assert_eq!(sent["ID"], "mysql");
+    assert_eq!(sent["PluginID"], "aws-ebs0");
+    assert_eq!(sent["ExternalID"], "vol-0b756b75620d63af5");
+    assert_eq!(
+        sent["RequestedTopologies"]["Required"][0]["Segments"]["topology.ebs.csi.aws.com/zone"],
+        "us-east-1a"
+    );
+    assert_eq!(
+        sent["RequestedTopologies"]["Preferred"][1]["Segments"]["topology.ebs.csi.aws.com/zone"],
+        "us-east-1b"
+    );
+    assert!(sent.get("Allocations").is_none());
+
+    let decoded: CSIVolume = serde_json::from_value(sent.clone()).unwrap();
+    let requested = decoded.requested_topologies.unwrap();
+    assert_eq!(
+        requested.required.unwrap()[0].segments,
+        topology("us-east-1a").segments
+    );
+    assert_eq!(requested.preferred.unwrap().len(), 2);
+    assert_eq!(
+        decoded.requested_capabilities.unwrap()[0].access_mode,
+        "single-node-writer"
+    );
+}
+
+#[tokio::test]
+async fn list_filters_by_plugin_id() {
+    let nomad = MockNomad::start().await;
+    nomad
+        .expect_get("/v1/volumes")
+        .with_query("type", "csi")
+        .with_query("plugin_id", "aws-ebs0")
+        .returns_json(json!([{
+            "ID": "mysql",
+            "Namespace": "default",
+            "Name": "mysql data",
+            "ExternalID": "vol-0b756b75620d63af5",
+            "Topologies": [{"Segments": {"topology.ebs.csi.aws.com/zone": "us-east-1a"}}],
+            "AccessMode": "single-node-writer",
+            "AttachmentMode": "file-system",
+            "CurrentReaders": 0,
+            "CurrentWriters": 1,
+            "Schedulable": true,
+            "PluginID": "aws-ebs0",
+            "Provider": "ebs.csi.aws.com",
+            "ControllerRequired": true,
+            "ControllersHealthy": 1,
+            "ControllersExpected": 1,
+            "NodesHealthy": 3,
+            "NodesExpected": 3,
+            "CreateIndex": 42,
+            "ModifyIndex": 45,
+        }]))
+        .await;
+
+    let volumes = nomad
+        .client()
+        .csi_volume()
+        .list(Some("aws-ebs0"), None)
+        .await
+        .unwrap();
+    assert_eq!(volumes.len(), 1);
+    assert_eq!(volumes[0].plugin_id, "aws-ebs0");
+    assert_eq!(volumes[0].current_writers, 1);
+}
+
+#[tokio::test]
+async fn get_returns_volume() {
+    let nomad = MockNomad::start().await;
+    nomad
+        .expect_get("/v1/volume/csi/mysql")
+        .returns_json(json!({
+            "ID": "mysql",
+            "Name": "mysql data",
+            "ExternalID": "vol-0b756b75620d63af5",
+            "Namespace": "default",
+            "Topologies": [{"Segments": {"topology.ebs.csi.aws.com/zone": "us-east-1a"}}],
+            "AccessMode": "single-node-writer",
+            "AttachmentMode": "file-system",
+            "Schedulable": true,
+            "PluginID": "aws-ebs0",
+            "Provider": "ebs.csi.aws.com",
+            "Capacity": 10737418240i64,
+            "RequestedCapabilities": [{
+                "AccessMode": "single-node-writer",
+                "AttachmentMode": "file-system",
+            }],
+            "Parameters": {"type": "gp3"},
+            "Allocations": [],
+            "CreateIndex": 42,
+            "ModifyIndex": 45,
+        }))
+        .await;
+
+    let volume = nomad
+        .client()
+        .csi_volume()
+        .get("mysql", None)
+        .await
+        .unwrap();
+    assert_eq!(volume.id, "mysql");
+    assert_eq!(volume.capacity, Some(10737418240));
+    assert_eq!(volume.schedulable, Some(true));
+    assert_eq!(volume.parameters.unwrap()["type"], "gp3");
+    assert_eq!(
+        volume.topologies.unwrap()[0].segments,
+        topology("us-east-1a").segments
+    );
+    assert!(volume.allocations.unwrap().is_empty());
+}
+
+#[tokio::test]
+async fn get_requires_volume_id() {
+    let nomad = MockNomad::start().await;
+
+    let err = nomad.client().csi_volume().get("", None).await.unwrap_err();
+    assert!(matches!(err, ClientError::InvalidInputError(_)));
+    assert!(nomad.received_requests().await.is_empty());
+}
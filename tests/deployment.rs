@@ -0,0 +1,257 @@
+mod common;
+
+use common::MockNomad;
+use nomad_rs_api::ClientError;
+use nomad_rs_api::deployment::DEPLOYMENT_STATUS_SUCCESSFUL;
+use serde_json::json;
+use std::time::Duration;
+use wiremock::ResponseTemplate;
+
+const DEPLOYMENT_ID: &str = "70638f62-5c19-193e-30d6-f9d6e689ab8e";
+
+fn deployment_alloc(
+    id: &str,
+    create_index: u64,
+    deployment_status: serde_json::Value,
+) -> serde_json::Value {
+    json!({
+        "ID": id,
+        "EvalID": "5456bd7a-9fc0-c0dd-6131-cbee77f57577",
+        "Name": "example.cache[0]",
+        "Namespace": "default",
+        "NodeID": "fb2170a8-257d-3c64-b14d-bc06cc94e34c",
+        "NodeName": "node-1",
+        "JobID": "example",
+        "JobType": "service",
+        "JobVersion": 1,
+        "TaskGroup": "cache",
+        "DesiredStatus": "run",
+        "DesiredDescription": "",
+        "ClientStatus": "running",
+        "ClientDescription": "Tasks are running",
+        "TaskStates": null,
+        "DeploymentID": DEPLOYMENT_ID,
+        "DeploymentStatus": deployment_status,
+        "FollowupEvalID": "",
+        "PreemptedAllocations": null,
+        "PreemptedByAllocation": "",
+        "CreateIndex": create_index,
+        "ModifyIndex": create_index + 4,
+        "CreateTime": 1700000000000000000i64,
+        "ModifyTime": 1700000001000000000i64,
+    })
+}
+
+#[tokio::test]
+async fn list_allocations_decodes_deployment_status() {
+    let nomad = MockNomad::start().await;
+    nomad
+        .expect_get(&format!("/v1/deployment/allocations/{}", DEPLOYMENT_ID))
+        .returns_json(json!([
+            deployment_alloc(
+                "8ba85cef-f75b-4b2f-8a8b-8e9e1e3a6e0a",
+                20,
+                json!({
+                    "Healthy": true,
+                    "Timestamp": "2024-01-02T15:04:05.123456789Z",
+                    "Canary": false,
+                    "ModifyIndex": 24,
+                }),
+            ),
+            deployment_alloc(
+                "a8198d79-cfdb-6593-a999-1e9adabcba2e",
+                31,
+                json!({
+                    "Healthy": null,
+                    "Timestamp": "0001-01-01T00:00:00Z",
+                    "Canary": true,
+                    "ModifyIndex": 35,
+                }),
+            ),
+        ]))
+        .await;
+
+    let allocs = nomad
+        .client()
+        .deployment()
+        .list_allocations(DEPLOYMENT_ID, None)
+        .await
+        .unwrap();
+
+    // The newest allocation, the canary, is listed first.
+    assert_eq!(allocs.len(), 2);
+    assert_eq!(allocs[0].create_index, 31);
+    assert_eq!(allocs[0].deployment_id.as_deref(), Some(DEPLOYMENT_ID));
+    let canary = allocs[0].deployment_status.as_ref().unwrap();
+    assert!(canary.canary);
+    assert_eq!(canary.healthy, None);
+
+    let healthy = allocs[1].deployment_status.as_ref().unwrap();
+    assert!(!healthy.canary);
+    assert_eq!(healthy.healthy, Some(true));
+    assert_eq!(healthy.modify_index, 24);
+}
+
+fn update_response() -> serde_json::Value {
+    json!({
+        "ID": "a4d7b2c3-9f0e-4d1a-8b6c-2e5f7a9c1d3e",
+        "EvalCreateIndex": 61,
+        "DeploymentModifyIndex": 60,
+        "RevertedJobVersion": null,
+    })
+}
+
+#[tokio::test]
+async fn unblock_posts_deployment_id() {
+    let nomad = MockNomad::start().await;
+    nomad
+        .expect_post(&format!("/v1/deployment/unblock/{}", DEPLOYMENT_ID))
+        .with_json(json!({"DeploymentID": DEPLOYMENT_ID}))
+        .returns_json(update_response())
+        .await;
+
+    let response = nomad
+        .client()
+        .deployment()
+        .unblock(DEPLOYMENT_ID, None)
+        .await
+        .unwrap();
+    assert_eq!(response.deployment_modify_index, 60);
+}
+
+#[tokio::test]
+async fn unblock_requires_deployment_id() {
+    let nomad = MockNomad::start().await;
+
+    let err = nomad
+        .client()
+        .deployment()
+        .unblock("", None)
+        .await
+        .unwrap_err();
+    assert!(matches!(err, ClientError::InvalidInputError(_)));
+    assert!(nomad.received_requests().await.is_empty());
+}
+
+#[tokio::test]
+async fn fail_posts_to_deployment() {
+    let nomad = MockNomad::start().await;
+    nomad
+        .expect_post(&format!("/v1/deployment/fail/{}", DEPLOYMENT_ID))
+        .returns_json(update_response())
+        .await;
+
+    let response = nomad
+        .client()
+        .deployment()
+        .fail(DEPLOYMENT_ID, None)
+        .await
+        .unwrap();
+    assert_eq!(response.eval_create_index, 61);
+}
+
+fn deployment_at(index: u64, status: &str, healthy_allocs: i32) -> ResponseTemplate {
+    ResponseTemplate::new(200)
+        .insert_header("X-Nomad-Index", index.to_string().as_str())
+        .set_body_json(json!({
+            "ID": DEPLOYMENT_ID,
+            "Namespace": "default",
+            "JobID": "example",
+            "JobVersion": 1,
+            "JobModifyIndex": 18,
+            "JobSpecModifyIndex": 18,
+            "JobCreateIndex": 10,
+            "IsMultiregion": false,
+            "TaskGroups": {
+                "cache": {
+                    "AutoRevert": false,
+                    "ProgressDeadline": 600000000000i64,
+                    "RequireProgressBy": "2024-01-02T15:14:05Z",
+                    "Promoted": false,
+                    "PlacedCanaries": null,
+                    "DesiredCanaries": 0,
+                    "DesiredTotal": 2,
+                    "PlacedAllocs": 2,
+                    "HealthyAllocs": healthy_allocs,
+                    "UnhealthyAllocs": 0,
+                },
+            },
+            "Status": status,
+            "StatusDescription": "",
+            "CreateIndex": 20,
+            "ModifyIndex": index,
+            "CreateTime": 1700000000000000000i64,
+            "ModifyTime": 1700000001000000000i64,
+        }))
+}
+
+#[tokio::test]
+async fn wait_for_deployment_blocks_until_successful() {
+    let nomad = MockNomad::start().await;
+    nomad
+        .expect_get(&format!("/v1/deployment/{}", DEPLOYMENT_ID))
+        .returns_sequence(vec![
+            deployment_at(21, "running", 0),
+            deployment_at(25, "running", 1),
+            deployment_at(29, DEPLOYMENT_STATUS_SUCCESSFUL, 2),
+        ])
+        .await;
+
+    let deployment = nomad
+        .client()
+        .deployment()
+        .wait_for_deployment(DEPLOYMENT_ID, Duration::from_secs(5), None)
+        .await
+        .unwrap();
+
+    assert_eq!(deployment.status, DEPLOYMENT_STATUS_SUCCESSFUL);
+    assert_eq!(deployment.task_groups["cache"].healthy_allocs, 2);
+
+    assert_eq!(
+        wait_indexes(&nomad.received_requests().await),
+        vec![None, Some("21".to_string()), Some("25".to_string())]
+    );
+}
+
+/// Get the `index` query parameter of each request.
+fn wait_indexes(requests: &[wiremock::Request]) -> Vec<Option<String>> {
+    requests
+        .iter()
+        .map(|request| {
+            request
+                .url
+                .query_pairs()
+                .find(|(name, _)| name == "index")
+                .map(|(_, value)| value.into_owned())
+        })
+        .collect()
+}
+
+#[tokio::test]
+async fn wait_for_deployment_resets_index_which_goes_backwards() {
+    let nomad = MockNomad::start().await;
+    nomad
+        .expect_get(&format!("/v1/deployment/{}", DEPLOYMENT_ID))
+        .returns_sequence(vec![
+            deployment_at(21, "running", 0),
+            deployment_at(21, "running", 0),
+            deployment_at(7, "running", 1),
+            deployment_at(9, DEPLOYMENT_STATUS_SUCCESSFUL, 2),
+        ])
+        .await;
+
+    let deployment = nomad
+        .client()
+        .deployment()
+        .wait_for_deployment(DEPLOYMENT_ID, Duration::from_secs(5), None)
+        .await
+        .unwrap();
+    assert_eq!(deployment.status, DEPLOYMENT_STATUS_SUCCESSFUL);
+
+    // An unchanged index keeps blocking on it, while an index which went
+    // backwards is dropped so the next request reads the current state.
+    assert_eq!(
+        wait_indexes(&nomad.received_requests().await),
+        vec![None, Some("21".to_string()), Some("21".to_string()), None]
+    );
+}
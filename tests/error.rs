@@ -0,0 +1,126 @@
+mod common;
+
+use common::MockNomad;
+use nomad_rs_api::{ClientError, DESERIALIZATION_ERROR_SNIPPET_LEN};
+use serde_json::json;
+use wiremock::ResponseTemplate;
+
+async fn list_regions_error(response: ResponseTemplate) -> ClientError {
+    let nomad = MockNomad::start().await;
+    nomad.expect_get("/v1/regions").returns(response).await;
+    nomad.client().region().list().await.unwrap_err()
+}
+
+#[tokio::test]
+async fn server_error_parses_json_body() {
+    let err = list_regions_error(
+        ResponseTemplate::new(500).set_body_json(json!({"Message": "rpc error: no leader"})),
+    )
+    .await;
+
+    match err {
+        ClientError::ServerError {
+            status,
+            message,
+            body,
+        } => {
+            assert_eq!(status, 500);
+            assert_eq!(message.as_deref(), Some("rpc error: no leader"));
+            assert_eq!(body, r#"{"Message":"rpc error: no leader"}"#);
+        }
+        err => panic!("unexpected error: {err:?}"),
+    }
+}
+
+#[tokio::test]
+async fn server_error_keeps_plain_text_body() {
+    let err =
+        list_regions_error(ResponseTemplate::new(400).set_body_string("invalid region\n")).await;
+
+    match err {
+        ClientError::ServerError {
+            status,
+            message,
+            body,
+        } => {
+            assert_eq!(status, 400);
+            assert_eq!(message.as_deref(), Some("invalid region"));
+            assert_eq!(body, "invalid region\n");
+        }
+        err => panic!("unexpected error: {err:?}"),
+    }
+}
+
+#[tokio::test]
+async fn server_error_with_empty_body() {
+    let err = list_regions_error(ResponseTemplate::new(502)).await;
+
+    match err {
+        ClientError::ServerError {
+            status,
+            message,
+            body,
+        } => {
+            assert_eq!(status, 502);
+            assert_eq!(message, None);
+            assert!(body.is_empty());
+        }
+        err => panic!("unexpected error: {err:?}"),
+    }
+}
+
+#[tokio::test]
+async fn unauthorized_and_forbidden_are_permission_denied() {
+    for status in [401, 403] {
+        let err =
+            list_regions_error(ResponseTemplate::new(status).set_body_string("Permission denied"))
+                .await;
+
+        match err {
+            ClientError::PermissionDenied {
+                status: denied,
+                message,
+            } => {
+                assert_eq!(denied, status);
+                assert_eq!(message.as_deref(), Some("Permission denied"));
+            }
+            err => panic!("unexpected error for {status}: {err:?}"),
+        }
+    }
+}
+
+#[tokio::test]
+async fn malformed_json_includes_body_snippet() {
+    let err = list_regions_error(
+        ResponseTemplate::new(200)
+            .insert_header("Content-Type", "application/json")
+            .set_body_string(r#"["eu-west", "us-east"#),
+    )
+    .await;
+
+    match err {
+        ClientError::DeserializationError {
+            status,
+            body_snippet,
+            ..
+        } => {
+            assert_eq!(status, 200);
+            assert_eq!(body_snippet, r#"["eu-west", "us-east"#);
+        }
+        err => panic!("unexpected error: {err:?}"),
+    }
+}
+
+#[tokio::test]
+async fn malformed_json_snippet_is_truncated() {
+    let body = format!("[\"{}", "x".repeat(2 * DESERIALIZATION_ERROR_SNIPPET_LEN));
+    let err = list_regions_error(ResponseTemplate::new(200).set_body_string(body.clone())).await;
+
+    match err {
+        ClientError::DeserializationError { body_snippet, .. } => {
+            assert_eq!(body_snippet.len(), DESERIALIZATION_ERROR_SNIPPET_LEN);
+            assert!(body.starts_with(&body_snippet));
+        }
+        err => panic!("unexpected error: {err:?}"),
+    }
+}
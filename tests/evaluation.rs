@@ -0,0 +1,244 @@
+mod common;
+
+use common::MockNomad;
+use nomad_rs_api::ClientError;
+use nomad_rs_api::option::{QUERY_MAX_PER_PAGE, QueryOptions};
+use serde_json::json;
+use std::time::Duration;
+use wiremock::ResponseTemplate;
+
+fn evaluation(id: &str, status: &str, create_index: u64) -> serde_json::Value {
+    json!({
+        "ID": id,
+        "Priority": 50,
+        "Type": "service",
+        "TriggeredBy": "job-register",
+        "Namespace": "default",
+        "JobID": "example",
+        "Status": status,
+        "SnapshotIndex": create_index,
+        "CreateIndex": create_index,
+        "ModifyIndex": create_index,
+        "CreateTime": 1700000000000000000i64,
+        "ModifyTime": 1700000001000000000i64,
+    })
+}
+
+fn page(items: serde_json::Value, next_token: &str) -> ResponseTemplate {
+    ResponseTemplate::new(200)
+        .insert_header("X-Nomad-NextToken", next_token)
+        .set_body_json(items)
+}
+
+#[tokio::test]
+async fn list_all_follows_next_token() {
+    let nomad = MockNomad::start().await;
+    let per_page = QUERY_MAX_PER_PAGE.to_string();
+
+    // Later pages are mounted first, as the first page's expectation also
+    // matches the requests which carry a next token.
+    nomad
+        .expect_get("/v1/evaluations")
+        .with_query("per_page", &per_page)
+        .with_query("next_token", "eval-3")
+        .returns(page(
+            json!([
+                evaluation("eval-3", "complete", 3),
+                evaluation("eval-4", "complete", 4)
+            ]),
+            "eval-5",
+        ))
+        .await;
+    nomad
+        .expect_get("/v1/evaluations")
+        .with_query("per_page", &per_page)
+        .with_query("next_token", "eval-5")
+        .returns(page(json!([evaluation("eval-5", "pending", 5)]), ""))
+        .await;
+    nomad
+        .expect_get("/v1/evaluations")
+        .with_query("per_page", &per_page)
+        .returns(page(
+            json!([
+                evaluation("eval-1", "complete", 1),
+                evaluation("eval-2", "complete", 2)
+            ]),
+            "eval-3",
+        ))
+        .await;
+
+    // Requesting more than Nomad's maximum page size is capped.
+    let opts = QueryOptions::new().with_per_page(QUERY_MAX_PER_PAGE * 5);
+    let evaluations = nomad
+        .client()
+        .evaluation()
+        .list_all(Some(opts))
+        .await
+        .unwrap();
+
+    let ids: Vec<&str> = evaluations.iter().map(|eval| eval.id.as_str()).collect();
+    assert_eq!(ids, vec!["eval-5", "eval-4", "eval-3", "eval-2", "eval-1"]);
+    assert_eq!(nomad.received_requests().await.len(), 3);
+}
+
+fn indexed(body: serde_json::Value, index: u64) -> ResponseTemplate {
+    ResponseTemplate::new(200)
+        .insert_header("X-Nomad-Index", index.to_string().as_str())
+        .set_body_json(body)
+}
+
+#[tokio::test]
+async fn wait_for_evaluation_blocks_until_complete() {
+    let nomad = MockNomad::start().await;
+    nomad
+        .expect_get("/v1/evaluation/eval-1")
+        .returns_sequence(vec![
+            indexed(evaluation("eval-1", "pending", 10), 10),
+            indexed(evaluation("eval-1", "complete", 10), 14),
+        ])
+        .await;
+
+    let evaluation = nomad
+        .client()
+        .evaluation()
+        .wait_for_evaluation("eval-1", Duration::from_secs(5), None)
+        .await
+        .unwrap();
+    assert_eq!(evaluation.status, "complete");
+
+    // The second request blocks on the index returned by the first.
+    let requests = nomad.received_requests().await;
+    let index = |request: &wiremock::Request| {
+        request
+            .url
+            .query_pairs()
+            .find(|(name, _)| name == "index")
+            .map(|(_, value)| value.into_owned())
+    };
+    assert_eq!(index(&requests[0]), None);
+    assert_eq!(index(&requests[1]).as_deref(), Some("10"));
+}
+
+#[tokio::test]
+async fn wait_for_evaluation_polls_without_index() {
+    let nomad = MockNomad::start().await;
+    nomad
+        .expect_get("/v1/evaluation/eval-1")
+        .times(2)
+        .returns_json(evaluation("eval-1", "pending", 10))
+        .await;
+
+    // Without an index to block on, the requests are spaced out rather than
+    // repeated back-to-back until the timeout.
+    let err = nomad
+        .client()
+        .evaluation()
+        .wait_for_evaluation("eval-1", Duration::from_millis(1500), None)
+        .await
+        .unwrap_err();
+    assert!(
+        matches!(err, ClientError::Timeout(_)),
+        "unexpected error: {err:?}"
+    );
+}
+
+fn chained(id: &str, next_eval: &str) -> serde_json::Value {
+    let mut evaluation = evaluation(id, "complete", 10);
+    evaluation["NextEval"] = json!(next_eval);
+    evaluation
+}
+
+#[tokio::test]
+async fn follow_eval_chain_returns_last_link() {
+    let nomad = MockNomad::start().await;
+    nomad
+        .expect_get("/v1/evaluation/eval-1")
+        .returns_json(chained("eval-1", "eval-2"))
+        .await;
+    nomad
+        .expect_get("/v1/evaluation/eval-2")
+        .returns_json(chained("eval-2", ""))
+        .await;
+
+    let evaluation = nomad
+        .client()
+        .evaluation()
+        .follow_eval_chain("eval-1", None)
+        .await
+        .unwrap();
+    assert_eq!(evaluation.id, "eval-2");
+}
+
+#[tokio::test]
+async fn follow_eval_chain_detects_cycle() {
+    let nomad = MockNomad::start().await;
+    nomad
+        .expect_get("/v1/evaluation/eval-1")
+        .returns_json(chained("eval-1", "eval-2"))
+        .await;
+    nomad
+        .expect_get("/v1/evaluation/eval-2")
+        .returns_json(chained("eval-2", "eval-1"))
+        .await;
+
+    let err = nomad
+        .client()
+        .evaluation()
+        .follow_eval_chain("eval-1", None)
+        .await
+        .unwrap_err();
+    assert!(
+        matches!(err, ClientError::InvalidInputError(_)),
+        "unexpected error: {err:?}"
+    );
+}
+
+#[tokio::test]
+async fn list_evaluations_filtered_composes_filter() {
+    let nomad = MockNomad::start().await;
+    nomad
+        .expect_get("/v1/evaluations")
+        .with_query("filter", r#"Status == "failed""#)
+        .returns_json(json!([
+            evaluation("eval-1", "failed", 10),
+            evaluation("eval-2", "failed", 20),
+        ]))
+        .await;
+    nomad
+        .expect_get("/v1/evaluations")
+        .with_query("filter", r#"JobID == "example""#)
+        .returns_json(json!([]))
+        .await;
+    nomad
+        .expect_get("/v1/evaluations")
+        .with_query("filter", r#"JobID == "example" and Status == "failed""#)
+        .returns_json(json!([]))
+        .await;
+
+    let client = nomad.client();
+    let evaluations = client
+        .evaluation()
+        .list_evaluations_filtered(None, Some("failed"), None)
+        .await
+        .unwrap();
+    let ids: Vec<_> = evaluations.iter().map(|eval| eval.id.as_str()).collect();
+    assert_eq!(ids, vec!["eval-2", "eval-1"]);
+
+    client
+        .evaluation()
+        .list_evaluations_filtered(Some("example"), None, None)
+        .await
+        .unwrap();
+    client
+        .evaluation()
+        .list_evaluations_filtered(Some("example"), Some("failed"), None)
+        .await
+        .unwrap();
+
+    let err = client
+        .evaluation()
+        .list_evaluations_filtered(None, Some("failing"), None)
+        .await
+        .unwrap_err();
+    assert!(matches!(err, ClientError::InvalidInputError(_)));
+}
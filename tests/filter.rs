@@ -0,0 +1,68 @@
+use nomad_rs_api::filter::Filter;
+use nomad_rs_api::option::QueryOptions;
+
+#[test]
+fn nested_expressions_are_parenthesised() {
+    let filter = Filter::eq("Status", "running")
+        .or(Filter::eq("Status", "pending"))
+        .and(Filter::eq("NodePool", "gpu").or(Filter::ne("Datacenter", "dc2")));
+    assert_eq!(
+        filter.to_string(),
+        r#"(Status == "running" or Status == "pending") and (NodePool == "gpu" or Datacenter != "dc2")"#
+    );
+}
+
+#[test]
+fn same_operator_chains_are_not_parenthesised() {
+    let filter = Filter::eq("Drain", true)
+        .and(Filter::eq("Priority", 50i64))
+        .and(Filter::contains("Tags", "gpu"));
+    assert_eq!(
+        filter.to_string(),
+        r#"Drain == true and Priority == 50 and Tags contains "gpu""#
+    );
+}
+
+#[test]
+fn not_parenthesises_combined_filters() {
+    let filter = !Filter::eq("Status", "dead").or(Filter::matches("Name", "^batch-"));
+    assert_eq!(
+        filter.to_string(),
+        r#"not (Status == "dead" or Name matches "^batch-")"#
+    );
+    assert_eq!((!Filter::eq("Stop", true)).to_string(), "not Stop == true");
+}
+
+#[test]
+fn string_values_are_escaped() {
+    let filter = Filter::eq("Meta[\"team\"]", r#"a "quoted" \ value"#);
+    assert_eq!(
+        filter.to_string(),
+        r#"Meta["team"] == "a \"quoted\" \\ value""#
+    );
+
+    let filter = Filter::matches("Name", "line\nbreak\t\\d+");
+    assert_eq!(filter.to_string(), r#"Name matches "line\nbreak\t\\d+""#);
+}
+
+#[test]
+fn and_filter_expr_combines_with_existing_filter() {
+    let opts = QueryOptions::new()
+        .with_filter(r#"Status == "running" or Status == "pending""#.to_string())
+        .and_filter_expr(Filter::eq("Namespace", "platform"));
+    assert_eq!(
+        opts.filter.as_deref(),
+        Some(r#"(Status == "running" or Status == "pending") and Namespace == "platform""#)
+    );
+}
+
+#[test]
+fn and_filter_expr_without_existing_filter() {
+    let opts = QueryOptions::new().and_filter_expr(Filter::eq("Namespace", "platform"));
+    assert_eq!(opts.filter.as_deref(), Some(r#"Namespace == "platform""#));
+
+    let opts = QueryOptions::new()
+        .with_filter(String::new())
+        .and_filter_expr(Filter::eq("Namespace", "platform"));
+    assert_eq!(opts.filter.as_deref(), Some(r#"Namespace == "platform""#));
+}
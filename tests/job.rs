@@ -0,0 +1,1237 @@
+mod common;
+
+use common::MockNomad;
+use nomad_rs_api::job::{
+    DIFF_TYPE_EDITED, DiffSummary, Job, JobDiff, JobDispatchRequest, JobPlanRequest,
+    JobPlanResponse, JobRegisterRequest, JobRegisterResponse, JobRevertRequest, JobSpecSource,
+    JobStabilityRequest, JobSummary, JobUpdateStrategy, PlanReport, Task, TaskGroupTotals,
+};
+use nomad_rs_api::namespace::NAMESPACE_WILDCARD;
+use nomad_rs_api::option::{QueryOptions, SortOrder, WriteOptions};
+use nomad_rs_api::{ClientError, Config, DispatchLimits, Nomad};
+use serde_json::json;
+
+#[tokio::test]
+async fn versions_returns_diffs() {
+    let nomad = MockNomad::start().await;
+    nomad
+        .expect_get("/v1/job/example/versions")
+        .with_query("diffs", "true")
+        .returns_json(json!({
+            "Versions": [
+                {"ID": "example", "Name": "example", "Version": 1, "Stable": false, "TaskGroups": []},
+                {"ID": "example", "Name": "example", "Version": 0, "Stable": true, "TaskGroups": []},
+            ],
+            "Diffs": [{
+                "Type": "Edited",
+                "ID": "example",
+                "Fields": [{
+                    "Type": "Edited",
+                    "Name": "Priority",
+                    "Old": "50",
+                    "New": "70",
+                    "Annotations": null,
+                }],
+                "Objects": null,
+                "TaskGroups": [{
+                    "Type": "Edited",
+                    "Name": "cache",
+                    "Fields": null,
+                    "Objects": null,
+                    "Tasks": [{
+                        "Type": "Edited",
+                        "Name": "redis",
+                        "Fields": null,
+                        "Objects": [{
+                            "Type": "Edited",
+                            "Name": "Config",
+                            "Fields": [{
+                                "Type": "Edited",
+                                "Name": "image",
+                                "Old": "redis:6",
+                                "New": "redis:7",
+                                "Annotations": null,
+                            }],
+                            "Objects": null,
+                        }],
+                        "Annotations": ["forces create/destroy update"],
+                    }],
+                    "Updates": {"create/destroy update": 1},
+                }],
+            }],
+            "Index": 42,
+            "KnownLeader": true,
+            "LastContact": 0,
+        }))
+        .await;
+
+    let response = nomad
+        .client()
+        .job()
+        .versions("example", true, None)
+        .await
+        .unwrap();
+
+    assert_eq!(response.versions.len(), 2);
+    assert_eq!(response.versions[0].version, Some(1));
+    let diffs = response.diffs.unwrap();
+    assert_eq!(diffs.len(), 1);
+    assert_eq!(diffs[0].type_, DIFF_TYPE_EDITED);
+    assert_eq!(diffs[0].fields.as_ref().unwrap()[0].new, "70");
+
+    let group = &diffs[0].task_groups.as_ref().unwrap()[0];
+    assert_eq!(group.updates.as_ref().unwrap()["create/destroy update"], 1);
+    let task = &group.tasks.as_ref().unwrap()[0];
+    assert_eq!(task.name, "redis");
+    let config = &task.objects.as_ref().unwrap()[0];
+    assert_eq!(config.fields.as_ref().unwrap()[0].old, "redis:6");
+}
+
+#[tokio::test]
+async fn revert_posts_version() {
+    let nomad = MockNomad::start().await;
+    nomad
+        .expect_post("/v1/job/example/revert")
+        .with_json(json!({
+            "JobID": "example",
+            "JobVersion": 0,
+            "EnforcePriorVersion": 1,
+        }))
+        .returns_json(json!({
+            "EvalID": "d092fdc0-e1fd-2536-67d8-43af8ca798ac",
+            "EvalCreateIndex": 45,
+            "JobModifyIndex": 44,
+            "Warnings": "",
+        }))
+        .await;
+
+    let mut request = JobRevertRequest::new("example".to_string(), 0);
+    request.enforce_prior_version = Some(1);
+    let response = nomad.client().job().revert(&request, None).await.unwrap();
+    assert_eq!(response.job_modify_index, 44);
+}
+
+#[tokio::test]
+async fn revert_requires_job_id() {
+    let nomad = MockNomad::start().await;
+
+    let err = nomad
+        .client()
+        .job()
+        .revert(&JobRevertRequest::new(String::new(), 0), None)
+        .await
+        .unwrap_err();
+    assert!(matches!(err, ClientError::InvalidInputError(_)));
+}
+
+#[test]
+fn stability_request_round_trips() {
+    let request = JobStabilityRequest::new("example".to_string(), 3, true);
+
+    let body = serde_json::to_value(&request).unwrap();
+    assert_eq!(
+        body,
+        json!({"JobID": "example", "JobVersion": 3, "Stable": true})
+    );
+
+    let decoded: JobStabilityRequest = serde_json::from_value(body).unwrap();
+    assert_eq!(decoded.job_id, "example");
+    assert_eq!(decoded.job_version, 3);
+    assert!(decoded.stable);
+}
+
+#[tokio::test]
+async fn parse_returns_job() {
+    let nomad = MockNomad::start().await;
+    let source = r#"job "example" {
+  group "cache" {
+    task "redis" {
+      driver = "docker"
+      config {
+        image = "redis:7"
+      }
+    }
+  }
+}"#;
+    nomad
+        .expect_post("/v1/jobs/parse")
+        .with_json(json!({
+            "JobHCL": source,
+            "Canonicalize": true,
+            "Variables": null,
+        }))
+        .returns_json(json!({
+            "ID": "example",
+            "Name": "example",
+            "Type": "service",
+            "Priority": 50,
+            "TaskGroups": [{
+                "Name": "cache",
+                "Count": 1,
+                "Tasks": [{
+                    "Name": "redis",
+                    "Driver": "docker",
+                    "Config": {"image": "redis:7"},
+                }],
+            }],
+        }))
+        .await;
+
+    let job = nomad
+        .client()
+        .job()
+        .parse(source, true, None)
+        .await
+        .unwrap();
+    assert_eq!(job.name, "example");
+    assert_eq!(job.task_groups.len(), 1);
+}
+
+#[tokio::test]
+async fn parse_sends_variables() {
+    let nomad = MockNomad::start().await;
+    let source = r#"variable "image" {}
+job "example" {}"#;
+    nomad
+        .expect_post("/v1/jobs/parse")
+        .with_json(json!({
+            "JobHCL": source,
+            "Canonicalize": false,
+            "Variables": "image = \"redis:$${tag}\"\n",
+        }))
+        .returns_json(json!({"ID": "example", "Name": "example", "TaskGroups": []}))
+        .await;
+
+    let variables = [("image".to_string(), "redis:${tag}".to_string())].into();
+    let job = nomad
+        .client()
+        .job()
+        .parse_with_variables(source, Some(&variables), false, None)
+        .await
+        .unwrap();
+    assert_eq!(job.name, "example");
+}
+
+#[tokio::test]
+async fn set_stability_posts_request() {
+    let nomad = MockNomad::start().await;
+    nomad
+        .expect_post("/v1/job/example/stable")
+        .with_json(json!({"JobID": "example", "JobVersion": 3, "Stable": false}))
+        .returns_json(json!({"JobModifyIndex": 51}))
+        .await;
+
+    let response = nomad
+        .client()
+        .job()
+        .set_stability(
+            &JobStabilityRequest::new("example".to_string(), 3, false),
+            None,
+        )
+        .await
+        .unwrap();
+    assert_eq!(response.job_modify_index, 51);
+}
+
+#[test]
+fn task_duration_getters_convert_nanoseconds() {
+    let task: Task = serde_json::from_value(json!({
+        "Name": "redis",
+        "Driver": "docker",
+        "KillTimeout": 5_000_000_000u64,
+        "ShutdownDelay": null,
+    }))
+    .unwrap();
+
+    assert_eq!(
+        task.kill_timeout_duration(),
+        Some(time::Duration::seconds(5))
+    );
+    assert_eq!(task.shutdown_delay_duration(), None);
+}
+
+#[test]
+fn update_strategy_duration_getters_convert_nanoseconds() {
+    let update: JobUpdateStrategy = serde_json::from_value(json!({
+        "Stagger": 30_000_000_000u64,
+        "MaxParallel": 1,
+        "MinHealthyTime": 10_000_000_000u64,
+        "HealthyDeadline": 300_000_000_000u64,
+    }))
+    .unwrap();
+
+    assert_eq!(update.stagger_duration(), Some(time::Duration::seconds(30)));
+    assert_eq!(
+        update.min_healthy_time_duration(),
+        Some(time::Duration::seconds(10))
+    );
+    assert_eq!(
+        update.healthy_deadline_duration(),
+        Some(time::Duration::minutes(5))
+    );
+}
+
+#[tokio::test]
+async fn get_returns_job() {
+    let nomad = MockNomad::start().await;
+    nomad
+        .expect_get("/v1/job/example")
+        .with_query("namespace", "platform")
+        .returns_json(json!({
+            "ID": "example",
+            "Name": "example",
+            "Namespace": "platform",
+            "Type": "service",
+            "Priority": 50,
+            "Datacenters": ["dc1"],
+            "TaskGroups": [{
+                "Name": "cache",
+                "Count": 2,
+                "Tasks": [{
+                    "Name": "redis",
+                    "Driver": "docker",
+                    "Config": {"image": "redis:7"},
+                }],
+            }],
+            "Status": "running",
+            "Version": 3,
+            "CreateIndex": 10,
+            "ModifyIndex": 42,
+            "JobModifyIndex": 40,
+        }))
+        .await;
+
+    let opts = QueryOptions::new().with_namespace("platform".to_string());
+    let job = nomad
+        .client()
+        .job()
+        .get("example", Some(opts))
+        .await
+        .unwrap();
+
+    assert_eq!(job.id.as_deref(), Some("example"));
+    assert_eq!(job.task_groups.len(), 1);
+    assert_eq!(job.task_groups[0].count, Some(2));
+}
+
+fn job_stub(id: &str, namespace: &str) -> serde_json::Value {
+    json!({
+        "ID": id,
+        "ParentID": "",
+        "Name": id,
+        "Namespace": namespace,
+        "Datacenters": ["dc1"],
+        "Type": "service",
+        "Priority": 50,
+        "Periodic": false,
+        "ParameterizedJob": false,
+        "Stop": false,
+        "Status": "running",
+        "StatusDescription": "",
+        "JobSummary": null,
+        "CreateIndex": 10,
+        "ModifyIndex": 12,
+        "JobModifyIndex": 10,
+        "SubmitTime": 1700000000000000000i64,
+        "Meta": null,
+    })
+}
+
+#[tokio::test]
+async fn list_sort_order_controls_job_order() {
+    let nomad = MockNomad::start().await;
+    nomad
+        .expect_get("/v1/jobs")
+        .times(4)
+        .returns_json(json!([
+            job_stub("etl", "default"),
+            job_stub("web", "default"),
+            job_stub("api", "default"),
+        ]))
+        .await;
+
+    let client = nomad.client();
+    let list = async |opts: Option<QueryOptions>| {
+        let jobs = client.job().list(None, opts).await.unwrap();
+        jobs.into_iter().map(|job| job.id).collect::<Vec<_>>()
+    };
+
+    assert_eq!(list(None).await, vec!["api", "etl", "web"]);
+    assert_eq!(
+        list(Some(
+            QueryOptions::new().with_sort_order(SortOrder::Descending)
+        ))
+        .await,
+        vec!["web", "etl", "api"]
+    );
+
+    // The order returned by Nomad is kept when it was asked to reverse it,
+    // or when no sorting is requested.
+    assert_eq!(
+        list(Some(QueryOptions::new().with_reverse(true))).await,
+        vec!["etl", "web", "api"]
+    );
+    assert_eq!(
+        list(Some(QueryOptions::new().with_sort_order(SortOrder::Server))).await,
+        vec!["etl", "web", "api"]
+    );
+}
+
+#[tokio::test]
+async fn list_in_namespace_wildcard_spans_namespaces() {
+    let nomad = MockNomad::start().await;
+    nomad
+        .expect_get("/v1/jobs")
+        .with_query("namespace", "*")
+        .returns_json(json!([
+            job_stub("api", "platform"),
+            job_stub("etl", "data"),
+            job_stub("web", "default"),
+        ]))
+        .await;
+
+    let jobs = nomad
+        .client()
+        .job()
+        .list_in_namespace(NAMESPACE_WILDCARD, None, None)
+        .await
+        .unwrap();
+
+    let namespaces: Vec<_> = jobs.iter().map(|job| job.namespace.as_str()).collect();
+    assert_eq!(namespaces, vec!["platform", "data", "default"]);
+
+    let requests = nomad.received_requests().await;
+    let query = requests[0].url.query().unwrap();
+    assert!(query.contains("namespace=*"), "query: {query}");
+}
+
+#[tokio::test]
+async fn register_idempotent_reuses_token() {
+    let nomad = MockNomad::start().await;
+    nomad
+        .expect_post("/v1/jobs")
+        .with_query("idempotency_token", "deploy-example-42")
+        .times(2)
+        .returns_json(json!({
+            "EvalID": "d092fdc0-e1fd-2536-67d8-43af8ca798ac",
+            "EvalCreateIndex": 35,
+            "JobModifyIndex": 34,
+        }))
+        .await;
+
+    let job = Job::new(
+        "example".to_string(),
+        "global".to_string(),
+        "service".to_string(),
+        vec![],
+    );
+    let request = JobRegisterRequest::new(&job);
+    let client = nomad.client();
+    let first = client
+        .job()
+        .register_idempotent(&request, "deploy-example-42", None)
+        .await
+        .unwrap();
+    // A token set within the options is replaced by the explicit token.
+    let opts = WriteOptions::new().with_idempotency_token("stale".to_string());
+    let retry = client
+        .job()
+        .register_idempotent(&request, "deploy-example-42", Some(opts))
+        .await
+        .unwrap();
+
+    assert_eq!(first.eval_id, retry.eval_id);
+    assert_eq!(first.job_modify_index, retry.job_modify_index);
+    for request in nomad.received_requests().await {
+        let tokens: Vec<_> = request
+            .url
+            .query_pairs()
+            .filter(|(name, _)| name == "idempotency_token")
+            .collect();
+        assert_eq!(tokens.len(), 1);
+    }
+}
+
+fn register_spec_response() -> serde_json::Value {
+    json!({
+        "EvalID": "d092fdc0-e1fd-2536-67d8-43af8ca798ac",
+        "EvalCreateIndex": 35,
+        "JobModifyIndex": 34,
+    })
+}
+
+#[tokio::test]
+async fn consul_and_vault_tokens_are_set_on_the_job() {
+    let nomad = MockNomad::start().await;
+    nomad
+        .expect_post("/v1/jobs")
+        .returns_json(register_spec_response())
+        .await;
+    nomad
+        .expect_post("/v1/job/example/plan")
+        .returns_json(json!({"JobModifyIndex": 34, "CreatedEvals": []}))
+        .await;
+
+    let mut job = Job::new(
+        "example".to_string(),
+        "global".to_string(),
+        "service".to_string(),
+        vec![],
+    );
+    job.id = Some("example".to_string());
+    let opts = || {
+        WriteOptions::new()
+            .with_consul_token("consul-secret".to_string())
+            .with_vault_token("vault-secret".to_string())
+    };
+    let client = nomad.client();
+    client
+        .job()
+        .regsiter(&JobRegisterRequest::new(&job), Some(opts()))
+        .await
+        .unwrap();
+    let plan_request = JobPlanRequest {
+        job: &job,
+        diff: false,
+        policy_override: false,
+    };
+    client
+        .job()
+        .plan(&plan_request, Some(opts()))
+        .await
+        .unwrap();
+
+    for request in nomad.received_requests().await {
+        let body: serde_json::Value = request.body_json().unwrap();
+        assert_eq!(body["Job"]["ConsulToken"], "consul-secret");
+        assert_eq!(body["Job"]["VaultToken"], "vault-secret");
+        assert!(!request.headers.contains_key("x-consul-token"));
+        assert!(!request.headers.contains_key("x-vault-token"));
+    }
+}
+
+/// The body of the job registration request received by the mock server.
+async fn registered_body(nomad: &MockNomad) -> serde_json::Value {
+    let request = nomad
+        .received_requests()
+        .await
+        .into_iter()
+        .find(|request| request.url.path() == "/v1/jobs")
+        .unwrap();
+    serde_json::from_slice(&request.body).unwrap()
+}
+
+#[tokio::test]
+async fn register_spec_json_attaches_encoded_job() {
+    let nomad = MockNomad::start().await;
+    nomad
+        .expect_post("/v1/jobs")
+        .returns_json(register_spec_response())
+        .await;
+
+    let job = Job::new(
+        "example".to_string(),
+        "global".to_string(),
+        "service".to_string(),
+        vec![],
+    );
+    let response = nomad
+        .client()
+        .job()
+        .register_spec(JobSpecSource::Json(Box::new(job)), None)
+        .await
+        .unwrap();
+    assert_eq!(response.job_modify_index, 34);
+
+    let body = registered_body(&nomad).await;
+    assert_eq!(body["Job"]["Name"], "example");
+    assert_eq!(body["Submission"]["Format"], "json");
+    let source: serde_json::Value =
+        serde_json::from_str(body["Submission"]["Source"].as_str().unwrap()).unwrap();
+    assert_eq!(source, body["Job"]);
+}
+
+#[tokio::test]
+async fn register_spec_raw_json_accepts_wrapped_and_bare_jobs() {
+    let bare = r#"{"Name": "example", "Type": "batch", "TaskGroups": []}"#;
+    let wrapped = format!(r#"{{"Job": {}}}"#, bare);
+
+    for source in [bare.to_string(), wrapped] {
+        let nomad = MockNomad::start().await;
+        nomad
+            .expect_post("/v1/jobs")
+            .returns_json(register_spec_response())
+            .await;
+
+        nomad
+            .client()
+            .job()
+            .register_spec(JobSpecSource::RawJson(source.clone()), None)
+            .await
+            .unwrap();
+
+        let body = registered_body(&nomad).await;
+        assert_eq!(body["Job"]["Name"], "example");
+        assert_eq!(body["Job"]["Type"], "batch");
+        assert_eq!(body["Submission"]["Source"], source);
+        assert_eq!(body["Submission"]["Format"], "json");
+    }
+}
+
+#[tokio::test]
+async fn register_spec_rejects_invalid_raw_json() {
+    let nomad = MockNomad::start().await;
+    let err = nomad
+        .client()
+        .job()
+        .register_spec(JobSpecSource::RawJson("{\"Job\": 1}".to_string()), None)
+        .await
+        .unwrap_err();
+    assert!(matches!(err, ClientError::InvalidInputError(_)));
+    assert!(nomad.received_requests().await.is_empty());
+}
+
+#[tokio::test]
+async fn register_spec_hcl_parses_before_registering() {
+    let nomad = MockNomad::start().await;
+    let source = r#"job "example" {
+  group "cache" {}
+}"#;
+    nomad
+        .expect_post("/v1/jobs/parse")
+        .with_json(json!({
+            "JobHCL": source,
+            "Canonicalize": false,
+            "Variables": null,
+        }))
+        .returns_json(json!({
+            "ID": "example",
+            "Name": "example",
+            "TaskGroups": [{"Name": "cache", "Tasks": []}],
+        }))
+        .await;
+    nomad
+        .expect_post("/v1/jobs")
+        .with_query("idempotency_token", "deploy-example-42")
+        .returns_json(register_spec_response())
+        .await;
+
+    let opts = WriteOptions::new().with_idempotency_token("deploy-example-42".to_string());
+    nomad
+        .client()
+        .job()
+        .register_spec(JobSpecSource::Hcl(source.to_string()), Some(opts))
+        .await
+        .unwrap();
+
+    let requests = nomad.received_requests().await;
+    assert_eq!(requests[0].url.path(), "/v1/jobs/parse");
+    assert!(
+        !requests[0]
+            .url
+            .query_pairs()
+            .any(|(name, _)| name == "idempotency_token")
+    );
+
+    let body = registered_body(&nomad).await;
+    assert_eq!(body["Job"]["ID"], "example");
+    assert_eq!(body["Job"]["TaskGroups"][0]["Name"], "cache");
+    assert_eq!(body["Submission"]["Source"], source);
+    assert_eq!(body["Submission"]["Format"], "hcl2");
+}
+
+fn deregister_response() -> serde_json::Value {
+    json!({
+        "EvalID": "d092fdc0-e1fd-2536-67d8-43af8ca798ac",
+        "EvalCreateIndex": 36,
+        "JobModifyIndex": 36,
+    })
+}
+
+#[tokio::test]
+async fn stop_does_not_purge() {
+    let nomad = MockNomad::start().await;
+    nomad
+        .expect_delete("/v1/job/example")
+        .with_query("purge", "false")
+        .returns_json(deregister_response())
+        .await;
+
+    let response = nomad.client().job().stop("example", None).await.unwrap();
+    assert_eq!(response.job_modify_index, 36);
+}
+
+#[tokio::test]
+async fn purge_sends_purge() {
+    let nomad = MockNomad::start().await;
+    nomad
+        .expect_delete("/v1/job/example")
+        .with_query("purge", "true")
+        .returns_json(deregister_response())
+        .await;
+
+    let response = nomad.client().job().purge("example", None).await.unwrap();
+    assert_eq!(response.job_modify_index, 36);
+}
+
+#[tokio::test]
+async fn global_param_sent_only_when_requested() {
+    let nomad = MockNomad::start().await;
+    nomad
+        .expect_get("/v1/job/example/deployment")
+        .times(3)
+        .returns_json(json!(null))
+        .await;
+
+    let client = nomad.client();
+    for opts in [
+        Some(QueryOptions::new().with_global(true)),
+        Some(QueryOptions::new().with_global(false)),
+        None,
+    ] {
+        let deployment = client
+            .job()
+            .get_latest_deployment("example", opts)
+            .await
+            .unwrap();
+        assert!(deployment.is_none());
+    }
+
+    let globals: Vec<_> = nomad
+        .received_requests()
+        .await
+        .iter()
+        .map(|request| {
+            request
+                .url
+                .query_pairs()
+                .filter(|(name, _)| name == "global")
+                .map(|(_, value)| value.into_owned())
+                .collect::<Vec<_>>()
+        })
+        .collect();
+    assert_eq!(globals, vec![vec!["true".to_string()], vec![], vec![]]);
+}
+
+#[tokio::test]
+async fn dispatch_rejects_oversized_payload() {
+    let nomad = MockNomad::start().await;
+    let request = JobDispatchRequest::new("batch".to_string()).with_payload(vec![
+        0;
+        DispatchLimits::DEFAULT_MAX_PAYLOAD_SIZE
+            + 1
+    ]);
+
+    let err = nomad
+        .client()
+        .job()
+        .dispatch(&request, None)
+        .await
+        .unwrap_err();
+    assert!(
+        matches!(err, ClientError::InvalidInputError(ref message) if message.contains("16384")),
+        "unexpected error: {err:?}"
+    );
+    assert!(nomad.received_requests().await.is_empty());
+}
+
+#[tokio::test]
+async fn dispatch_honours_raised_payload_limit() {
+    let nomad = MockNomad::start().await;
+    nomad
+        .expect_post("/v1/job/batch/dispatch")
+        .returns_json(json!({
+            "DispatchedJobID": "batch/dispatch-1700000000-3e1c8a9b",
+            "EvalID": "d092fdc0-e1fd-2536-67d8-43af8ca798ac",
+            "EvalCreateIndex": 40,
+            "JobCreateIndex": 39,
+        }))
+        .await;
+
+    let config = Config {
+        dispatch_limits: DispatchLimits::new(64 * 1024),
+        ..nomad.config()
+    };
+    let request = JobDispatchRequest::new("batch".to_string()).with_payload(vec![0; 32 * 1024]);
+    let response = Nomad::new(config)
+        .job()
+        .dispatch(&request, None)
+        .await
+        .unwrap();
+    assert_eq!(
+        response.dispatched_job_id,
+        "batch/dispatch-1700000000-3e1c8a9b"
+    );
+    assert_eq!(response.job_create_index, 39);
+}
+
+/// The warnings Nomad returns for a job whose update `max_parallel` exceeds
+/// the group count, formatted by Nomad's warnings formatter.
+const UPDATE_WARNINGS: &str = "1 warning(s):\n\n* Group \"cache\" has warnings: 1 error occurred:\n\t* Update max parallel count is greater than task group count (6 > 3). A destructive change would result in the simultaneous replacement of all allocations.\n\n";
+
+/// The warning messages within `UPDATE_WARNINGS`.
+const UPDATE_WARNINGS_LIST: [&str; 2] = [
+    "Group \"cache\" has warnings: 1 error occurred:",
+    "Update max parallel count is greater than task group count (6 > 3). A destructive change would result in the simultaneous replacement of all allocations.",
+];
+
+fn register_response(warnings: serde_json::Value) -> JobRegisterResponse {
+    serde_json::from_value(json!({
+        "EvalID": "d092fdc0-e1fd-2536-67d8-43af8ca798ac",
+        "EvalCreateIndex": 35,
+        "JobModifyIndex": 34,
+        "Warnings": warnings,
+    }))
+    .unwrap()
+}
+
+#[test]
+fn warnings_list_splits_multiple_warnings() {
+    let response = register_response(json!(UPDATE_WARNINGS));
+
+    assert_eq!(response.warnings_list(), UPDATE_WARNINGS_LIST);
+}
+
+#[test]
+fn warnings_list_of_no_warnings_is_empty() {
+    assert!(register_response(json!("")).warnings_list().is_empty());
+    assert!(register_response(json!(null)).warnings_list().is_empty());
+}
+
+#[test]
+fn plan_warnings_list_strips_count_header() {
+    let plan: JobPlanResponse = serde_json::from_value(json!({
+        "JobModifyIndex": 34,
+        "CreatedEvals": [],
+        "Warnings": UPDATE_WARNINGS,
+    }))
+    .unwrap();
+
+    assert_eq!(plan.warnings_list(), UPDATE_WARNINGS_LIST);
+}
+
+fn field_diff(type_: &str, name: &str) -> serde_json::Value {
+    json!({"Type": type_, "Name": name, "Old": "", "New": "", "Annotations": null})
+}
+
+fn object_diff(
+    type_: &str,
+    name: &str,
+    fields: Vec<serde_json::Value>,
+    objects: Vec<serde_json::Value>,
+) -> serde_json::Value {
+    json!({"Type": type_, "Name": name, "Fields": fields, "Objects": objects})
+}
+
+fn nested_job_diff() -> JobDiff {
+    serde_json::from_value(json!({
+        "Type": "Edited",
+        "ID": "example",
+        "Fields": [field_diff("Edited", "Priority")],
+        "Objects": null,
+        "TaskGroups": [{
+            "Type": "Added",
+            "Name": "api",
+            "Fields": [field_diff("Added", "Count")],
+            "Objects": null,
+            "Tasks": [{
+                "Type": "Added",
+                "Name": "web",
+                "Fields": [field_diff("Added", "Driver")],
+                "Objects": null,
+                "Annotations": null,
+            }],
+            "Updates": {"create": 1},
+        }, {
+            "Type": "Edited",
+            "Name": "cache",
+            "Fields": [field_diff("None", "Count")],
+            "Objects": null,
+            "Tasks": [{
+                "Type": "Edited",
+                "Name": "redis",
+                "Fields": null,
+                "Objects": [
+                    object_diff("Edited", "Config", vec![field_diff("Edited", "image")], vec![]),
+                    object_diff(
+                        "Deleted",
+                        "Service",
+                        vec![field_diff("Deleted", "PortLabel")],
+                        vec![object_diff(
+                            "Deleted",
+                            "Check",
+                            vec![field_diff("Deleted", "Type")],
+                            vec![],
+                        )],
+                    ),
+                ],
+                "Annotations": ["forces create/destroy update"],
+            }],
+            "Updates": {"create/destroy update": 1},
+        }],
+    }))
+    .unwrap()
+}
+
+#[test]
+fn job_diff_summarizes_nested_changes() {
+    let summary = nested_job_diff().summarize();
+
+    assert_eq!(
+        summary,
+        DiffSummary {
+            fields_added: 2,
+            fields_deleted: 2,
+            fields_edited: 2,
+            objects_added: 2,
+            objects_deleted: 2,
+            objects_edited: 3,
+        }
+    );
+    assert_eq!(summary.added(), 4);
+    assert_eq!(summary.deleted(), 4);
+    assert_eq!(summary.edited(), 5);
+    assert!(!summary.is_empty());
+    assert_eq!(summary.to_string(), "4 added, 5 edited, 4 deleted");
+}
+
+#[test]
+fn task_group_diff_summarizes_its_tasks() {
+    let diff = nested_job_diff();
+    let groups = diff.task_groups.as_ref().unwrap();
+
+    // The summary of a task group excludes the change to the group itself.
+    let cache = groups[1].summarize();
+    assert_eq!(cache.fields_edited, 1);
+    assert_eq!(cache.fields_deleted, 2);
+    assert_eq!(cache.objects_edited, 2);
+    assert_eq!(cache.objects_deleted, 2);
+    assert_eq!(cache.added(), 0);
+}
+
+fn parameterized_job() -> serde_json::Value {
+    json!({
+        "ID": "batch",
+        "Name": "batch",
+        "Type": "batch",
+        "TaskGroups": [],
+        "ParameterizedJob": {
+            "Payload": "optional",
+            "MetaRequired": ["input", "owner"],
+            "MetaOptional": ["priority", "region"],
+        },
+        "Meta": {"priority": "low", "team": "data"},
+    })
+}
+
+#[tokio::test]
+async fn dispatch_with_defaults_lists_missing_required_meta() {
+    let nomad = MockNomad::start().await;
+    nomad
+        .expect_get("/v1/job/batch")
+        .returns_json(parameterized_job())
+        .await;
+
+    let provided = [("input".to_string(), "s3://bucket/key".to_string())].into();
+    let err = nomad
+        .client()
+        .job()
+        .dispatch_with_defaults("batch", provided, None)
+        .await
+        .unwrap_err();
+
+    assert!(
+        matches!(err, ClientError::InvalidInputError(ref message) if message.ends_with(": owner")),
+        "unexpected error: {err:?}"
+    );
+    // Nothing is dispatched once validation fails.
+    assert_eq!(nomad.received_requests().await.len(), 1);
+}
+
+#[tokio::test]
+async fn dispatch_with_defaults_merges_optional_meta() {
+    let nomad = MockNomad::start().await;
+    nomad
+        .expect_get("/v1/job/batch")
+        .returns_json(parameterized_job())
+        .await;
+    nomad
+        .expect_post("/v1/job/batch/dispatch")
+        .returns_json(json!({
+            "DispatchedJobID": "batch/dispatch-1700000000-3e1c8a9b",
+            "EvalID": "d092fdc0-e1fd-2536-67d8-43af8ca798ac",
+            "EvalCreateIndex": 40,
+            "JobCreateIndex": 39,
+        }))
+        .await;
+
+    let provided = [
+        ("input".to_string(), "s3://bucket/key".to_string()),
+        ("owner".to_string(), "alice".to_string()),
+        ("region".to_string(), "eu-west".to_string()),
+    ]
+    .into();
+    let response = nomad
+        .client()
+        .job()
+        .dispatch_with_defaults("batch", provided, None)
+        .await
+        .unwrap();
+    assert_eq!(
+        response.dispatched_job_id,
+        "batch/dispatch-1700000000-3e1c8a9b"
+    );
+
+    // The optional "priority" key defaults to the job's meta, while "team" is
+    // not declared as dispatch meta and is left out.
+    let requests = nomad.received_requests().await;
+    let body: serde_json::Value = requests[1].body_json().unwrap();
+    assert_eq!(
+        body["Meta"],
+        json!({
+            "input": "s3://bucket/key",
+            "owner": "alice",
+            "region": "eu-west",
+            "priority": "low",
+        })
+    );
+}
+
+fn desired_updates(in_place_update: u64, destructive_update: u64) -> serde_json::Value {
+    json!({
+        "Ignore": 0,
+        "Place": 0,
+        "Migrate": 0,
+        "Stop": 0,
+        "InPlaceUpdate": in_place_update,
+        "DestructiveUpdate": destructive_update,
+        "Canary": 0,
+        "Preemptions": 0,
+    })
+}
+
+#[tokio::test]
+async fn plan_and_report_counts_destructive_updates() {
+    let nomad = MockNomad::start().await;
+    nomad
+        .expect_post("/v1/job/example/plan")
+        .returns_json(json!({
+            "JobModifyIndex": 34,
+            "CreatedEvals": [],
+            "Diff": null,
+            "Annotations": {
+                "DesiredTGUpdates": {
+                    "cache": desired_updates(0, 3),
+                    "api": desired_updates(2, 1),
+                },
+                "PreemptedAllocs": null,
+            },
+            "FailedTGAllocs": null,
+            "NextPeriodicLaunch": "0001-01-01T00:00:00Z",
+            "Warnings": UPDATE_WARNINGS,
+        }))
+        .await;
+
+    let mut job = Job::new(
+        "example".to_string(),
+        "global".to_string(),
+        "service".to_string(),
+        vec![],
+    );
+    job.id = Some("example".to_string());
+    let report = nomad
+        .client()
+        .job()
+        .plan_and_report(&job, None)
+        .await
+        .unwrap();
+
+    assert_eq!(
+        report,
+        PlanReport {
+            will_create_evals: false,
+            destructive_updates: 4,
+            in_place_updates: 2,
+            warnings: UPDATE_WARNINGS_LIST.map(String::from).to_vec(),
+            failed_tg_allocs_present: false,
+        }
+    );
+    assert!(report.is_go());
+
+    let requests = nomad.received_requests().await;
+    let body: serde_json::Value = requests[0].body_json().unwrap();
+    assert_eq!(body["Diff"], true);
+}
+
+#[test]
+fn plan_report_with_failed_allocs_is_no_go() {
+    let plan: JobPlanResponse = serde_json::from_value(json!({
+        "JobModifyIndex": 34,
+        "CreatedEvals": [{
+            "ID": "5456bd7a-9fc0-c0dd-6131-cbee77f57577",
+            "Priority": 50,
+            "Type": "service",
+            "TriggeredBy": "queued-allocs",
+            "Namespace": "default",
+            "JobID": "example",
+            "Status": "blocked",
+            "SnapshotIndex": 34,
+            "CreateIndex": 35,
+            "ModifyIndex": 35,
+            "CreateTime": 1700000000000000000i64,
+            "ModifyTime": 1700000000000000000i64,
+        }],
+        "FailedTGAllocs": {
+            "cache": {
+                "NodesEvaluated": 3,
+                "NodesFiltered": 0,
+                "NodesAvailable": {"dc1": 3},
+                "NodesExhausted": 3,
+                "DimensionExhausted": {"memory": 3},
+                "AllocationTime": 41000,
+                "CoalescedFailures": 1,
+            },
+        },
+    }))
+    .unwrap();
+
+    let report = PlanReport::from(&plan);
+    assert!(report.will_create_evals);
+    assert!(report.failed_tg_allocs_present);
+    assert!(!report.is_go());
+}
+
+#[test]
+fn job_summary_totals_sum_task_groups() {
+    let summary: JobSummary = serde_json::from_value(json!({
+        "JobID": "example",
+        "Namespace": "default",
+        "Summary": {
+            "cache": {
+                "Queued": 1,
+                "Complete": 4,
+                "Failed": 2,
+                "Running": 3,
+                "Starting": 0,
+                "Lost": 1,
+                "Unknown": 0,
+            },
+            "api": {
+                "Queued": 0,
+                "Complete": 1,
+                "Failed": 0,
+                "Running": 5,
+                "Starting": 2,
+                "Lost": 0,
+                "Unknown": 1,
+            },
+        },
+        "Children": null,
+        "CreateIndex": 7,
+        "ModifyIndex": 13,
+    }))
+    .unwrap();
+
+    assert_eq!(
+        summary.totals(),
+        TaskGroupTotals {
+            queued: 1,
+            complete: 5,
+            failed: 2,
+            running: 8,
+            starting: 2,
+            lost: 1,
+            unknown: 1,
+        }
+    );
+}
+
+#[tokio::test]
+async fn get_optional_maps_not_found_to_none() {
+    let nomad = MockNomad::start().await;
+    nomad
+        .expect_get("/v1/job/missing")
+        .returns_status(404, "job not found")
+        .await;
+    nomad
+        .expect_get("/v1/job/broken")
+        .returns_status(500, "rpc error: No cluster leader")
+        .await;
+
+    let client = nomad.client();
+    let job = client.job().get_optional("missing", None).await.unwrap();
+    assert!(job.is_none());
+
+    let err = client.job().get_optional("broken", None).await.unwrap_err();
+    assert!(matches!(err, ClientError::ServerError { status: 500, .. }));
+    assert!(!err.is_not_found());
+}
+
+#[tokio::test]
+async fn scale_status_decodes_scaling_event_meta() {
+    let nomad = MockNomad::start().await;
+    nomad
+        .expect_get("/v1/job/example/scale")
+        .returns_json(json!({
+            "JobCreateIndex": 10,
+            "JobID": "example",
+            "JobModifyIndex": 18,
+            "JobStopped": false,
+            "Namespace": "default",
+            "TaskGroups": {
+                "cache": {
+                    "Desired": 3,
+                    "Events": [
+                        {
+                            "Count": 3,
+                            "CreateIndex": 18,
+                            "Error": false,
+                            "EvalID": "d092fdc0-e1fd-2536-67d8-43af8ca798ac",
+                            "Message": "Scaling cache from 1 to 3",
+                            "Meta": {
+                                "nomad_autoscaler.count.capped": false,
+                                "nomad_autoscaler.count.original": 3,
+                                "nomad_autoscaler.reason_history": [],
+                            },
+                            "PreviousCount": 1,
+                            "Time": 1700000000000000000i64,
+                        },
+                        {
+                            "Count": null,
+                            "CreateIndex": 12,
+                            "Error": true,
+                            "EvalID": null,
+                            "Message": "failed to query source",
+                            "Meta": null,
+                            "PreviousCount": 1,
+                            "Time": 1699999000000000000i64,
+                        },
+                    ],
+                    "Healthy": 3,
+                    "Placed": 3,
+                    "Running": 3,
+                    "Unhealthy": 0,
+                },
+            },
+        }))
+        .await;
+
+    let status = nomad
+        .client()
+        .job()
+        .scale_status("example", None)
+        .await
+        .unwrap();
+    let events = status.task_groups["cache"].events.as_ref().unwrap();
+
+    let meta = events[0].meta.as_ref().unwrap();
+    assert_eq!(meta["nomad_autoscaler.count.original"], json!(3));
+    assert_eq!(meta["nomad_autoscaler.count.capped"], json!(false));
+    assert_eq!(
+        events[0].eval_id.as_deref(),
+        Some("d092fdc0-e1fd-2536-67d8-43af8ca798ac")
+    );
+    assert!(events[1].error);
+    assert!(events[1].meta.is_none());
+    assert!(events[1].eval_id.is_none());
+}
@@ -0,0 +1,157 @@
+use nomad_rs_api::ClientError;
+use nomad_rs_api::job::{JOB_TYPE_BATCH, JobTaskGroup, Task};
+use nomad_rs_api::job_builder::{JobBuilder, TaskBuilder, TaskGroupBuilder};
+use serde_json::json;
+use std::time::Duration;
+
+fn task(name: &str) -> Task {
+    TaskBuilder::new(name, "docker").build().unwrap()
+}
+
+fn task_group(name: &str) -> JobTaskGroup {
+    TaskGroupBuilder::new(name)
+        .add_task(task("redis"))
+        .build()
+        .unwrap()
+}
+
+fn assert_invalid<T: std::fmt::Debug>(result: Result<T, ClientError>, expected: &str) {
+    match result {
+        Err(ClientError::InvalidInputError(message)) => {
+            assert!(message.contains(expected), "unexpected message: {message}")
+        }
+        other => panic!("unexpected result: {other:?}"),
+    }
+}
+
+#[test]
+fn job_builds_with_defaults() {
+    let web = TaskBuilder::new("web", "docker")
+        .config("image", json!("nginx:1.27"))
+        .cpu_and_memory(200, 128)
+        .kill_timeout(Duration::from_secs(10))
+        .build()
+        .unwrap();
+    let group = TaskGroupBuilder::new("frontend")
+        .count(2)
+        .add_task(web)
+        .build()
+        .unwrap();
+    let job = JobBuilder::new("frontend")
+        .job_type(JOB_TYPE_BATCH)
+        .datacenters(&["dc1"])
+        .priority(70)
+        .add_task_group(group)
+        .build()
+        .unwrap();
+
+    // The job ID defaults to the job name.
+    assert_eq!(job.id.as_deref(), Some("frontend"));
+    assert_eq!(job.type_.as_deref(), Some(JOB_TYPE_BATCH));
+    assert_eq!(job.priority, Some(70));
+    let task = &job.task_groups[0].tasks[0];
+    assert_eq!(task.kill_timeout, Some(10_000_000_000));
+    assert_eq!(task.resources.as_ref().unwrap().memory_mb, Some(128));
+}
+
+#[test]
+fn job_rejects_missing_name() {
+    let result = JobBuilder::new("")
+        .add_task_group(task_group("cache"))
+        .build();
+    assert_invalid(result, "name must be set");
+}
+
+#[test]
+fn job_rejects_empty_id() {
+    let result = JobBuilder::new("example")
+        .id("")
+        .add_task_group(task_group("cache"))
+        .build();
+    assert_invalid(result, "ID must not be empty");
+}
+
+#[test]
+fn job_rejects_unknown_type() {
+    for job_type in ["", "services"] {
+        let result = JobBuilder::new("example")
+            .job_type(job_type)
+            .add_task_group(task_group("cache"))
+            .build();
+        assert_invalid(result, "Invalid job type");
+    }
+}
+
+#[test]
+fn job_rejects_out_of_range_priority() {
+    for priority in [0, 101] {
+        let result = JobBuilder::new("example")
+            .priority(priority)
+            .add_task_group(task_group("cache"))
+            .build();
+        assert_invalid(result, "priority must be between 1 and 100");
+    }
+}
+
+#[test]
+fn job_rejects_missing_task_groups() {
+    assert_invalid(
+        JobBuilder::new("example").build(),
+        "at least one task group",
+    );
+}
+
+#[test]
+fn job_rejects_duplicate_task_group_names() {
+    let result = JobBuilder::new("example")
+        .add_task_group(task_group("cache"))
+        .add_task_group(task_group("cache"))
+        .build();
+    assert_invalid(result, "Duplicate task group name: cache");
+}
+
+#[test]
+fn task_group_rejects_missing_name() {
+    let result = TaskGroupBuilder::new("").add_task(task("redis")).build();
+    assert_invalid(result, "Task group name must be set");
+}
+
+#[test]
+fn task_group_rejects_negative_count() {
+    let result = TaskGroupBuilder::new("cache")
+        .count(-1)
+        .add_task(task("redis"))
+        .build();
+    assert_invalid(result, "count must not be negative");
+}
+
+#[test]
+fn task_group_rejects_missing_tasks() {
+    assert_invalid(TaskGroupBuilder::new("cache").build(), "at least one task");
+}
+
+#[test]
+fn task_group_rejects_duplicate_task_names() {
+    let result = TaskGroupBuilder::new("cache")
+        .add_task(task("redis"))
+        .add_task(task("redis"))
+        .build();
+    assert_invalid(result, "Duplicate task name in task group cache: redis");
+}
+
+#[test]
+fn task_rejects_missing_name_and_driver() {
+    assert_invalid(
+        TaskBuilder::new("", "docker").build(),
+        "Task name must be set",
+    );
+    assert_invalid(TaskBuilder::new("redis", "").build(), "driver must be set");
+}
+
+#[test]
+fn task_rejects_oversized_kill_timeout() {
+    let result = TaskBuilder::new("redis", "docker")
+        .kill_timeout(Duration::MAX)
+        .build();
+    assert_invalid(result, "kill timeout is too large");
+}
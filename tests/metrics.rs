@@ -0,0 +1,92 @@
+mod common;
+
+use common::MockNomad;
+use serde_json::json;
+use wiremock::ResponseTemplate;
+
+#[tokio::test]
+async fn summary_decodes_captured_payload() {
+    let nomad = MockNomad::start().await;
+    nomad
+        .expect_get("/v1/metrics")
+        .returns_json(json!({
+            "Counters": [{
+                "Count": 1,
+                "Labels": {},
+                "Max": 1,
+                "Mean": 1,
+                "Min": 1,
+                "Name": "nomad.nomad.rpc.query",
+                "Rate": 0.1,
+                "Stddev": 0,
+                "Sum": 1,
+            }],
+            "Gauges": [{
+                "Labels": {
+                    "node_id": "cd7c3e0c-0174-29dd-17ba-ea4609e0fd1f",
+                    "datacenter": "dc1",
+                },
+                "Name": "nomad.client.allocations.blocked",
+                "Value": 0,
+            }, {
+                "Labels": {},
+                "Name": "nomad.runtime.num_goroutines",
+                "Value": 56,
+            }],
+            "Points": [{
+                "Name": "nomad.worker.invoke_scheduler",
+                "Points": [0.25, 0.5],
+            }],
+            "Samples": [{
+                "Count": 20,
+                "Labels": {"host": "nomad-1"},
+                "Max": 0.03544100001454353,
+                "Mean": 0.023678050097078084,
+                "Min": 0.00956599973142147,
+                "Name": "nomad.memberlist.gossip",
+                "Rate": 0.04735610019415617,
+                "Stddev": 0.005445327744645669,
+                "Sum": 0.4735610019415617,
+            }],
+            "Timestamp": "2017-08-10 05:26:50 +0000 UTC",
+        }))
+        .await;
+
+    let summary = nomad.client().metrics().summary(None).await.unwrap();
+
+    assert_eq!(summary.timestamp, "2017-08-10 05:26:50 +0000 UTC");
+    assert_eq!(summary.gauges.len(), 2);
+    assert_eq!(summary.gauges[0].labels["datacenter"], "dc1");
+    assert!(summary.gauges[1].labels.is_empty());
+    assert_eq!(summary.gauges[1].value, 56.0);
+    assert_eq!(summary.points[0].points, vec![0.25, 0.5]);
+    assert_eq!(summary.counters[0].name, "nomad.nomad.rpc.query");
+    assert_eq!(summary.counters[0].count, 1);
+    assert_eq!(summary.samples[0].count, 20);
+    assert_eq!(summary.samples[0].labels["host"], "nomad-1");
+}
+
+#[tokio::test]
+async fn prometheus_returns_exposition_text() {
+    let exposition = "\
+# HELP nomad_client_allocations_blocked nomad_client_allocations_blocked
+# TYPE nomad_client_allocations_blocked gauge
+nomad_client_allocations_blocked{datacenter=\"dc1\",node_class=\"none\"} 0
+# HELP nomad_nomad_rpc_query nomad_nomad_rpc_query
+# TYPE nomad_nomad_rpc_query counter
+nomad_nomad_rpc_query 17
+";
+    let nomad = MockNomad::start().await;
+    nomad
+        .expect_get("/v1/metrics")
+        .with_query("format", "prometheus")
+        .returns(
+            ResponseTemplate::new(200)
+                .insert_header("Content-Type", "text/plain; version=0.0.4; charset=utf-8")
+                .set_body_string(exposition),
+        )
+        .await;
+
+    let text = nomad.client().metrics().prometheus(None).await.unwrap();
+    assert_eq!(text, exposition);
+}
@@ -0,0 +1,117 @@
+mod common;
+
+use common::MockNomad;
+use nomad_rs_api::job::JobStub;
+use nomad_rs_api::namespace::{Namespace, group_by_namespace};
+use nomad_rs_api::option::WriteOptions;
+use serde_json::json;
+use wiremock::ResponseTemplate;
+
+fn job_stub(id: &str, namespace: &str) -> JobStub {
+    serde_json::from_value(json!({
+        "ID": id,
+        "Name": id,
+        "Namespace": namespace,
+        "Datacenters": ["dc1"],
+        "Type": "service",
+        "Priority": 50,
+        "Periodic": false,
+        "ParameterizedJob": false,
+        "Stop": false,
+        "Status": "running",
+        "StatusDescription": "",
+        "CreateIndex": 10,
+        "ModifyIndex": 12,
+        "JobModifyIndex": 10,
+        "SubmitTime": 1700000000000000000i64,
+    }))
+    .unwrap()
+}
+
+#[test]
+fn group_by_namespace_preserves_order() {
+    let grouped = group_by_namespace(vec![
+        job_stub("api", "platform"),
+        job_stub("etl", "data"),
+        job_stub("web", "default"),
+        job_stub("gateway", "platform"),
+        job_stub("reports", "data"),
+    ]);
+
+    assert_eq!(grouped.len(), 3);
+    let ids = |namespace: &str| -> Vec<String> {
+        grouped[namespace]
+            .iter()
+            .map(|job| job.id.clone())
+            .collect()
+    };
+    assert_eq!(ids("platform"), vec!["api", "gateway"]);
+    assert_eq!(ids("data"), vec!["etl", "reports"]);
+    assert_eq!(ids("default"), vec!["web"]);
+}
+
+#[test]
+fn group_by_namespace_of_nothing_is_empty() {
+    assert!(group_by_namespace(Vec::<JobStub>::new()).is_empty());
+}
+
+#[tokio::test]
+async fn create_and_fetch_returns_stored_indexes() {
+    let nomad = MockNomad::start().await;
+    nomad
+        .expect_put("/v1/namespace")
+        .with_header("X-Nomad-Token", "secret")
+        .returns(ResponseTemplate::new(200))
+        .await;
+    nomad
+        .expect_get("/v1/namespace/platform")
+        .with_header("X-Nomad-Token", "secret")
+        .returns_json(json!({
+            "Name": "platform",
+            "Description": "Platform services",
+            "Quota": "",
+            "Capabilities": null,
+            "Meta": null,
+            "CreateIndex": 64,
+            "ModifyIndex": 64,
+        }))
+        .await;
+
+    let mut namespace = Namespace::new("platform".to_string());
+    namespace.description = Some("Platform services".to_string());
+    let opts = WriteOptions::new().with_auth_token("secret".to_string());
+    let stored = nomad
+        .client()
+        .namespace()
+        .create_and_fetch(&namespace, Some(opts))
+        .await
+        .unwrap();
+
+    assert_eq!(stored.create_index, Some(64));
+    assert_eq!(stored.modify_index, Some(64));
+
+    let requests = nomad.received_requests().await;
+    let methods: Vec<_> = requests
+        .iter()
+        .map(|request| request.method.as_str())
+        .collect();
+    assert_eq!(methods, vec!["PUT", "GET"]);
+}
+
+#[tokio::test]
+async fn create_sends_idempotency_token() {
+    let nomad = MockNomad::start().await;
+    nomad
+        .expect_put("/v1/namespace")
+        .with_query("idempotency_token", "create-platform-1")
+        .returns(ResponseTemplate::new(200))
+        .await;
+
+    let opts = WriteOptions::new().with_idempotency_token("create-platform-1".to_string());
+    nomad
+        .client()
+        .namespace()
+        .create(&Namespace::new("platform".to_string()), Some(opts))
+        .await
+        .unwrap();
+}
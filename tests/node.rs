@@ -0,0 +1,163 @@
+mod common;
+
+use common::MockNomad;
+use nomad_rs_api::ClientError;
+use nomad_rs_api::node::DrainSpec;
+use nomad_rs_api::option::QueryOptions;
+use serde_json::json;
+use wiremock::ResponseTemplate;
+
+const NODE_ID: &str = "fb2170a8-257d-3c64-b14d-bc06cc94e34c";
+
+fn drain_response() -> serde_json::Value {
+    json!({
+        "NodeModifyIndex": 42,
+        "EvalIDs": ["5456bd7a-9fc0-c0dd-6131-cbee77f57577"],
+        "EvalCreateIndex": 42,
+    })
+}
+
+#[tokio::test]
+async fn update_drain_sends_deadline_in_nanoseconds() {
+    let nomad = MockNomad::start().await;
+    nomad
+        .expect_post(&format!("/v1/node/{}/drain", NODE_ID))
+        .with_json(json!({
+            "NodeID": NODE_ID,
+            "DrainSpec": {
+                "Deadline": 3_600_000_000_000i64,
+                "IgnoreSystemJobs": true,
+            },
+            "MarkEligible": false,
+        }))
+        .returns_json(drain_response())
+        .await;
+
+    let spec = DrainSpec::with_deadline(time::Duration::hours(1), true);
+    let response = nomad
+        .client()
+        .node()
+        .update_drain(NODE_ID, Some(spec), false, None)
+        .await
+        .unwrap();
+
+    assert_eq!(response.node_modify_index, 42);
+    assert_eq!(response.eval_ids.unwrap().len(), 1);
+}
+
+#[test]
+fn drain_spec_converts_deadline_to_nanoseconds() {
+    let spec = DrainSpec::with_deadline(time::Duration::milliseconds(1500), false);
+    assert_eq!(spec.deadline, 1_500_000_000);
+    assert!(!spec.ignore_system_jobs);
+
+    let spec = DrainSpec::with_deadline(time::Duration::nanoseconds(-1), true);
+    assert_eq!(spec.deadline, -1);
+}
+
+#[tokio::test]
+async fn update_drain_cancels_with_null_spec() {
+    let nomad = MockNomad::start().await;
+    nomad
+        .expect_post(&format!("/v1/node/{}/drain", NODE_ID))
+        .with_json(json!({
+            "NodeID": NODE_ID,
+            "DrainSpec": null,
+            "MarkEligible": true,
+        }))
+        .returns_json(drain_response())
+        .await;
+
+    nomad
+        .client()
+        .node()
+        .update_drain(NODE_ID, None, true, None)
+        .await
+        .unwrap();
+}
+
+#[tokio::test]
+async fn purge_posts_to_node() {
+    let nomad = MockNomad::start().await;
+    nomad
+        .expect_post(&format!("/v1/node/{}/purge", NODE_ID))
+        .returns_json(json!({
+            "NodeModifyIndex": 0,
+            "EvalIDs": [
+                "5456bd7a-9fc0-c0dd-6131-cbee77f57577",
+                "a4d7b2c3-9f0e-4d1a-8b6c-2e5f7a9c1d3e",
+            ],
+            "EvalCreateIndex": 57,
+        }))
+        .await;
+
+    let response = nomad.client().node().purge(NODE_ID, None).await.unwrap();
+    assert_eq!(response.eval_ids.unwrap().len(), 2);
+    assert_eq!(response.eval_create_index, 57);
+}
+
+#[tokio::test]
+async fn purge_requires_node_id() {
+    let nomad = MockNomad::start().await;
+
+    let err = nomad.client().node().purge("", None).await.unwrap_err();
+    assert!(matches!(err, ClientError::InvalidInputError(_)));
+    assert!(nomad.received_requests().await.is_empty());
+}
+
+#[tokio::test]
+async fn list_draining_filters_on_drain() {
+    let nomad = MockNomad::start().await;
+    nomad
+        .expect_get("/v1/nodes")
+        .with_query("filter", "Drain == true")
+        .returns_json(json!([]))
+        .await;
+
+    let nodes = nomad.client().node().list_draining(None).await.unwrap();
+    assert!(nodes.is_empty());
+}
+
+#[tokio::test]
+async fn list_ineligible_combines_existing_filter() {
+    let nomad = MockNomad::start().await;
+    nomad
+        .expect_get("/v1/nodes")
+        .with_query(
+            "filter",
+            r#"(Datacenter == "dc1" or Datacenter == "dc2") and SchedulingEligibility == "ineligible""#,
+        )
+        .returns_json(json!([]))
+        .await;
+
+    let opts = QueryOptions::new()
+        .with_filter(r#"Datacenter == "dc1" or Datacenter == "dc2""#.to_string());
+    let nodes = nomad
+        .client()
+        .node()
+        .list_ineligible(Some(opts))
+        .await
+        .unwrap();
+    assert!(nodes.is_empty());
+}
+
+#[tokio::test]
+async fn node_gc_gets_client_gc_with_node_id() {
+    let nomad = MockNomad::start().await;
+    nomad
+        .expect_get("/v1/client/gc")
+        .with_query("node_id", NODE_ID)
+        .returns(ResponseTemplate::new(200))
+        .await;
+
+    nomad.client().node().node_gc(NODE_ID, None).await.unwrap();
+}
+
+#[tokio::test]
+async fn node_gc_requires_node_id() {
+    let nomad = MockNomad::start().await;
+
+    let err = nomad.client().node().node_gc("", None).await.unwrap_err();
+    assert!(matches!(err, ClientError::InvalidInputError(_)));
+    assert!(nomad.received_requests().await.is_empty());
+}
@@ -0,0 +1,196 @@
+mod common;
+
+use common::MockNomad;
+use nomad_rs_api::ClientError;
+use nomad_rs_api::node_pool::NodePool;
+use nomad_rs_api::option::QueryOptions;
+use serde_json::json;
+use wiremock::ResponseTemplate;
+
+fn server_error_message(err: ClientError) -> String {
+    match err {
+        ClientError::ServerError { message, .. } => message.unwrap_or_default(),
+        err => panic!("unexpected error: {err:?}"),
+    }
+}
+
+#[tokio::test]
+async fn missing_endpoints_explain_version_requirement() {
+    // Before Nomad 1.6, the node pool paths are routed to the node endpoints.
+    let nomad = MockNomad::start().await;
+    nomad
+        .expect_get("/v1/node/pool/gpu")
+        .returns_status(404, "node not found")
+        .await;
+    nomad
+        .expect_put("/v1/node/pool")
+        .returns_status(405, "Invalid method")
+        .await;
+
+    let client = nomad.client();
+    let err = client.node_pool().get("gpu", None).await.unwrap_err();
+    assert!(matches!(err, ClientError::ServerError { status: 404, .. }));
+    let message = server_error_message(err);
+    assert!(
+        message.starts_with("Node pools require Nomad 1.6"),
+        "{message}"
+    );
+    assert!(message.ends_with("node not found"), "{message}");
+
+    let err = client
+        .node_pool()
+        .create(&NodePool::new("gpu".to_string()), None)
+        .await
+        .unwrap_err();
+    let message = server_error_message(err);
+    assert!(
+        message.starts_with("Node pools require Nomad 1.6"),
+        "{message}"
+    );
+}
+
+#[tokio::test]
+async fn not_implemented_is_enterprise_only() {
+    let nomad = MockNomad::start().await;
+    nomad
+        .expect_get("/v1/node/pools")
+        .returns_status(501, "Nomad Enterprise only endpoint")
+        .await;
+
+    let err = nomad.client().node_pool().list(None).await.unwrap_err();
+    match err {
+        ClientError::EnterpriseOnly(message) => {
+            assert!(
+                message.starts_with("Node pools require Nomad 1.6"),
+                "{message}"
+            )
+        }
+        err => panic!("unexpected error: {err:?}"),
+    }
+}
+
+#[tokio::test]
+async fn missing_node_pool_is_not_rewritten() {
+    let nomad = MockNomad::start().await;
+    nomad
+        .expect_get("/v1/node/pool/gpu")
+        .returns_status(404, "node pool not found")
+        .await;
+
+    let err = nomad
+        .client()
+        .node_pool()
+        .get("gpu", None)
+        .await
+        .unwrap_err();
+    match err {
+        ClientError::ServerError {
+            status: 404,
+            message,
+            body,
+        } => {
+            assert!(message.is_none_or(|message| !message.contains("Nomad 1.6")));
+            assert_eq!(body, "node pool not found");
+        }
+        err => panic!("unexpected error: {err:?}"),
+    }
+}
+
+fn page(items: serde_json::Value, next_token: &str) -> ResponseTemplate {
+    ResponseTemplate::new(200)
+        .insert_header("X-Nomad-Index", "57")
+        .insert_header("X-Nomad-NextToken", next_token)
+        .set_body_json(items)
+}
+
+#[tokio::test]
+async fn list_nodes_returns_pool_nodes_and_next_token() {
+    let nomad = MockNomad::start().await;
+    nomad
+        .expect_get("/v1/node/pool/gpu/nodes")
+        .with_query("per_page", "1")
+        .returns(page(
+            json!([{
+                "ID": "fb2170a8-257d-3c64-b14d-bc06cc94e34c",
+                "Name": "gpu-1",
+                "Address": "10.0.3.5",
+                "Datacenter": "dc1",
+                "NodePool": "gpu",
+                "NodeClass": "",
+                "Version": "1.8.0",
+                "Status": "ready",
+                "StatusDescription": "",
+                "SchedulingEligibility": "eligible",
+                "Drain": false,
+                "CreateIndex": 12,
+                "ModifyIndex": 57,
+            }]),
+            "gpu-2",
+        ))
+        .await;
+
+    let opts = QueryOptions::new().with_per_page(1);
+    let (nodes, meta) = nomad
+        .client()
+        .node_pool()
+        .list_nodes_with_meta("gpu", Some(opts))
+        .await
+        .unwrap();
+
+    assert_eq!(nodes.len(), 1);
+    assert_eq!(nodes[0].node_pool.as_deref(), Some("gpu"));
+    assert_eq!(meta.last_index, 57);
+    assert_eq!(meta.next_token.as_deref(), Some("gpu-2"));
+}
+
+#[tokio::test]
+async fn list_jobs_returns_pool_jobs_and_next_token() {
+    let nomad = MockNomad::start().await;
+    nomad
+        .expect_get("/v1/node/pool/gpu/jobs")
+        .with_query("next_token", "default.train")
+        .returns(page(
+            json!([{
+                "ID": "train",
+                "Name": "train",
+                "Namespace": "default",
+                "Datacenters": ["dc1"],
+                "Type": "batch",
+                "Priority": 50,
+                "Periodic": false,
+                "ParameterizedJob": false,
+                "Stop": false,
+                "Status": "running",
+                "StatusDescription": "",
+                "CreateIndex": 30,
+                "ModifyIndex": 31,
+                "JobModifyIndex": 30,
+                "SubmitTime": 1700000000000000000i64,
+            }]),
+            "",
+        ))
+        .await;
+
+    let opts = QueryOptions::new().with_next_token("default.train".to_string());
+    let (jobs, meta) = nomad
+        .client()
+        .node_pool()
+        .list_jobs_with_meta("gpu", Some(opts))
+        .await
+        .unwrap();
+
+    assert_eq!(jobs.len(), 1);
+    assert_eq!(jobs[0].id, "train");
+    assert_eq!(meta.next_token, None);
+}
+
+#[tokio::test]
+async fn member_listings_require_pool_name() {
+    let nomad = MockNomad::start().await;
+    let client = nomad.client();
+
+    let err = client.node_pool().list_nodes("", None).await.unwrap_err();
+    assert!(matches!(err, ClientError::InvalidInputError(_)));
+    let err = client.node_pool().list_jobs("", None).await.unwrap_err();
+    assert!(matches!(err, ClientError::InvalidInputError(_)));
+}
@@ -0,0 +1,310 @@
+mod common;
+
+use common::MockNomad;
+use nomad_rs_api::ClientError;
+use serde_json::json;
+use wiremock::ResponseTemplate;
+
+#[tokio::test]
+async fn raft_get_configuration_returns_servers() {
+    let nomad = MockNomad::start().await;
+    nomad
+        .expect_get("/v1/operator/raft/configuration")
+        .returns_json(json!({
+            "Index": 0,
+            "Servers": [
+                {
+                    "Address": "10.0.1.5:4647",
+                    "ID": "b5a2a4a0-8ad4-7e8a-6f8c-4f0c9c2c2a1e",
+                    "Leader": true,
+                    "Node": "server-1.global",
+                    "RaftProtocol": "3",
+                    "Voter": true,
+                },
+                {
+                    "Address": "10.0.1.6:4647",
+                    "ID": "2c1c7a2e-96b5-b3c4-2a3e-1c9e8c7d6f5a",
+                    "Leader": false,
+                    "Node": "server-2.global",
+                    "RaftProtocol": "3",
+                    "Voter": true,
+                },
+                {
+                    "Address": "10.0.1.7:4647",
+                    "ID": "7e4b2d8a-3f1c-9e6b-5a2d-8c3f1e7b4a9d",
+                    "Leader": false,
+                    "Node": "server-3.global",
+                    "RaftProtocol": "3",
+                    "Voter": false,
+                },
+            ],
+        }))
+        .await;
+
+    let config = nomad
+        .client()
+        .operator()
+        .raft_get_configuration(None)
+        .await
+        .unwrap();
+
+    assert_eq!(config.servers.len(), 3);
+    let leaders: Vec<&str> = config
+        .servers
+        .iter()
+        .filter(|server| server.leader)
+        .map(|server| server.node.as_str())
+        .collect();
+    assert_eq!(leaders, vec!["server-1.global"]);
+    assert_eq!(config.servers[1].address, "10.0.1.6:4647");
+    assert_eq!(config.servers[2].raft_version, "3");
+    assert!(!config.servers[2].voter);
+}
+
+#[tokio::test]
+async fn raft_remove_peer_by_id_sends_id() {
+    let nomad = MockNomad::start().await;
+    nomad
+        .expect_delete("/v1/operator/raft/peer")
+        .with_query("id", "7e4b2d8a-3f1c-9e6b-5a2d-8c3f1e7b4a9d")
+        .returns(ResponseTemplate::new(200))
+        .await;
+
+    nomad
+        .client()
+        .operator()
+        .raft_remove_peer_by_id("7e4b2d8a-3f1c-9e6b-5a2d-8c3f1e7b4a9d", None)
+        .await
+        .unwrap();
+}
+
+#[tokio::test]
+async fn raft_remove_peer_by_id_requires_id() {
+    let nomad = MockNomad::start().await;
+
+    let err = nomad
+        .client()
+        .operator()
+        .raft_remove_peer_by_id("", None)
+        .await
+        .unwrap_err();
+    assert!(matches!(err, ClientError::InvalidInputError(_)));
+}
+
+#[tokio::test]
+async fn keyring_rotate_returns_new_key() {
+    let nomad = MockNomad::start().await;
+    nomad
+        .expect_post("/v1/operator/keyring/rotate")
+        .with_query("full", "true")
+        .returns_json(json!({
+            "Key": {
+                "Algorithm": "aes256-gcm",
+                "CreateTime": 1700000000123456789i64,
+                "CreateIndex": 118,
+                "KeyID": "d1e5b5c3-2c9b-4f0f-8f1e-6f9e4c4a7b21",
+                "ModifyIndex": 118,
+                "State": "active",
+            },
+            "Index": 118,
+        }))
+        .await;
+
+    let key = nomad
+        .client()
+        .operator()
+        .keyring_rotate(true, None)
+        .await
+        .unwrap();
+
+    assert_eq!(key.key_id, "d1e5b5c3-2c9b-4f0f-8f1e-6f9e4c4a7b21");
+    assert_eq!(key.algorithm, "aes256-gcm");
+    assert_eq!(key.state, "active");
+    assert_eq!(key.create_time, 1700000000123456789);
+    assert_eq!(key.create_index, 118);
+}
+
+fn autopilot_config() -> serde_json::Value {
+    json!({
+        "CleanupDeadServers": true,
+        "LastContactThreshold": "200ms",
+        "MaxTrailingLogs": 250,
+        "MinQuorum": 3,
+        "ServerStabilizationTime": "10s",
+        "EnableRedundancyZones": false,
+        "DisableUpgradeMigration": false,
+        "EnableCustomUpgrades": false,
+        "CreateIndex": 4,
+        "ModifyIndex": 57,
+    })
+}
+
+#[tokio::test]
+async fn autopilot_configuration_round_trips() {
+    let nomad = MockNomad::start().await;
+    nomad
+        .expect_get("/v1/operator/autopilot/configuration")
+        .returns_json(autopilot_config())
+        .await;
+    nomad
+        .expect_put("/v1/operator/autopilot/configuration")
+        .with_query("cas", "57")
+        .with_json(autopilot_config())
+        .returns_json(json!(true))
+        .await;
+
+    let client = nomad.client();
+    let config = client
+        .operator()
+        .autopilot_get_configuration(None)
+        .await
+        .unwrap();
+    assert!(config.cleanup_dead_servers);
+    assert_eq!(config.last_contact_threshold, "200ms");
+    assert_eq!(config.max_trailing_logs, 250);
+    assert_eq!(config.server_stabilization_time, "10s");
+
+    let updated = client
+        .operator()
+        .autopilot_set_configuration(&config, config.modify_index, None)
+        .await
+        .unwrap();
+    assert!(updated);
+}
+
+#[tokio::test]
+async fn autopilot_health_reports_unhealthy_servers() {
+    let nomad = MockNomad::start().await;
+    nomad
+        .expect_get("/v1/operator/autopilot/health")
+        .returns(ResponseTemplate::new(429).set_body_json(json!({
+            "Healthy": false,
+            "FailureTolerance": 0,
+            "Servers": [
+                {
+                    "ID": "b5a2a4a0-8ad4-7e8a-6f8c-4f0c9c2c2a1e",
+                    "Name": "server-1.global",
+                    "Address": "10.0.1.5:4647",
+                    "SerfStatus": "alive",
+                    "Version": "1.8.0",
+                    "Leader": true,
+                    "LastContact": "0s",
+                    "LastTerm": 3,
+                    "LastIndex": 812,
+                    "Healthy": true,
+                    "Voter": true,
+                    "StableSince": "2024-06-01T10:15:30.123456789Z",
+                },
+                {
+                    "ID": "2c1c7a2e-96b5-b3c4-2a3e-1c9e8c7d6f5a",
+                    "Name": "server-2.global",
+                    "Address": "10.0.1.6:4647",
+                    "SerfStatus": "failed",
+                    "Version": "1.8.0",
+                    "Leader": false,
+                    "LastContact": "12.5s",
+                    "LastTerm": 3,
+                    "LastIndex": 790,
+                    "Healthy": false,
+                    "Voter": true,
+                    "StableSince": "0001-01-01T00:00:00Z",
+                },
+            ],
+        })))
+        .await;
+
+    let health = nomad
+        .client()
+        .operator()
+        .autopilot_health(None)
+        .await
+        .unwrap();
+
+    assert!(!health.healthy);
+    assert_eq!(health.failure_tolerance, 0);
+    assert_eq!(health.servers.len(), 2);
+    assert!(health.servers[0].leader);
+    assert_eq!(health.servers[1].serf_status, "failed");
+    assert_eq!(health.servers[1].last_contact, "12.5s");
+    assert!(!health.servers[1].healthy);
+}
+
+#[tokio::test]
+async fn license_get_parses_license() {
+    let nomad = MockNomad::start().await;
+    nomad
+        .expect_get("/v1/operator/license")
+        .returns_json(json!({
+            "License": {
+                "LicenseID": "6cd8ad56-2f7b-4a8c-8f64-2b0d43a4b1c9",
+                "CustomerID": "0b2f1f3e-5c0d-4b45-9f7a-3d7c1b5e8a24",
+                "InstallationID": "*",
+                "IssueTime": "2024-01-15T09:00:00.000000000Z",
+                "StartTime": "2024-01-15T00:00:00Z",
+                "ExpirationTime": "2025-01-15T00:00:00Z",
+                "TerminationTime": "2025-01-15T00:00:00Z",
+                "Product": "nomad",
+                "Flags": {"modules": ["governance-policy", "multicluster-and-efficiency"]},
+                "Modules": ["governance-policy", "multicluster-and-efficiency"],
+                "Features": ["Audit Logging", "Namespaces", "Resource Quotas"],
+            },
+            "ConfigOutdated": false,
+        }))
+        .await;
+
+    let reply = nomad.client().operator().license_get(None).await.unwrap();
+
+    assert_eq!(
+        reply.license.license_id,
+        "6cd8ad56-2f7b-4a8c-8f64-2b0d43a4b1c9"
+    );
+    assert_eq!(reply.license.product, "nomad");
+    assert_eq!(reply.license.expiration_time.year(), 2025);
+    assert_eq!(reply.license.features.as_ref().unwrap().len(), 3);
+    assert!(!reply.config_outdated);
+}
+
+#[tokio::test]
+async fn license_get_without_license_is_enterprise_only() {
+    let nomad = MockNomad::start().await;
+    nomad
+        .expect_get("/v1/operator/license")
+        .returns_json(json!({"License": null, "ConfigOutdated": false}))
+        .await;
+
+    let err = nomad
+        .client()
+        .operator()
+        .license_get(None)
+        .await
+        .unwrap_err();
+    assert!(matches!(err, ClientError::EnterpriseOnly(_)));
+}
+
+#[tokio::test]
+async fn snapshot_save_returns_raw_bytes() {
+    // The start of a gzipped archive, which is not valid UTF-8 or JSON.
+    let snapshot = vec![
+        0x1f, 0x8b, 0x08, 0x00, 0x00, 0x00, 0x00, 0x00, 0xff, 0xfe, 0x00,
+    ];
+    let nomad = MockNomad::start().await;
+    nomad
+        .expect_get("/v1/operator/snapshot")
+        .returns(
+            ResponseTemplate::new(200)
+                .insert_header("X-Nomad-Index", "42")
+                .set_body_raw(snapshot.clone(), "application/octet-stream"),
+        )
+        .await;
+
+    let saved = nomad.client().operator().snapshot_save(None).await.unwrap();
+    assert_eq!(saved.as_ref(), snapshot.as_slice());
+
+    // Only the HTTP client's default "Accept: */*" may be sent.
+    let requests = nomad.received_requests().await;
+    let accept: Vec<_> = requests[0].headers.get_all("accept").iter().collect();
+    assert!(
+        accept.iter().all(|value| *value == "*/*"),
+        "unexpected accept header: {accept:?}"
+    );
+}
@@ -0,0 +1,116 @@
+mod common;
+
+use common::MockNomad;
+use nomad_rs_api::ClientError;
+use nomad_rs_api::option::QueryOptions;
+use nomad_rs_api::quota::{QuotaLimit, QuotaResources, QuotaSpec};
+use serde_json::json;
+
+fn quota_spec() -> QuotaSpec {
+    QuotaSpec::new(
+        "default-quota".to_string(),
+        vec![QuotaLimit {
+            region: "global".to_string(),
+            region_limit: Some(QuotaResources {
+                cpu: Some(2500),
+                memory_mb: Some(1000),
+                ..QuotaResources::default()
+            }),
+            variables_limit: None,
+            hash: None,
+        }],
+    )
+}
+
+#[tokio::test]
+async fn create_posts_quota_spec() {
+    let nomad = MockNomad::start().await;
+    nomad
+        .expect_post("/v1/quota")
+        .with_json(json!({
+            "Name": "default-quota",
+            "Description": null,
+            "Limits": [{
+                "Region": "global",
+                "RegionLimit": {
+                    "CPU": 2500,
+                    "Cores": null,
+                    "MemoryMB": 1000,
+                    "MemoryMaxMB": null,
+                },
+                "VariablesLimit": null,
+                "Hash": null,
+            }],
+            "CreateIndex": null,
+            "ModifyIndex": null,
+        }))
+        .returns_json(json!(null))
+        .await;
+
+    nomad
+        .client()
+        .quota()
+        .create(&quota_spec(), None)
+        .await
+        .unwrap();
+}
+
+#[tokio::test]
+async fn create_requires_name() {
+    let nomad = MockNomad::start().await;
+    let mut spec = quota_spec();
+    spec.name.clear();
+
+    let err = nomad
+        .client()
+        .quota()
+        .create(&spec, None)
+        .await
+        .unwrap_err();
+    assert!(matches!(err, ClientError::InvalidInputError(_)));
+    assert!(nomad.received_requests().await.is_empty());
+}
+
+#[tokio::test]
+async fn list_returns_quota_specs() {
+    let nomad = MockNomad::start().await;
+    nomad
+        .expect_get("/v1/quotas")
+        .with_query("prefix", "default")
+        .returns_json(json!([{
+            "Name": "default-quota",
+            "Description": "Limit the shared default namespace",
+            "Limits": [{
+                "Region": "global",
+                "RegionLimit": {"CPU": 2500, "MemoryMB": 1000},
+                "VariablesLimit": 1000,
+                "Hash": "NLOoV2WBU8ieJIrYXXx8NRb5C2xU61pVVWRDLEIMxlU=",
+            }],
+            "CreateIndex": 8,
+            "ModifyIndex": 56,
+        }]))
+        .await;
+
+    let opts = QueryOptions::new().with_prefix("default".to_string());
+    let quotas = nomad.client().quota().list(Some(opts)).await.unwrap();
+    assert_eq!(quotas.len(), 1);
+    assert_eq!(quotas[0].name, "default-quota");
+    let limit = &quotas[0].limits[0];
+    assert_eq!(limit.region_limit.as_ref().unwrap().cpu, Some(2500));
+    assert_eq!(limit.variables_limit, Some(1000));
+}
+
+#[tokio::test]
+async fn community_edition_is_enterprise_only() {
+    let nomad = MockNomad::start().await;
+    nomad
+        .expect_get("/v1/quotas")
+        .returns_status(501, "Nomad Enterprise only endpoint")
+        .await;
+
+    let err = nomad.client().quota().list(None).await.unwrap_err();
+    assert!(
+        matches!(err, ClientError::EnterpriseOnly(ref message) if message.contains("Enterprise")),
+        "unexpected error: {err:?}"
+    );
+}
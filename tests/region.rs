@@ -0,0 +1,160 @@
+mod common;
+
+use common::MockNomad;
+use nomad_rs_api::option::{QueryOptions, WriteOptions};
+use nomad_rs_api::{ClientError, Nomad};
+use serde_json::json;
+use wiremock::ResponseTemplate;
+
+#[tokio::test]
+async fn list_returns_regions() {
+    let nomad = MockNomad::start().await;
+    nomad
+        .expect_get("/v1/regions")
+        .with_query("region", "global")
+        .returns_json(json!(["eu-west", "us-east"]))
+        .await;
+
+    let regions = nomad.client().region().list().await.unwrap();
+    assert_eq!(regions, vec!["eu-west", "us-east"]);
+}
+
+#[tokio::test]
+async fn for_each_region_collects_per_region_results() {
+    let nomad = MockNomad::start().await;
+    nomad
+        .expect_get("/v1/regions")
+        .returns_json(json!(["eu-west", "us-east", "ap-south"]))
+        .await;
+    nomad
+        .expect_get("/v1/status/leader")
+        .with_query("region", "eu-west")
+        .returns_json(json!("10.0.1.5:4647"))
+        .await;
+    nomad
+        .expect_get("/v1/status/leader")
+        .with_query("region", "us-east")
+        .returns_json(json!("10.0.2.5:4647"))
+        .await;
+    nomad
+        .expect_get("/v1/status/leader")
+        .with_query("region", "ap-south")
+        .returns_status(500, "No cluster leader")
+        .await;
+
+    let client = nomad.client();
+    let results = client
+        .region()
+        .for_each_region(|region, opts| {
+            let client = &client;
+            async move {
+                assert_eq!(opts.region.as_deref(), Some(region.as_str()));
+                client.status().get_leader(Some(opts)).await
+            }
+        })
+        .await
+        .unwrap();
+
+    assert_eq!(results.len(), 3);
+    assert_eq!(results["eu-west"].as_deref().unwrap(), "10.0.1.5:4647");
+    assert_eq!(results["us-east"].as_deref().unwrap(), "10.0.2.5:4647");
+    assert!(matches!(
+        results["ap-south"],
+        Err(ClientError::ServerError { status: 500, .. })
+    ));
+}
+
+/// Get the values of every `region` query parameter of the request.
+fn region_params(request: &wiremock::Request) -> Vec<String> {
+    request
+        .url
+        .query_pairs()
+        .filter(|(name, _)| name == "region")
+        .map(|(_, value)| value.into_owned())
+        .collect()
+}
+
+#[tokio::test]
+async fn region_param_is_sent_once() {
+    let nomad = MockNomad::start().await;
+    nomad
+        .expect_get("/v1/status/leader")
+        .times(2)
+        .returns_json(json!("10.0.1.5:4647"))
+        .await;
+    nomad
+        .expect_put("/v1/system/gc")
+        .times(2)
+        .returns(ResponseTemplate::new(200))
+        .await;
+
+    let client = nomad.client();
+    client.status().get_leader(None).await.unwrap();
+    client
+        .status()
+        .get_leader(Some(QueryOptions::new().with_region("eu-west".to_string())))
+        .await
+        .unwrap();
+    client.system().garbage_collect(None).await.unwrap();
+    client
+        .system()
+        .garbage_collect(Some(WriteOptions::new().with_region("eu-west".to_string())))
+        .await
+        .unwrap();
+
+    let requests = nomad.received_requests().await;
+    let regions: Vec<_> = requests.iter().map(region_params).collect();
+    assert_eq!(
+        regions,
+        vec![
+            vec!["global"],
+            vec!["eu-west"],
+            vec!["global"],
+            vec!["eu-west"],
+        ]
+    );
+}
+
+#[tokio::test]
+async fn auto_region_uses_agent_region() {
+    let nomad = MockNomad::start().await;
+    nomad
+        .expect_get("/v1/agent/self")
+        .times(1)
+        .returns_json(json!({
+            "config": {"Region": "us-east", "Datacenter": "dc1"},
+            "member": {
+                "Name": "nomad-1.us-east",
+                "Addr": "10.0.1.5",
+                "Port": 4648,
+                "Tags": {"dc": "dc1", "region": "us-east", "role": "nomad"},
+                "Status": "alive",
+                "ProtocolMin": 1,
+                "ProtocolMax": 5,
+                "ProtocolCur": 2,
+                "DelegateMin": 2,
+                "DelegateMax": 5,
+                "DelegateCur": 4,
+            },
+            "stats": null,
+        }))
+        .await;
+    nomad
+        .expect_get("/v1/status/leader")
+        .with_query("region", "us-east")
+        .returns_json(json!("10.0.1.5:4647"))
+        .await;
+
+    let client = Nomad::with_auto_region(nomad.config()).await.unwrap();
+    client.status().get_leader(None).await.unwrap();
+
+    // The resolved region is cached, so the agent is only queried once.
+    assert_eq!(client.resolve_region().await.unwrap(), "us-east");
+    let agent_requests = nomad
+        .received_requests()
+        .await
+        .iter()
+        .filter(|request| request.url.path() == "/v1/agent/self")
+        .count();
+    assert_eq!(agent_requests, 1);
+}
@@ -0,0 +1,78 @@
+mod common;
+
+use common::MockNomad;
+use serde_json::json;
+
+fn policy_stub(id: &str, job: &str, create_index: u64) -> serde_json::Value {
+    json!({
+        "ID": id,
+        "Enabled": true,
+        "Type": "horizontal",
+        "Target": {
+            "Namespace": "default",
+            "Job": job,
+            "Group": "cache",
+        },
+        "CreateIndex": create_index,
+        "ModifyIndex": create_index,
+    })
+}
+
+#[tokio::test]
+async fn list_policies_filters_by_job() {
+    let nomad = MockNomad::start().await;
+    nomad
+        .expect_get("/v1/scaling/policies")
+        .with_query("job", "example")
+        .returns_json(json!([
+            policy_stub("31a53813-24df-b2ad-77dc-1b4bad4e7dca", "example", 12),
+            policy_stub("9b1f6d3a-0a8e-4c57-a3b2-5f8c6e2d7a41", "example", 30),
+        ]))
+        .await;
+
+    let policies = nomad
+        .client()
+        .scaling()
+        .list_policies(Some("example"), None, None)
+        .await
+        .unwrap();
+
+    assert_eq!(policies.len(), 2);
+    assert_eq!(policies[0].create_index, 30);
+    assert!(
+        policies
+            .iter()
+            .all(|policy| policy.target["Job"] == "example")
+    );
+
+    let requests = nomad.received_requests().await;
+    assert!(
+        requests[0]
+            .url
+            .query_pairs()
+            .all(|(name, _)| name != "type")
+    );
+}
+
+#[tokio::test]
+async fn list_policies_filters_by_job_and_type() {
+    let nomad = MockNomad::start().await;
+    nomad
+        .expect_get("/v1/scaling/policies")
+        .with_query("job", "example")
+        .with_query("type", "horizontal")
+        .returns_json(json!([policy_stub(
+            "31a53813-24df-b2ad-77dc-1b4bad4e7dca",
+            "example",
+            12
+        )]))
+        .await;
+
+    let policies = nomad
+        .client()
+        .scaling()
+        .list_policies(Some("example"), Some("horizontal"), None)
+        .await
+        .unwrap();
+    assert_eq!(policies[0].policy_type, "horizontal");
+}
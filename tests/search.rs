@@ -0,0 +1,83 @@
+mod common;
+
+use common::MockNomad;
+use nomad_rs_api::search::SearchContext;
+use serde_json::json;
+
+#[test]
+fn contexts_serialize_to_nomad_names() {
+    for (context, name) in [
+        (SearchContext::Allocs, "allocs"),
+        (SearchContext::Deployments, "deployment"),
+        (SearchContext::Evals, "evals"),
+        (SearchContext::NodePools, "node_pools"),
+        (SearchContext::ScalingPolicies, "scaling_policy"),
+        (SearchContext::Variables, "vars"),
+        (SearchContext::All, "all"),
+    ] {
+        assert_eq!(serde_json::to_value(context).unwrap(), json!(name));
+    }
+}
+
+#[tokio::test]
+async fn prefix_search_treats_null_context_as_empty() {
+    let nomad = MockNomad::start().await;
+    nomad
+        .expect_post("/v1/search")
+        .with_json(json!({"Prefix": "abc", "Context": "all"}))
+        .returns_json(json!({
+            "Matches": {
+                "allocs": null,
+                "deployment": null,
+                "evals": ["abc2fdc0-e1fd-2536-67d8-43af8ca798ac"],
+                "jobs": null,
+                "nodes": null,
+            },
+            "Truncations": {
+                "allocs": false,
+                "deployment": false,
+                "evals": false,
+                "jobs": false,
+                "nodes": false,
+            },
+        }))
+        .await;
+
+    let response = nomad
+        .client()
+        .search()
+        .prefix_search("abc", SearchContext::All, None)
+        .await
+        .unwrap();
+    assert!(response.matches["allocs"].is_empty());
+    assert!(response.matches["deployment"].is_empty());
+    assert_eq!(
+        response.matches["evals"],
+        vec!["abc2fdc0-e1fd-2536-67d8-43af8ca798ac"]
+    );
+}
+
+#[tokio::test]
+async fn fuzzy_search_treats_null_context_as_empty() {
+    let nomad = MockNomad::start().await;
+    nomad
+        .expect_post("/v1/search/fuzzy")
+        .with_json(json!({"Text": "redis", "Context": "all"}))
+        .returns_json(json!({
+            "Matches": {
+                "jobs": [{"ID": "redis", "Scope": ["default"]}],
+                "nodes": null,
+            },
+            "Truncations": {"jobs": false, "nodes": false},
+        }))
+        .await;
+
+    let response = nomad
+        .client()
+        .search()
+        .fuzzy_search("redis", SearchContext::All, None)
+        .await
+        .unwrap();
+    assert_eq!(response.matches["jobs"][0].id, "redis");
+    assert!(response.matches["nodes"].is_empty());
+}
@@ -0,0 +1,95 @@
+mod common;
+
+use common::MockNomad;
+use nomad_rs_api::sentinel_policy::{
+    EnforcementLevel, SENTINEL_POLICY_SCOPE_SUBMIT_JOB, SentinelPolicy,
+};
+use serde_json::json;
+use wiremock::ResponseTemplate;
+
+fn policy() -> SentinelPolicy {
+    let mut policy = SentinelPolicy::new(
+        "restrict-drivers".to_string(),
+        SENTINEL_POLICY_SCOPE_SUBMIT_JOB.to_string(),
+        EnforcementLevel::SoftMandatory,
+        "main = rule { true }".to_string(),
+    );
+    policy.description = Some("Only allow the docker driver".to_string());
+    policy
+}
+
+#[test]
+fn policy_round_trips() {
+    let body = serde_json::to_value(policy()).unwrap();
+    assert_eq!(
+        body,
+        json!({
+            "Name": "restrict-drivers",
+            "Description": "Only allow the docker driver",
+            "Scope": "submit-job",
+            "EnforcementLevel": "soft-mandatory",
+            "Policy": "main = rule { true }",
+            "CreateIndex": null,
+            "ModifyIndex": null,
+        })
+    );
+
+    let decoded: SentinelPolicy = serde_json::from_value(body).unwrap();
+    assert_eq!(decoded.name, "restrict-drivers");
+    assert_eq!(decoded.enforcement_level, EnforcementLevel::SoftMandatory);
+    assert_eq!(decoded.policy, "main = rule { true }");
+}
+
+#[test]
+fn enforcement_levels_use_kebab_case() {
+    for (level, name) in [
+        (EnforcementLevel::Advisory, "advisory"),
+        (EnforcementLevel::SoftMandatory, "soft-mandatory"),
+        (EnforcementLevel::HardMandatory, "hard-mandatory"),
+    ] {
+        assert_eq!(serde_json::to_value(level).unwrap(), json!(name));
+        assert_eq!(
+            serde_json::from_value::<EnforcementLevel>(json!(name)).unwrap(),
+            level
+        );
+    }
+}
+
+#[tokio::test]
+async fn upsert_then_get_returns_policy() {
+    let nomad = MockNomad::start().await;
+    let body = serde_json::to_value(policy()).unwrap();
+    nomad
+        .expect_post("/v1/sentinel/policy/restrict-drivers")
+        .with_json(body)
+        .returns(ResponseTemplate::new(200))
+        .await;
+    nomad
+        .expect_get("/v1/sentinel/policy/restrict-drivers")
+        .returns_json(json!({
+            "Name": "restrict-drivers",
+            "Description": "Only allow the docker driver",
+            "Scope": "submit-job",
+            "EnforcementLevel": "soft-mandatory",
+            "Policy": "main = rule { true }",
+            "CreateIndex": 7,
+            "ModifyIndex": 7,
+        }))
+        .await;
+
+    let client = nomad.client();
+    client
+        .sentinel_policy()
+        .upsert(&policy(), None)
+        .await
+        .unwrap();
+    let fetched = client
+        .sentinel_policy()
+        .get("restrict-drivers", None)
+        .await
+        .unwrap();
+
+    assert_eq!(fetched.enforcement_level, EnforcementLevel::SoftMandatory);
+    assert_eq!(fetched.scope, SENTINEL_POLICY_SCOPE_SUBMIT_JOB);
+    assert_eq!(fetched.create_index, Some(7));
+}
@@ -0,0 +1,193 @@
+mod common;
+
+use common::MockNomad;
+use nomad_rs_api::option::{QueryOptions, WriteOptions};
+use serde_json::json;
+use wiremock::ResponseTemplate;
+
+fn registration() -> serde_json::Value {
+    json!({
+        "ID": "_nomad-task-0b1d2c3e-4f5a-6b7c-8d9e-0f1a2b3c4d5e-group-cache-redis-db",
+        "ServiceName": "redis",
+        "Namespace": "platform",
+        "NodeID": "f1e2d3c4-b5a6-9788-6950-4a3b2c1d0e9f",
+        "Datacenter": "dc1",
+        "JobID": "cache",
+        "AllocID": "0b1d2c3e-4f5a-6b7c-8d9e-0f1a2b3c4d5e",
+        "Tags": ["db"],
+        "Address": "10.0.1.5",
+        "Port": 6379,
+        "CreateIndex": 20,
+        "ModifyIndex": 20,
+    })
+}
+
+#[tokio::test]
+async fn get_sends_query_options() {
+    let nomad = MockNomad::start().await;
+    nomad
+        .expect_get("/v1/service/redis")
+        .with_query("namespace", "platform")
+        .with_query("filter", "Tags contains \"db\"")
+        .with_header("X-Nomad-Token", "service-token")
+        .returns_json(json!([registration()]))
+        .await;
+
+    let opts = QueryOptions::new()
+        .with_namespace("platform".to_string())
+        .with_filter("Tags contains \"db\"".to_string())
+        .with_auth_token("service-token".to_string());
+    let services = nomad
+        .client()
+        .service()
+        .get("redis", Some(opts))
+        .await
+        .unwrap();
+    assert_eq!(services.len(), 1);
+    assert_eq!(services[0].namespace, "platform");
+    assert_eq!(services[0].port, 6379);
+}
+
+#[tokio::test]
+async fn list_sends_query_options() {
+    let nomad = MockNomad::start().await;
+    nomad
+        .expect_get("/v1/services")
+        .with_query("namespace", "platform")
+        .with_query("filter", "ServiceName == \"redis\"")
+        .with_header("X-Nomad-Token", "service-token")
+        .returns_json(json!([{
+            "Namespace": "platform",
+            "Services": [{"ServiceName": "redis", "Tags": ["db"]}],
+        }]))
+        .await;
+
+    let opts = QueryOptions::new()
+        .with_namespace("platform".to_string())
+        .with_filter("ServiceName == \"redis\"".to_string())
+        .with_auth_token("service-token".to_string());
+    let services = nomad.client().service().list(Some(opts)).await.unwrap();
+    assert_eq!(services[0].services[0].service_name, "redis");
+}
+
+#[tokio::test]
+async fn delete_sends_write_options() {
+    let nomad = MockNomad::start().await;
+    nomad
+        .expect_delete("/v1/service/redis")
+        .with_query("namespace", "platform")
+        .with_header("X-Nomad-Token", "service-token")
+        .returns_json(json!(null))
+        .await;
+
+    let opts = WriteOptions::new()
+        .with_namespace("platform".to_string())
+        .with_auth_token("service-token".to_string());
+    nomad
+        .client()
+        .service()
+        .delete("redis", Some(opts))
+        .await
+        .unwrap();
+}
+
+#[tokio::test]
+async fn list_with_meta_returns_next_token() {
+    let nomad = MockNomad::start().await;
+    nomad
+        .expect_get("/v1/services")
+        .with_query("per_page", "1")
+        .returns(
+            ResponseTemplate::new(200)
+                .insert_header("X-Nomad-Index", "57")
+                .insert_header("X-Nomad-NextToken", "platform.web")
+                .set_body_json(json!([{
+                    "Namespace": "platform",
+                    "Services": [{"ServiceName": "redis", "Tags": []}],
+                }])),
+        )
+        .await;
+
+    let opts = QueryOptions::new().with_per_page(1);
+    let (services, meta) = nomad
+        .client()
+        .service()
+        .list_with_meta(Some(opts))
+        .await
+        .unwrap();
+    assert_eq!(services.len(), 1);
+    assert_eq!(meta.last_index, 57);
+    assert_eq!(meta.next_token.as_deref(), Some("platform.web"));
+}
+
+#[tokio::test]
+async fn list_by_namespace_overrides_options_namespace() {
+    let nomad = MockNomad::start().await;
+    nomad
+        .expect_get("/v1/services")
+        .with_query("namespace", "platform")
+        .returns_json(json!([]))
+        .await;
+
+    let opts = QueryOptions::new().with_namespace("default".to_string());
+    nomad
+        .client()
+        .service()
+        .list_by_namespace("platform", Some(opts))
+        .await
+        .unwrap();
+
+    let requests = nomad.received_requests().await;
+    let namespaces: Vec<_> = requests[0]
+        .url
+        .query_pairs()
+        .filter(|(key, _)| key == "namespace")
+        .map(|(_, value)| value.into_owned())
+        .collect();
+    assert_eq!(namespaces, vec!["platform"]);
+}
+
+#[tokio::test]
+async fn list_sorts_by_namespace_then_service_name() {
+    let nomad = MockNomad::start().await;
+    nomad
+        .expect_get("/v1/services")
+        .returns_json(json!([
+            {
+                "Namespace": "platform",
+                "Services": [
+                    {"ServiceName": "web", "Tags": []},
+                    {"ServiceName": "redis", "Tags": []},
+                ],
+            },
+            {
+                "Namespace": "default",
+                "Services": [
+                    {"ServiceName": "api", "Tags": []},
+                    {"ServiceName": "admin", "Tags": []},
+                ],
+            },
+        ]))
+        .await;
+
+    let services = nomad.client().service().list(None).await.unwrap();
+    let order: Vec<(&str, Vec<&str>)> = services
+        .iter()
+        .map(|list| {
+            (
+                list.namespace.as_str(),
+                list.services
+                    .iter()
+                    .map(|service| service.service_name.as_str())
+                    .collect(),
+            )
+        })
+        .collect();
+    assert_eq!(
+        order,
+        vec![
+            ("default", vec!["admin", "api"]),
+            ("platform", vec!["redis", "web"]),
+        ]
+    );
+}
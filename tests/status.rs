@@ -0,0 +1,71 @@
+mod common;
+
+use common::MockNomad;
+use nomad_rs_api::option::QueryOptions;
+use serde_json::json;
+
+#[tokio::test]
+async fn get_leader_in_region_sets_region() {
+    let nomad = MockNomad::start().await;
+    nomad
+        .expect_get("/v1/status/leader")
+        .with_query("region", "eu-west")
+        .returns_json(json!("10.0.1.5:4647"))
+        .await;
+
+    let leader = nomad
+        .client()
+        .status()
+        .get_leader_in_region("eu-west")
+        .await
+        .unwrap();
+    assert_eq!(leader, "10.0.1.5:4647");
+}
+
+#[tokio::test]
+async fn list_peers_forwards_query_options() {
+    let nomad = MockNomad::start().await;
+    nomad
+        .expect_get("/v1/status/peers")
+        .with_query("region", "us-east")
+        .with_query("stale", "")
+        .returns_json(json!(["10.0.2.5:4647", "10.0.2.6:4647", "10.0.2.7:4647"]))
+        .await;
+
+    let opts = QueryOptions::new()
+        .with_region("us-east".to_string())
+        .with_allow_stale(true);
+    let peers = nomad
+        .client()
+        .status()
+        .list_peers(Some(opts))
+        .await
+        .unwrap();
+    assert_eq!(peers.len(), 3);
+}
+
+#[tokio::test]
+async fn stale_reads_send_bare_stale_param() {
+    let nomad = MockNomad::start().await;
+    nomad
+        .expect_get("/v1/status/peers")
+        .times(2)
+        .returns_json(json!(["10.0.2.5:4647"]))
+        .await;
+
+    let client = nomad.client();
+    client
+        .status()
+        .list_peers(Some(QueryOptions::new().stale()))
+        .await
+        .unwrap();
+    client
+        .status()
+        .list_peers(Some(QueryOptions::new().with_allow_stale(false)))
+        .await
+        .unwrap();
+
+    let requests = nomad.received_requests().await;
+    assert_eq!(requests[0].url.query(), Some("stale=&region=global"));
+    assert_eq!(requests[1].url.query(), Some("region=global"));
+}
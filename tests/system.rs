@@ -0,0 +1,52 @@
+mod common;
+
+use common::MockNomad;
+use nomad_rs_api::ClientError;
+use wiremock::ResponseTemplate;
+
+#[tokio::test]
+async fn garbage_collect_puts_gc() {
+    let nomad = MockNomad::start().await;
+    nomad
+        .expect_put("/v1/system/gc")
+        .returns(ResponseTemplate::new(200))
+        .await;
+
+    nomad.client().system().garbage_collect(None).await.unwrap();
+}
+
+#[tokio::test]
+async fn reconcile_summaries_puts_reconcile() {
+    let nomad = MockNomad::start().await;
+    nomad
+        .expect_put("/v1/system/reconcile/summaries")
+        .returns(ResponseTemplate::new(200))
+        .await;
+
+    nomad
+        .client()
+        .system()
+        .reconcile_summaries(None)
+        .await
+        .unwrap();
+}
+
+#[tokio::test]
+async fn garbage_collect_without_management_token_is_denied() {
+    let nomad = MockNomad::start().await;
+    nomad
+        .expect_put("/v1/system/gc")
+        .returns_status(403, "Permission denied")
+        .await;
+
+    let err = nomad
+        .client()
+        .system()
+        .garbage_collect(None)
+        .await
+        .unwrap_err();
+    assert!(
+        matches!(err, ClientError::PermissionDenied { status: 403, .. }),
+        "unexpected error: {err:?}"
+    );
+}
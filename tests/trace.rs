@@ -0,0 +1,188 @@
+#![cfg(feature = "tracing")]
+
+mod common;
+
+use common::MockNomad;
+use nomad_rs_api::{Config, Nomad};
+use serde_json::json;
+use std::collections::HashMap;
+use std::fmt;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
+use tracing::field::{Field, Visit};
+use tracing::span::{Attributes, Id, Record};
+use tracing::{Event, Metadata, Subscriber};
+use tracing_core::span::Current;
+
+type Fields = HashMap<String, String>;
+
+/// A subscriber which captures the fields of every span and event emitted by
+/// this crate, ignoring those of its dependencies. The stack of entered spans
+/// is tracked, as the client records the status code on the current span.
+#[derive(Clone, Default)]
+struct Capture {
+    next_id: Arc<AtomicU64>,
+    spans: Arc<Mutex<HashMap<u64, (&'static Metadata<'static>, Fields)>>>,
+    entered: Arc<Mutex<Vec<u64>>>,
+    events: Arc<Mutex<Vec<Fields>>>,
+}
+
+struct FieldVisitor<'a>(&'a mut Fields);
+
+impl Visit for FieldVisitor<'_> {
+    fn record_debug(&mut self, field: &Field, value: &dyn fmt::Debug) {
+        self.0
+            .insert(field.name().to_string(), format!("{:?}", value));
+    }
+}
+
+impl Capture {
+    fn span(&self, name: &str) -> Fields {
+        let spans = self.spans.lock().unwrap();
+        let mut matching = spans
+            .values()
+            .filter(|(metadata, _)| metadata.name() == name);
+        let (_, fields) = matching.next().expect("span was not recorded");
+        assert!(
+            matching.next().is_none(),
+            "span was recorded more than once"
+        );
+        fields.clone()
+    }
+
+    /// Every captured field value, to assert that secrets are never recorded.
+    fn all_values(&self) -> Vec<String> {
+        let spans = self.spans.lock().unwrap();
+        let events = self.events.lock().unwrap();
+        spans
+            .values()
+            .flat_map(|(_, fields)| fields.values())
+            .chain(events.iter().flat_map(|fields| fields.values()))
+            .cloned()
+            .collect()
+    }
+}
+
+impl Subscriber for Capture {
+    fn enabled(&self, metadata: &Metadata<'_>) -> bool {
+        metadata.target().starts_with("nomad_rs_api")
+    }
+
+    fn new_span(&self, span: &Attributes<'_>) -> Id {
+        let id = self.next_id.fetch_add(1, Ordering::SeqCst) + 1;
+        let mut fields = Fields::new();
+        span.record(&mut FieldVisitor(&mut fields));
+        self.spans
+            .lock()
+            .unwrap()
+            .insert(id, (span.metadata(), fields));
+        Id::from_u64(id)
+    }
+
+    fn record(&self, span: &Id, values: &Record<'_>) {
+        if let Some((_, fields)) = self.spans.lock().unwrap().get_mut(&span.into_u64()) {
+            values.record(&mut FieldVisitor(fields));
+        }
+    }
+
+    fn record_follows_from(&self, _: &Id, _: &Id) {}
+
+    fn event(&self, event: &Event<'_>) {
+        let mut fields = Fields::new();
+        event.record(&mut FieldVisitor(&mut fields));
+        self.events.lock().unwrap().push(fields);
+    }
+
+    fn enter(&self, span: &Id) {
+        self.entered.lock().unwrap().push(span.into_u64());
+    }
+
+    fn exit(&self, span: &Id) {
+        let mut entered = self.entered.lock().unwrap();
+        if let Some(position) = entered.iter().rposition(|id| *id == span.into_u64()) {
+            entered.remove(position);
+        }
+    }
+
+    fn current_span(&self) -> Current {
+        let entered = self.entered.lock().unwrap();
+        let spans = self.spans.lock().unwrap();
+        match entered
+            .last()
+            .and_then(|id| spans.get(id).map(|span| (id, span)))
+        {
+            Some((id, (metadata, _))) => Current::new(Id::from_u64(*id), metadata),
+            None => Current::none(),
+        }
+    }
+}
+
+fn client(nomad: &MockNomad) -> Nomad {
+    Nomad::new(Config {
+        token: Some("secret-token".to_string()),
+        ..nomad.config()
+    })
+}
+
+#[tokio::test]
+async fn successful_request_records_span_fields() {
+    let nomad = MockNomad::start().await;
+    nomad
+        .expect_get("/v1/job/billing-api")
+        .with_header("X-Nomad-Token", "secret-token")
+        .returns_json(json!({"ID": "billing-api", "Name": "billing-api", "TaskGroups": []}))
+        .await;
+
+    let capture = Capture::default();
+    let _guard = tracing::subscriber::set_default(capture.clone());
+    client(&nomad).job().get("billing-api", None).await.unwrap();
+
+    let span = capture.span("nomad_request");
+    assert_eq!(span["method"], "GET");
+    assert_eq!(span["path"], "/v1/job/:id");
+    assert_eq!(span["status"], "200");
+    assert!(span["elapsed_ms"].parse::<u64>().is_ok());
+    assert!(capture.events.lock().unwrap().is_empty());
+
+    for value in capture.all_values() {
+        assert!(
+            !value.contains("secret-token"),
+            "token was recorded: {value}"
+        );
+        assert!(
+            !value.contains("billing-api"),
+            "job ID was recorded: {value}"
+        );
+    }
+}
+
+#[tokio::test]
+async fn failed_request_records_error_without_body() {
+    let nomad = MockNomad::start().await;
+    nomad
+        .expect_get("/v1/job/billing-api")
+        .returns_status(500, "failed for token secret-token")
+        .await;
+
+    let capture = Capture::default();
+    let _guard = tracing::subscriber::set_default(capture.clone());
+    client(&nomad)
+        .job()
+        .get("billing-api", None)
+        .await
+        .unwrap_err();
+
+    let span = capture.span("nomad_request");
+    assert_eq!(span["status"], "500");
+
+    let events = capture.events.lock().unwrap().clone();
+    assert_eq!(events.len(), 1);
+    assert_eq!(events[0]["error"], "\"server\"");
+
+    for value in capture.all_values() {
+        assert!(
+            !value.contains("secret-token"),
+            "token was recorded: {value}"
+        );
+    }
+}
@@ -0,0 +1,75 @@
+#![cfg(feature = "unix-socket")]
+
+use nomad_rs_api::{Config, Nomad};
+use std::path::PathBuf;
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::UnixListener;
+
+/// Serve a single request on a Unix socket, responding with `body` and
+/// returning the raw request head received.
+async fn serve_once(socket_path: PathBuf, body: &'static str) -> tokio::task::JoinHandle<String> {
+    let _ = std::fs::remove_file(&socket_path);
+    let listener = UnixListener::bind(&socket_path).unwrap();
+
+    tokio::spawn(async move {
+        let (mut stream, _) = listener.accept().await.unwrap();
+        let mut head = Vec::new();
+        let mut buf = [0u8; 1024];
+        while !head.windows(4).any(|window| window == b"\r\n\r\n") {
+            let read = stream.read(&mut buf).await.unwrap();
+            assert_ne!(read, 0, "connection closed before the request head");
+            head.extend_from_slice(&buf[..read]);
+        }
+
+        let response = format!(
+            "HTTP/1.1 200 OK\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+            body.len(),
+            body
+        );
+        stream.write_all(response.as_bytes()).await.unwrap();
+        String::from_utf8(head).unwrap()
+    })
+}
+
+fn socket_path(name: &str) -> PathBuf {
+    std::env::temp_dir().join(format!("nomad-rs-api-{}-{}.sock", name, std::process::id()))
+}
+
+#[tokio::test]
+async fn requests_send_default_user_agent() {
+    let socket_path = socket_path("default-user-agent");
+    let server = serve_once(socket_path.clone(), r#"["global"]"#).await;
+
+    let nomad = Nomad::new(Config {
+        address: format!("unix://{}", socket_path.display()),
+        ..Config::default()
+    });
+    let regions = nomad.region().list().await.unwrap();
+    assert_eq!(regions, vec!["global"]);
+
+    let head = server.await.unwrap().to_lowercase();
+    assert!(
+        head.starts_with("get /v1/regions?"),
+        "unexpected request: {head}"
+    );
+    let user_agent = format!("user-agent: nomad-rs-api/{}\r\n", env!("CARGO_PKG_VERSION"));
+    assert!(head.contains(&user_agent), "missing user agent: {head}");
+    let _ = std::fs::remove_file(socket_path);
+}
+
+#[tokio::test]
+async fn missing_socket_is_a_connection_error() {
+    let socket_path = socket_path("missing");
+    let _ = std::fs::remove_file(&socket_path);
+
+    let nomad = Nomad::new(Config {
+        address: format!("unix://{}", socket_path.display()),
+        connect_timeout: Some(std::time::Duration::from_secs(1)),
+        ..Config::default()
+    });
+    let err = nomad.region().list().await.unwrap_err();
+    assert!(
+        matches!(err, nomad_rs_api::ClientError::ConnectionError(_)),
+        "unexpected error: {err:?}"
+    );
+}
@@ -0,0 +1,74 @@
+mod common;
+
+use common::MockNomad;
+use nomad_rs_api::ClientError;
+use nomad_rs_api::variable::Variable;
+use serde_json::json;
+use std::collections::HashMap;
+
+fn variable(owner: &str) -> Variable {
+    let mut variable = Variable::new(
+        "nomad/jobs/example".to_string(),
+        HashMap::from([("owner".to_string(), owner.to_string())]),
+    );
+    variable.modify_index = Some(10);
+    variable
+}
+
+fn stored(owner: &str, modify_index: u64) -> serde_json::Value {
+    json!({
+        "Path": "nomad/jobs/example",
+        "Namespace": "default",
+        "Items": {"owner": owner},
+        "CreateIndex": 5,
+        "ModifyIndex": modify_index,
+        "CreateTime": 1700000000000000000i64,
+        "ModifyTime": 1700000001000000000i64,
+    })
+}
+
+#[tokio::test]
+async fn create_cas_racing_writers_conflict() {
+    let nomad = MockNomad::start().await;
+    let first = variable("alice");
+    let second = variable("bob");
+    nomad
+        .expect_put("/v1/var/nomad/jobs/example")
+        .with_query("cas", "10")
+        .with_json(serde_json::to_value(&first).unwrap())
+        .returns_json(stored("alice", 11))
+        .await;
+    nomad
+        .expect_put("/v1/var/nomad/jobs/example")
+        .with_query("cas", "10")
+        .with_json(serde_json::to_value(&second).unwrap())
+        .returns(wiremock::ResponseTemplate::new(409).set_body_json(stored("alice", 11)))
+        .await;
+
+    // Both writers read the variable at index 10 before attempting to update
+    // it, so only the first write succeeds.
+    let client = nomad.client();
+    let stored = client
+        .variable()
+        .create_cas(&first, 10, None)
+        .await
+        .unwrap();
+    assert_eq!(stored.modify_index, Some(11));
+    assert_eq!(stored.items["owner"], "alice");
+
+    let err = client
+        .variable()
+        .create_cas(&second, 10, None)
+        .await
+        .unwrap_err();
+    assert!(
+        matches!(
+            err,
+            ClientError::CasConflict {
+                expected: 10,
+                current: Some(11)
+            }
+        ),
+        "unexpected error: {err:?}"
+    );
+}
@@ -0,0 +1,195 @@
+mod common;
+
+use common::MockNomad;
+use nomad_rs_api::ClientError;
+use nomad_rs_api::option::QueryOptions;
+use serde_json::json;
+use std::time::{Duration, Instant};
+use wiremock::ResponseTemplate;
+
+fn job_at(index: u64, status: &str) -> ResponseTemplate {
+    ResponseTemplate::new(200)
+        .insert_header("X-Nomad-Index", index.to_string().as_str())
+        .set_body_json(json!({"ID": "example", "Status": status}))
+}
+
+fn query_param(request: &wiremock::Request, key: &str) -> Option<String> {
+    request
+        .url
+        .query_pairs()
+        .find(|(name, _)| name == key)
+        .map(|(_, value)| value.into_owned())
+}
+
+#[tokio::test]
+async fn watch_invokes_callback_only_when_index_increases() {
+    let nomad = MockNomad::start().await;
+    nomad
+        .expect_get("/v1/job/example")
+        .returns_sequence(vec![
+            job_at(5, "pending"),
+            // The blocking query timed out without a change.
+            job_at(5, "pending"),
+            job_at(7, "running"),
+            // The index went backwards, such as after a snapshot restore.
+            job_at(3, "running"),
+            // End the watch, as it otherwise runs until an error.
+            ResponseTemplate::new(500).set_body_string("shutting down"),
+        ])
+        .await;
+
+    let mut statuses = Vec::new();
+    let opts = QueryOptions::new().with_wait_time(30_000_000_000);
+    let err = nomad
+        .client()
+        .watch("/v1/job/example", Some(opts), |job: serde_json::Value| {
+            statuses.push(job["Status"].as_str().unwrap().to_string());
+        })
+        .await
+        .unwrap_err();
+
+    assert!(
+        matches!(err, ClientError::ServerError { status: 500, .. }),
+        "unexpected error: {err:?}"
+    );
+    assert_eq!(statuses, vec!["pending", "running"]);
+
+    let requests = nomad.received_requests().await;
+    let indexes: Vec<Option<String>> = requests
+        .iter()
+        .map(|request| query_param(request, "index"))
+        .collect();
+    assert_eq!(
+        indexes,
+        vec![
+            None,
+            Some("5".to_string()),
+            Some("5".to_string()),
+            Some("7".to_string()),
+            None,
+        ]
+    );
+    for request in &requests {
+        assert_eq!(query_param(request, "wait").as_deref(), Some("30000ms"));
+    }
+}
+
+#[tokio::test]
+async fn sub_millisecond_wait_rounds_up() {
+    let nomad = MockNomad::start().await;
+    nomad
+        .expect_get("/v1/job/example")
+        .times(3)
+        .returns_json(json!({"ID": "example", "Name": "example", "TaskGroups": []}))
+        .await;
+
+    let client = nomad.client();
+    for wait_time in [1, 999_999, 1_000_001] {
+        let opts = QueryOptions::new()
+            .with_wait_index(42)
+            .with_wait_time(wait_time);
+        client.job().get("example", Some(opts)).await.unwrap();
+    }
+
+    // A zero wait would make Nomad block for its default of five minutes.
+    let waits: Vec<_> = nomad
+        .received_requests()
+        .await
+        .iter()
+        .map(|request| query_param(request, "wait"))
+        .collect();
+    assert_eq!(
+        waits,
+        vec![
+            Some("1ms".to_string()),
+            Some("1ms".to_string()),
+            Some("2ms".to_string())
+        ]
+    );
+}
+
+#[tokio::test]
+async fn query_options_send_index_and_wait() {
+    let nomad = MockNomad::start().await;
+    nomad
+        .expect_get("/v1/job/example")
+        .with_query("index", "42")
+        .with_query("wait", "1500ms")
+        .returns_json(json!({"ID": "example", "Name": "example", "TaskGroups": []}))
+        .await;
+
+    let opts = QueryOptions::new()
+        .with_wait_index(42)
+        .with_wait_time(1_500_000_000);
+    nomad
+        .client()
+        .job()
+        .get("example", Some(opts))
+        .await
+        .unwrap();
+}
+
+#[tokio::test]
+async fn timeout_cancels_blocking_watch_promptly() {
+    let nomad = MockNomad::start().await;
+    nomad
+        .expect_get("/v1/job/example")
+        .returns_sequence(vec![
+            job_at(5, "pending"),
+            // The blocking query only returns once its wait time elapses.
+            job_at(5, "pending").set_delay(Duration::from_secs(30)),
+        ])
+        .await;
+    nomad
+        .expect_get("/v1/regions")
+        .returns_json(json!(["global"]))
+        .await;
+
+    let client = nomad.client();
+    let mut calls = 0;
+    let opts = QueryOptions::new().with_wait_time(300_000_000_000);
+    let started = Instant::now();
+    let result = tokio::time::timeout(
+        Duration::from_millis(300),
+        client.watch("/v1/job/example", Some(opts), |_: serde_json::Value| {
+            calls += 1;
+        }),
+    )
+    .await;
+
+    assert!(result.is_err(), "watch returned: {result:?}");
+    assert!(started.elapsed() < Duration::from_secs(2));
+    assert_eq!(calls, 1);
+
+    // The client remains usable once the pending request is dropped.
+    assert_eq!(client.region().list().await.unwrap(), vec!["global"]);
+}
+
+#[tokio::test]
+async fn watch_without_index_polls_at_interval() {
+    let nomad = MockNomad::start().await;
+    nomad
+        .expect_get("/v1/job/example")
+        .times(2)
+        .returns_json(json!({"ID": "example", "Status": "pending"}))
+        .await;
+
+    let result = tokio::time::timeout(
+        Duration::from_millis(1500),
+        nomad
+            .client()
+            .watch("/v1/job/example", None, |_: serde_json::Value| {}),
+    )
+    .await;
+    assert!(result.is_err(), "watch returned: {result:?}");
+
+    // Without an index every request returns immediately, so only the poll
+    // interval keeps the watch from sending requests back to back.
+    let requests = nomad.received_requests().await;
+    assert_eq!(requests.len(), 2);
+    assert!(
+        requests
+            .iter()
+            .all(|request| query_param(request, "index").is_none())
+    );
+}